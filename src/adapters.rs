@@ -0,0 +1,280 @@
+//! Adapters between the collection traits.
+
+use super::*;
+
+/// An adapter that exposes a set as a map whose values are `()`.
+///
+/// This allows algorithms that are written against [`Map`] to operate on sets without copying
+/// their items. A `SetMap<S>` has the same representation as `S`, so references to sets can be
+/// converted with [`from_ref`] and [`from_mut`].
+///
+/// The map traits are implemented only for sets that support insertion and removal, because the
+/// adapter cannot otherwise forward the operations that require [`AddRemove`].
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::Map;
+/// use eclectic::adapters::SetMap;
+/// use eclectic::map::Base;
+/// use std::collections::HashSet;
+///
+/// fn count_present<M: ?Sized + Map>(map: &M, keys: &[M::Key]) -> usize {
+///     keys.iter().filter(|key| map.contains_key(key)).count()
+/// }
+///
+/// let mut set: HashSet<_> = vec![1, 2, 3].into_iter().collect();
+///
+/// assert_eq!(count_present(SetMap::from_ref(&set), &[1, 3, 5]), 2);
+///
+/// SetMap::from_mut(&mut set).entry(4).or_insert(());
+/// assert!(set.contains(&4));
+/// ```
+///
+/// [`AddRemove`]: ../trait.AddRemove.html
+/// [`Map`]: ../map/trait.Map.html
+/// [`from_mut`]: #method.from_mut
+/// [`from_ref`]: #method.from_ref
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct SetMap<S> {
+    set: S,
+}
+
+impl<S: set::Set> SetMap<S> {
+    /// Returns a new map that wraps the given set.
+    pub fn new(set: S) -> Self {
+        SetMap { set: set }
+    }
+
+    /// Converts a reference to a set into a reference to a map.
+    pub fn from_ref(set: &S) -> &Self {
+        // this is safe because `SetMap<S>` is a transparent wrapper around `S`
+        unsafe { &*(set as *const S as *const Self) }
+    }
+
+    /// Converts a mutable reference to a set into a mutable reference to a map.
+    pub fn from_mut(set: &mut S) -> &mut Self {
+        // this is safe because `SetMap<S>` is a transparent wrapper around `S`
+        unsafe { &mut *(set as *mut S as *mut Self) }
+    }
+
+    /// Returns a reference to the underlying set.
+    pub fn as_set(&self) -> &S {
+        &self.set
+    }
+
+    /// Returns a mutable reference to the underlying set.
+    pub fn as_set_mut(&mut self) -> &mut S {
+        &mut self.set
+    }
+
+    /// Consumes the map and returns the underlying set.
+    pub fn into_inner(self) -> S {
+        self.set
+    }
+}
+
+/// Returns a mutable reference to a unit value that lives as long as necessary.
+fn unit<'a>() -> &'a mut () {
+    // `()` is zero-sized, so this does not allocate
+    Box::leak(Box::new(()))
+}
+
+impl<S: set::Set + AddRemove> Mutate for SetMap<S> {}
+
+impl<S: set::Set + AddRemove> AddRemove for SetMap<S> {}
+
+impl<S: set::Set + AddRemove> Collection for SetMap<S> {
+    type Item = (S::Item, ());
+
+    fn len(&self) -> usize {
+        self.set.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.set.is_empty()
+    }
+
+    fn capacity(&self) -> usize {
+        self.set.capacity()
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        self.set.append(&mut other.set);
+    }
+
+    fn extend_object(&mut self, items: &mut Iterator<Item = (S::Item, ())>) {
+        self.set.extend_object(&mut items.map(|(item, ())| item));
+    }
+
+    fn clear(&mut self) {
+        self.set.clear();
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = (S::Item, ())> + 'a> {
+        Box::new(self.set.drain().map(|item| (item, ())))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.set.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.set.shrink_to_fit();
+    }
+
+    fn with_capacity(capacity: usize) -> Self where Self: Default {
+        let mut map = Self::default();
+        map.set.reserve(capacity);
+        map
+    }
+
+    fn into_vec(self) -> Vec<(S::Item, ())> {
+        self.set.into_vec().into_iter().map(|item| (item, ())).collect()
+    }
+}
+
+impl<S: set::Set + AddRemove> map::Base for SetMap<S> {
+    type Key = S::Item;
+    type Value = ();
+
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = (&'a S::Item, &'a ())> + 'a> {
+        Box::new(self.set.iter().map(|item| (item, &())))
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = (&'a S::Item, &'a mut ())> + 'a> {
+        Box::new(self.set.iter().map(|item| (item, unit())))
+    }
+
+    fn insert(&mut self, key: S::Item, _value: ()) -> Option<()> {
+        if self.set.insert(key) { None } else { Some(()) }
+    }
+
+    fn entry<'a>(&'a mut self, key: S::Item) -> map::Entry<'a, S::Item, ()> {
+        if self.set.contains(&key) {
+            map::Entry::Occupied(Box::new(OccupiedEntry { set: &mut self.set, key: key }))
+        } else {
+            map::Entry::Vacant(Box::new(VacantEntry { set: &mut self.set, key: key }))
+        }
+    }
+
+    fn replace(&mut self, key: S::Item, _value: ()) -> Option<(S::Item, ())> {
+        self.set.replace(key).map(|key| (key, ()))
+    }
+}
+
+impl<S: set::Set + set::Set<Q> + AddRemove, Q: ?Sized> Map<Q> for SetMap<S> {
+    fn contains_key(&self, key: &Q) -> bool {
+        self.set.contains(key)
+    }
+
+    fn get(&self, key: &Q) -> Option<&()> {
+        self.set.get(key).map(|_| &())
+    }
+
+    fn get_key_value(&self, key: &Q) -> Option<(&S::Item, &())> {
+        self.set.get(key).map(|key| (key, &()))
+    }
+
+    fn get_mut(&mut self, key: &Q) -> Option<&mut ()> {
+        self.set.get(key).map(|_| unit())
+    }
+
+    fn remove(&mut self, key: &Q) -> Option<()> {
+        if self.set.remove(key) { Some(()) } else { None }
+    }
+
+    fn remove_entry(&mut self, key: &Q) -> Option<(S::Item, ())> {
+        self.set.take(key).map(|key| (key, ()))
+    }
+}
+
+struct OccupiedEntry<'a, S: 'a + set::Set> {
+    set: &'a mut S,
+    key: S::Item,
+}
+
+impl<'a, S: 'a + set::Set + AddRemove> map::OccupiedEntry for OccupiedEntry<'a, S> {
+    type Key = S::Item;
+    type Value = ();
+    type MutValue = &'a mut ();
+
+    fn key(&self) -> &S::Item {
+        self.set.get(&self.key).expect("occupied entry's key is missing from the set")
+    }
+
+    fn get(&self) -> &() {
+        &()
+    }
+
+    fn get_mut(&mut self) -> &mut () {
+        unit()
+    }
+
+    fn into_mut(self: Box<Self>) -> &'a mut () {
+        unit()
+    }
+
+    fn remove(self: Box<Self>) {
+        let this = *self;
+        this.set.remove(&this.key);
+    }
+
+    fn remove_entry(self: Box<Self>) -> (S::Item, ()) {
+        let this = *self;
+        (this.set.take(&this.key).expect("occupied entry's key is missing from the set"), ())
+    }
+
+    fn replace_key(self: Box<Self>, key: S::Item) -> S::Item {
+        self.set.replace(key).expect("occupied entry's key is missing from the set")
+    }
+}
+
+struct VacantEntry<'a, S: 'a + set::Set> {
+    set: &'a mut S,
+    key: S::Item,
+}
+
+impl<'a, S: 'a + set::Set + AddRemove> map::VacantEntry for VacantEntry<'a, S> {
+    type Key = S::Item;
+    type Value = ();
+    type MutValue = &'a mut ();
+
+    fn key(&self) -> &S::Item {
+        &self.key
+    }
+
+    fn into_key(self: Box<Self>) -> S::Item {
+        self.key
+    }
+
+    fn insert(self: Box<Self>, _value: ()) -> &'a mut () {
+        let this = *self;
+        this.set.insert(this.key);
+        unit()
+    }
+}
+
+#[test]
+fn test_set_map_entry() {
+    use std::collections::BTreeSet;
+    use std::mem;
+
+    let mut map = SetMap::new(BTreeSet::new());
+
+    match map::Base::entry(&mut map, 1) {
+        map::Entry::Occupied(_) => panic!("expected a vacant entry"),
+        map::Entry::Vacant(e) => { e.insert(()); }
+    }
+
+    assert!(map.as_set().iter().eq(&[1]));
+
+    match map::Base::entry(&mut map, 1) {
+        map::Entry::Occupied(e) => e.remove(),
+        map::Entry::Vacant(_) => panic!("expected an occupied entry"),
+    }
+
+    assert!(map.as_set().is_empty());
+    assert_eq!(mem::size_of::<SetMap<BTreeSet<i32>>>(), mem::size_of::<BTreeSet<i32>>());
+}
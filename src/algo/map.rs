@@ -0,0 +1,250 @@
+//! Algorithms that combine maps.
+//!
+//! The algorithms accept any pair of maps whose keys have the same type, so they can combine maps
+//! of different types (e.g. a `HashMap` with a `BTreeMap`). Entries are matched by looking up the
+//! keys of each map in the other, using the second map's notion of key equivalence.
+//!
+//! Functions with an `_ordered` suffix require both maps to implement [`Ordered`]. They match
+//! entries by walking both maps in key order at the same time, which visits each entry exactly
+//! once and performs no lookups.
+//!
+//! # Examples
+//!
+//! ```
+//! use eclectic::algo::map::{self, EitherOrBoth};
+//! use std::collections::{BTreeMap, HashMap};
+//!
+//! let stock: HashMap<_, _> = vec![("apples", 3), ("pears", 5)].into_iter().collect();
+//! let orders: BTreeMap<_, _> = vec![("pears", 2), ("plums", 1)].into_iter().collect();
+//!
+//! let remaining: BTreeMap<_, _> = map::merge(stock, orders, |_, entry| match entry {
+//!     EitherOrBoth::Left(count) => Some(count),
+//!     EitherOrBoth::Right(_) => None,
+//!     EitherOrBoth::Both(count, ordered) => Some(count - ordered),
+//! });
+//!
+//! assert_eq!(remaining, vec![("apples", 3), ("pears", 3)].into_iter().collect());
+//! ```
+//!
+//! [`Ordered`]: ../../map/trait.Ordered.html
+
+use std::cmp::Ordering;
+use std::iter::Peekable;
+use super::super::*;
+use super::super::map::{Base, Ordered};
+
+/// A value that is present in the first map, the second map, or both.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EitherOrBoth<A, B> {
+    /// A value that is present only in the first map.
+    Left(A),
+    /// A value that is present only in the second map.
+    Right(B),
+    /// Values that are present in both maps.
+    Both(A, B),
+}
+
+/// Returns an iterator that yields each key in either of the given maps, along with the
+/// corresponding values.
+///
+/// The entries of the first map are yielded first, in its iteration order, followed by the
+/// entries that are present only in the second map.
+pub fn join<'a, A, B>(a: &'a A, b: &'a B)
+    -> Box<Iterator<Item = (&'a A::Key, EitherOrBoth<&'a A::Value, &'a B::Value>)> + 'a>
+    where A: ?Sized + Map, B: ?Sized + Map<A::Key, Key = A::Key>
+{
+    let left = a.iter().map(move |(key, value)| match b.get(key) {
+        Some(other) => (key, EitherOrBoth::Both(value, other)),
+        None => (key, EitherOrBoth::Left(value)),
+    });
+
+    let right = b.iter()
+        .filter(move |&(key, _)| !a.contains_key(key))
+        .map(|(key, value)| (key, EitherOrBoth::Right(value)));
+
+    Box::new(left.chain(right))
+}
+
+/// Returns an iterator that yields each key in either of the given ordered maps, along with the
+/// corresponding values, in ascending order of the keys.
+pub fn join_ordered<'a, A, B>(a: &'a A, b: &'a B)
+    -> Box<Iterator<Item = (&'a A::Key, EitherOrBoth<&'a A::Value, &'a B::Value>)> + 'a>
+    where A: ?Sized + Ordered, B: ?Sized + Ordered<Key = A::Key>, A::Key: Ord
+{
+    Box::new(OrderedJoin { left: a.iter().peekable(), right: b.iter().peekable() })
+}
+
+/// Returns an iterator that yields the differences between the given maps.
+///
+/// Each key that is present only in the first map is yielded with `EitherOrBoth::Left`, each key
+/// that is present only in the second map is yielded with `EitherOrBoth::Right`, and each key
+/// that is present in both maps with unequal values is yielded with `EitherOrBoth::Both`.
+pub fn diff<'a, A, B>(a: &'a A, b: &'a B)
+    -> Box<Iterator<Item = (&'a A::Key, EitherOrBoth<&'a A::Value, &'a B::Value>)> + 'a>
+    where A: ?Sized + Map, B: ?Sized + Map<A::Key, Key = A::Key>, A::Value: PartialEq<B::Value>
+{
+    Box::new(join(a, b).filter(|&(_, ref entry)| match *entry {
+        EitherOrBoth::Both(value, other) => value != other,
+        _ => true,
+    }))
+}
+
+/// Merges the given maps into a new map.
+///
+/// The given function is called with each key in either map and the corresponding values, and
+/// the key is inserted into the new map with the value that the function returns, if any.
+pub fn merge<A, B, C, F>(mut a: A, mut b: B, mut f: F) -> C
+    where A: Map + AddRemove,
+          B: Map<A::Key, Key = A::Key> + AddRemove,
+          C: Base<Key = A::Key> + AddRemove + Default,
+          F: FnMut(&A::Key, EitherOrBoth<A::Value, B::Value>) -> Option<C::Value>
+{
+    let mut c = C::default();
+
+    for (key, value) in a.drain() {
+        let entry = match b.remove(&key) {
+            Some(other) => EitherOrBoth::Both(value, other),
+            None => EitherOrBoth::Left(value),
+        };
+
+        if let Some(value) = f(&key, entry) {
+            c.insert(key, value);
+        }
+    }
+
+    for (key, other) in b.drain() {
+        if let Some(value) = f(&key, EitherOrBoth::Right(other)) {
+            c.insert(key, value);
+        }
+    }
+
+    c
+}
+
+/// Merges the given ordered maps into a new map.
+///
+/// This is equivalent to [`merge`], but the function is called in ascending order of the keys.
+///
+/// [`merge`]: fn.merge.html
+pub fn merge_ordered<A, B, C, F>(mut a: A, mut b: B, mut f: F) -> C
+    where A: Ordered + AddRemove,
+          B: Ordered<Key = A::Key> + AddRemove,
+          C: Base<Key = A::Key> + AddRemove + Default,
+          F: FnMut(&A::Key, EitherOrBoth<A::Value, B::Value>) -> Option<C::Value>,
+          A::Key: Ord
+{
+    let mut c = C::default();
+
+    for (key, entry) in (OrderedJoin { left: a.drain().peekable(), right: b.drain().peekable() }) {
+        if let Some(value) = f(&key, entry) {
+            c.insert(key, value);
+        }
+    }
+
+    c
+}
+
+/// Inserts the entries of the second map into the first map, combining the values of keys that
+/// are present in both maps with the given function.
+///
+/// The function is called with a mutable reference to the first map's value and the second map's
+/// value.
+pub fn union_with<A, B, F>(a: &mut A, mut b: B, mut f: F)
+    where A: ?Sized + Map + Mutate + AddRemove,
+          B: Base<Key = A::Key, Value = A::Value> + AddRemove,
+          F: FnMut(&A::Key, &mut A::Value, A::Value)
+{
+    for (key, other) in b.drain() {
+        match a.get_mut(&key) {
+            Some(value) => f(&key, value, other),
+            None => { a.insert(key, other); }
+        }
+    }
+}
+
+/// Returns a new map that contains the keys that are present in both of the given maps, with
+/// values that are the result of combining the maps' values with the given function.
+pub fn intersection_with<A, B, C, F>(a: A, b: B, mut f: F) -> C
+    where A: Map + AddRemove,
+          B: Map<A::Key, Key = A::Key> + AddRemove,
+          C: Base<Key = A::Key> + AddRemove + Default,
+          F: FnMut(&A::Key, A::Value, B::Value) -> C::Value
+{
+    merge(a, b, |key, entry| match entry {
+        EitherOrBoth::Both(value, other) => Some(f(key, value, other)),
+        _ => None,
+    })
+}
+
+/// An iterator that joins two iterators over entries that are sorted by key.
+struct OrderedJoin<I: Iterator, J: Iterator> {
+    left: Peekable<I>,
+    right: Peekable<J>,
+}
+
+impl<K: Ord, A, B, I, J> Iterator for OrderedJoin<I, J>
+    where I: Iterator<Item = (K, A)>, J: Iterator<Item = (K, B)>
+{
+    type Item = (K, EitherOrBoth<A, B>);
+
+    fn next(&mut self) -> Option<(K, EitherOrBoth<A, B>)> {
+        let ordering = match (self.left.peek(), self.right.peek()) {
+            (Some(&(ref key, _)), Some(&(ref other, _))) => key.cmp(other),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => return None,
+        };
+
+        match ordering {
+            Ordering::Less => self.left.next().map(|(key, value)| (key, EitherOrBoth::Left(value))),
+            Ordering::Greater => {
+                self.right.next().map(|(key, other)| (key, EitherOrBoth::Right(other)))
+            }
+            Ordering::Equal => {
+                let (key, value) = self.left.next().expect("peeked entry is missing");
+                let (_, other) = self.right.next().expect("peeked entry is missing");
+                Some((key, EitherOrBoth::Both(value, other)))
+            }
+        }
+    }
+}
+
+#[test]
+fn test_join() {
+    use std::collections::{BTreeMap, HashMap};
+
+    let a: BTreeMap<_, _> = vec![(1, 'a'), (2, 'b'), (4, 'd')].into_iter().collect();
+    let b: BTreeMap<_, _> = vec![(2, 'b'), (3, 'c'), (4, 'x')].into_iter().collect();
+
+    let expected = vec![
+        (&1, EitherOrBoth::Left(&'a')),
+        (&2, EitherOrBoth::Both(&'b', &'b')),
+        (&3, EitherOrBoth::Right(&'c')),
+        (&4, EitherOrBoth::Both(&'d', &'x')),
+    ];
+
+    assert_eq!(join_ordered(&a, &b).collect::<Vec<_>>(), expected);
+
+    let mut actual: Vec<_> = join(&a, &b).collect();
+    actual.sort_by_key(|entry| entry.0);
+    assert_eq!(actual, expected);
+
+    let h: HashMap<_, _> = b.clone().into_iter().collect();
+    let mut actual: Vec<_> = diff(&h, &a).collect();
+    actual.sort_by_key(|entry| entry.0);
+    assert_eq!(actual, [
+        (&1, EitherOrBoth::Right(&'a')),
+        (&3, EitherOrBoth::Left(&'c')),
+        (&4, EitherOrBoth::Both(&'x', &'d')),
+    ]);
+
+    let merged: BTreeMap<_, _> = merge_ordered(a.clone(), b.clone(), |_, entry| match entry {
+        EitherOrBoth::Both(value, _) => Some(value),
+        _ => None,
+    });
+    assert_eq!(merged, intersection_with(a.clone(), h, |_, value, _| value));
+
+    let mut union = a;
+    union_with(&mut union, b, |_, value, other| *value = other);
+    assert!(union.into_iter().eq(vec![(1, 'a'), (2, 'b'), (3, 'c'), (4, 'x')]));
+}
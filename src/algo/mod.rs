@@ -0,0 +1,11 @@
+//! Generic algorithms.
+//!
+//! The algorithms in this module are written against the collection traits, so they work with any
+//! implementor, including slices and the views in the [`list`] module. Where possible, they
+//! delegate to specialized routines (e.g. those on slices) when a collection exposes them.
+//!
+//! [`list`]: ../list/index.html
+
+pub mod map;
+pub mod search;
+pub mod sort;
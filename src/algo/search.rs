@@ -0,0 +1,188 @@
+//! Searching algorithms for sorted lists.
+//!
+//! Each algorithm assumes that the list is sorted according to the ordering it uses (e.g. by
+//! `Ord` for [`binary_search`]), and the results are unspecified if it is not. The searches
+//! perform `O(log n)` calls to [`List::get`], and delegate to the corresponding slice methods when
+//! a list's items are stored contiguously (as reported by [`List::contiguous`]).
+//!
+//! # Examples
+//!
+//! ```
+//! use eclectic::algo::search;
+//! use std::collections::VecDeque;
+//!
+//! let mut list: VecDeque<_> = vec![1, 3, 3, 5].into_iter().collect();
+//!
+//! assert_eq!(search::binary_search(&list, &5), Ok(3));
+//! assert_eq!(search::lower_bound(&list, &3), 1);
+//! assert_eq!(search::upper_bound(&list, &3), 3);
+//!
+//! search::insert_sorted(&mut list, 4);
+//! search::merge_sorted(&mut list, vec![0, 2, 6]);
+//! assert!(list.iter().eq(&[0, 1, 2, 3, 3, 4, 5, 6]));
+//! ```
+//!
+//! [`List::contiguous`]: ../../trait.List.html#method.contiguous
+//! [`List::get`]: ../../trait.List.html#tymethod.get
+//! [`binary_search`]: fn.binary_search.html
+
+use std::cmp::Ordering;
+use super::super::*;
+
+/// Searches the sorted list for the given item.
+///
+/// Returns `Ok(index)` if the list contains an item that is equal to the given item, where
+/// `index` is the index of that item. If there are several such items, any one of them may be
+/// returned. Returns `Err(index)` if the list contains no such item, where `index` is the index at
+/// which the item could be inserted while maintaining sorted order.
+pub fn binary_search<L: ?Sized + List>(list: &L, item: &L::Item) -> Result<usize, usize>
+    where L::Item: Ord
+{
+    binary_search_by(list, |probe| probe.cmp(item))
+}
+
+/// Searches the sorted list with the given comparison function.
+///
+/// The comparison function should return the ordering of its argument relative to the target
+/// (e.g. `Ordering::Less` if the argument precedes the target). The return value has the same
+/// meaning as that of [`binary_search`].
+///
+/// [`binary_search`]: fn.binary_search.html
+pub fn binary_search_by<L: ?Sized + List, F>(list: &L, mut f: F) -> Result<usize, usize>
+    where F: FnMut(&L::Item) -> Ordering
+{
+    if let Some(slice) = list.contiguous() {
+        return slice.binary_search_by(f);
+    }
+
+    let mut start = 0;
+    let mut end = list.len();
+
+    while start < end {
+        let mid = start + (end - start) / 2;
+
+        match f(list.get(mid).expect("index out of bounds")) {
+            Ordering::Less => start = mid + 1,
+            Ordering::Greater => end = mid,
+            Ordering::Equal => return Ok(mid),
+        }
+    }
+
+    Err(start)
+}
+
+/// Searches the sorted list for the given key with the given key extraction function.
+///
+/// The list should be sorted by the extracted keys. The return value has the same meaning as that
+/// of [`binary_search`].
+///
+/// [`binary_search`]: fn.binary_search.html
+pub fn binary_search_by_key<L: ?Sized + List, K: Ord, F>(list: &L, key: &K, mut f: F)
+    -> Result<usize, usize> where F: FnMut(&L::Item) -> K
+{
+    binary_search_by(list, |probe| f(probe).cmp(key))
+}
+
+/// Returns the index of the first item in the list for which the given predicate returns `false`.
+///
+/// The list should be partitioned according to the predicate (i.e. the predicate should return
+/// `true` for a prefix of the list and `false` for the remainder). Returns the list's length if
+/// the predicate returns `true` for all of its items.
+pub fn partition_point<L: ?Sized + List, P>(list: &L, mut pred: P) -> usize
+    where P: FnMut(&L::Item) -> bool
+{
+    if let Some(slice) = list.contiguous() {
+        return slice.partition_point(pred);
+    }
+
+    let mut start = 0;
+    let mut end = list.len();
+
+    while start < end {
+        let mid = start + (end - start) / 2;
+
+        if pred(list.get(mid).expect("index out of bounds")) {
+            start = mid + 1;
+        } else {
+            end = mid;
+        }
+    }
+
+    start
+}
+
+/// Returns the index of the first item in the sorted list that is not less than the given item.
+///
+/// Returns the list's length if all of its items are less than the given item.
+pub fn lower_bound<L: ?Sized + List>(list: &L, item: &L::Item) -> usize where L::Item: Ord {
+    partition_point(list, |probe| probe < item)
+}
+
+/// Returns the index of the first item in the sorted list that is greater than the given item.
+///
+/// Returns the list's length if none of its items are greater than the given item.
+pub fn upper_bound<L: ?Sized + List>(list: &L, item: &L::Item) -> usize where L::Item: Ord {
+    partition_point(list, |probe| probe <= item)
+}
+
+/// Inserts the given item into the sorted list, maintaining sorted order.
+///
+/// The item is inserted after any equal items, and the index at which it was inserted is
+/// returned.
+pub fn insert_sorted<L: ?Sized + List + AddRemove>(list: &mut L, item: L::Item) -> usize
+    where L::Item: Ord
+{
+    let index = upper_bound(list, &item);
+    list.insert(index, item);
+    index
+}
+
+/// Merges the given sorted items into the sorted list, maintaining sorted order.
+///
+/// The merge is stable: items that are already in the list precede any equal items that are
+/// merged into it. This performs `O(n + m)` comparisons, where `n` is the length of the list and
+/// `m` is the number of items being merged.
+pub fn merge_sorted<L: ?Sized + List + AddRemove, I>(list: &mut L, items: I)
+    where L::Item: Ord, I: IntoIterator<Item = L::Item>
+{
+    let items = items.into_iter();
+
+    let mut merged = Vec::with_capacity(list.len() + items.size_hint().0);
+    let mut items = items.peekable();
+
+    for item in list.drain_range(..) {
+        while let Some(other) = items.next_if(|other| *other < item) {
+            merged.push(other);
+        }
+
+        merged.push(item);
+    }
+
+    merged.extend(items);
+    list.extend_object(&mut merged.into_iter());
+}
+
+#[test]
+fn test_search_generic() {
+    use std::collections::LinkedList;
+
+    let items = vec![1, 3, 3, 3, 5, 7];
+    let mut list: LinkedList<_> = items.iter().cloned().collect();
+
+    for item in 0..9 {
+        match binary_search(&list, &item) {
+            Ok(index) => assert_eq!(list.get(index), Some(&item)),
+            Err(index) => {
+                assert!(!items.contains(&item));
+                assert_eq!(index, lower_bound(&list, &item));
+            }
+        }
+
+        assert_eq!(lower_bound(&list, &item), items.iter().filter(|&&i| i < item).count());
+        assert_eq!(upper_bound(&list, &item), items.iter().filter(|&&i| i <= item).count());
+    }
+
+    assert_eq!(insert_sorted(&mut list, 3), 4);
+    merge_sorted(&mut list, vec![0, 3, 8]);
+    assert!(list.iter().eq(&[0, 1, 3, 3, 3, 3, 3, 5, 7, 8]));
+}
@@ -0,0 +1,662 @@
+//! Sorting algorithms.
+//!
+//! Each algorithm sorts any list that supports the mutation of its items, using only
+//! [`List::get`] and [`List::swap`]. When a list's items are stored contiguously (as reported by
+//! [`List::contiguous_mut`]), the stable and unstable sorts delegate to the corresponding slice
+//! methods instead.
+//!
+//! # Examples
+//!
+//! ```
+//! use eclectic::List;
+//! use eclectic::algo::sort;
+//! use std::collections::LinkedList;
+//!
+//! let mut list: LinkedList<_> = vec![5, 1, 4, 2, 3].into_iter().collect();
+//!
+//! sort::sort(&mut list.slice_mut(1..4));
+//! assert!(list.iter().eq(&[5, 1, 2, 4, 3]));
+//!
+//! sort::sort_unstable_by_key(&mut list, |&item| -item);
+//! assert!(list.iter().eq(&[5, 4, 3, 2, 1]));
+//! ```
+//!
+//! [`List::contiguous_mut`]: ../../trait.List.html#method.contiguous_mut
+//! [`List::get`]: ../../trait.List.html#tymethod.get
+//! [`List::swap`]: ../../trait.List.html#tymethod.swap
+
+use std::cmp::{self, Ordering};
+use std::mem;
+use super::super::*;
+
+/// Sorts the list.
+///
+/// This sort is stable (i.e. it does not reorder equal items) and performs `O(n log n)`
+/// comparisons and `O(n log^2 n)` swaps in the worst case. It does not allocate unless the list
+/// is stored contiguously (see [`sort_by`]).
+///
+/// [`sort_by`]: fn.sort_by.html
+pub fn sort<L: ?Sized + List + Mutate>(list: &mut L) where L::Item: Ord {
+    sort_by(list, Ord::cmp);
+}
+
+/// Sorts the list with the given comparison function.
+///
+/// This sort is stable (i.e. it does not reorder equal items) and performs `O(n log n)`
+/// comparisons and `O(n log^2 n)` swaps in the worst case.
+///
+/// If the list is stored contiguously, it is sorted with [`slice::sort_by`], which allocates a
+/// buffer of up to half the list's length. Otherwise, the sort does not allocate.
+///
+/// [`slice::sort_by`]: https://doc.rust-lang.org/stable/std/primitive.slice.html#method.sort_by
+pub fn sort_by<L: ?Sized + List + Mutate, F>(list: &mut L, mut compare: F)
+    where F: FnMut(&L::Item, &L::Item) -> Ordering
+{
+    if let Some(slice) = list.contiguous_mut() {
+        slice.sort_by(compare);
+        return;
+    }
+
+    merge_sort(list, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// Sorts the list with the given key extraction function.
+///
+/// This sort is stable (i.e. it does not reorder equal items) and performs `O(n log n)`
+/// comparisons and `O(n log^2 n)` swaps in the worst case. Like [`sort_by`], it allocates only
+/// when the list is stored contiguously.
+///
+/// [`sort_by`]: fn.sort_by.html
+pub fn sort_by_key<L: ?Sized + List + Mutate, K: Ord, F>(list: &mut L, mut f: F)
+    where F: FnMut(&L::Item) -> K
+{
+    sort_by(list, |a, b| f(a).cmp(&f(b)));
+}
+
+/// Sorts the list, but may not preserve the order of equal items.
+///
+/// This sort is an in-place pattern-defeating quicksort, which performs `O(n log n)` comparisons
+/// and swaps in the worst case and runs in linear time on many common input patterns (e.g.
+/// sorted, reverse-sorted and all-equal lists).
+pub fn sort_unstable<L: ?Sized + List + Mutate>(list: &mut L) where L::Item: Ord {
+    sort_unstable_by(list, Ord::cmp);
+}
+
+/// Sorts the list with the given comparison function, but may not preserve the order of equal
+/// items.
+///
+/// This sort is an in-place pattern-defeating quicksort, which performs `O(n log n)` comparisons
+/// and swaps in the worst case and runs in linear time on many common input patterns (e.g.
+/// sorted, reverse-sorted and all-equal lists).
+pub fn sort_unstable_by<L: ?Sized + List + Mutate, F>(list: &mut L, mut compare: F)
+    where F: FnMut(&L::Item, &L::Item) -> Ordering
+{
+    if let Some(slice) = list.contiguous_mut() {
+        slice.sort_unstable_by(compare);
+        return;
+    }
+
+    let len = list.len();
+    let limit = mem::size_of::<usize>() * 8 - len.leading_zeros() as usize;
+    quicksort(list, 0, len, &mut |a, b| compare(a, b) == Ordering::Less, None, limit);
+}
+
+/// Sorts the list with the given key extraction function, but may not preserve the order of
+/// equal items.
+///
+/// This sort is an in-place pattern-defeating quicksort, which performs `O(n log n)` comparisons
+/// and swaps in the worst case and runs in linear time on many common input patterns (e.g.
+/// sorted, reverse-sorted and all-equal lists).
+pub fn sort_unstable_by_key<L: ?Sized + List + Mutate, K: Ord, F>(list: &mut L, mut f: F)
+    where F: FnMut(&L::Item) -> K
+{
+    sort_unstable_by(list, |a, b| f(a).cmp(&f(b)));
+}
+
+/// Sorts the list using heapsort.
+///
+/// This sort is unstable (i.e. it may reorder equal items) and performs `O(n log n)` comparisons
+/// and swaps in the worst case. It does not allocate.
+pub fn heapsort<L: ?Sized + List + Mutate>(list: &mut L) where L::Item: Ord {
+    heapsort_by(list, Ord::cmp);
+}
+
+/// Sorts the list with the given comparison function using heapsort.
+///
+/// This sort is unstable (i.e. it may reorder equal items) and performs `O(n log n)` comparisons
+/// and swaps in the worst case. It does not allocate.
+pub fn heapsort_by<L: ?Sized + List + Mutate, F>(list: &mut L, mut compare: F)
+    where F: FnMut(&L::Item, &L::Item) -> Ordering
+{
+    let mut is_less = |a: &L::Item, b: &L::Item| compare(a, b) == Ordering::Less;
+
+    if let Some(slice) = list.contiguous_mut() {
+        let len = slice.len();
+        heapsort_range(slice, 0, len, &mut is_less);
+        return;
+    }
+
+    let len = list.len();
+    heapsort_range(list, 0, len, &mut is_less);
+}
+
+/// The length of the blocks that the merge sort initially sorts with insertion sort.
+const MERGE_BLOCK_LEN: usize = 20;
+
+/// The length of the ranges that the quicksort sorts with insertion sort.
+const MAX_INSERTION: usize = 20;
+
+/// The minimum length of a range for which the quicksort chooses a pivot from nine samples.
+const SHORTEST_NINTHER: usize = 50;
+
+/// The minimum length of a range for which the quicksort shifts out-of-order items.
+const SHORTEST_SHIFTING: usize = 50;
+
+/// The maximum number of out-of-order pairs that the quicksort shifts before giving up.
+const MAX_SHIFTING_STEPS: usize = 5;
+
+fn item<L: ?Sized + List>(list: &L, index: usize) -> &L::Item {
+    list.get(index).expect("index out of bounds")
+}
+
+fn less<L: ?Sized + List, F>(list: &L, is_less: &mut F, i: usize, j: usize) -> bool
+    where F: FnMut(&L::Item, &L::Item) -> bool
+{
+    is_less(item(list, i), item(list, j))
+}
+
+/// Sorts the items in `start..end` with insertion sort.
+fn insertion_sort<L: ?Sized + List + Mutate, F>(list: &mut L, start: usize, end: usize,
+                                                 is_less: &mut F)
+    where F: FnMut(&L::Item, &L::Item) -> bool
+{
+    for i in start + 1..end {
+        let mut j = i;
+
+        while j > start && less(list, is_less, j, j - 1) {
+            list.swap(j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+/// Swaps the items in `a..a + len` with the items in `b..b + len`.
+fn swap_range<L: ?Sized + List + Mutate>(list: &mut L, a: usize, b: usize, len: usize) {
+    for i in 0..len {
+        list.swap(a + i, b + i);
+    }
+}
+
+/// Rotates the items in `start..end` so that the item at `mid` becomes the item at `start`.
+fn rotate<L: ?Sized + List + Mutate>(list: &mut L, start: usize, mid: usize, end: usize) {
+    let mut i = mid - start;
+    let mut j = end - mid;
+
+    while i != j {
+        if i > j {
+            swap_range(list, mid - i, mid, j);
+            i -= j;
+        } else {
+            swap_range(list, mid - i, mid + j - i, i);
+            j -= i;
+        }
+    }
+
+    swap_range(list, mid - i, mid, i);
+}
+
+/// Sorts the list with a bottom-up merge sort that merges in place.
+fn merge_sort<L: ?Sized + List + Mutate, F>(list: &mut L, is_less: &mut F)
+    where F: FnMut(&L::Item, &L::Item) -> bool
+{
+    let len = list.len();
+
+    let mut start = 0;
+
+    while start < len {
+        let end = cmp::min(start + MERGE_BLOCK_LEN, len);
+        insertion_sort(list, start, end, is_less);
+        start = end;
+    }
+
+    let mut block_len = MERGE_BLOCK_LEN;
+
+    while block_len < len {
+        let mut start = 0;
+
+        while start + block_len < len {
+            let end = cmp::min(start + 2 * block_len, len);
+            merge(list, start, start + block_len, end, is_less);
+            start = end;
+        }
+
+        block_len *= 2;
+    }
+}
+
+/// Merges the sorted ranges `start..mid` and `mid..end` in place.
+///
+/// This is the SymMerge algorithm of Kim and Kutzner, "Stable Minimum Storage Merging by
+/// Symmetric Comparisons".
+fn merge<L: ?Sized + List + Mutate, F>(list: &mut L, start: usize, mid: usize, end: usize,
+                                        is_less: &mut F)
+    where F: FnMut(&L::Item, &L::Item) -> bool
+{
+    if mid - start == 1 {
+        // insert the item at `start` into `mid..end`
+        let (mut i, mut j) = (mid, end);
+
+        while i < j {
+            let h = i + (j - i) / 2;
+            if less(list, is_less, h, start) { i = h + 1; } else { j = h; }
+        }
+
+        for k in start..i - 1 {
+            list.swap(k, k + 1);
+        }
+
+        return;
+    }
+
+    if end - mid == 1 {
+        // insert the item at `mid` into `start..mid`
+        let (mut i, mut j) = (start, mid);
+
+        while i < j {
+            let h = i + (j - i) / 2;
+            if !less(list, is_less, mid, h) { i = h + 1; } else { j = h; }
+        }
+
+        for k in (i + 1..mid + 1).rev() {
+            list.swap(k, k - 1);
+        }
+
+        return;
+    }
+
+    let half = start + (end - start) / 2;
+    let n = half + mid;
+
+    let (mut lo, mut hi) = if mid > half { (n - end, half) } else { (start, mid) };
+    let p = n - 1;
+
+    while lo < hi {
+        let c = lo + (hi - lo) / 2;
+        if !less(list, is_less, p - c, c) { lo = c + 1; } else { hi = c; }
+    }
+
+    let split = n - lo;
+
+    if lo < mid && mid < split {
+        rotate(list, lo, mid, split);
+    }
+
+    if start < lo && lo < half {
+        merge(list, start, lo, half, is_less);
+    }
+
+    if half < split && split < end {
+        merge(list, half, split, end, is_less);
+    }
+}
+
+/// Restores the heap property of the heap in `start..start + len` at the given node.
+fn sift_down<L: ?Sized + List + Mutate, F>(list: &mut L, start: usize, len: usize,
+                                            mut node: usize, is_less: &mut F)
+    where F: FnMut(&L::Item, &L::Item) -> bool
+{
+    loop {
+        let mut child = 2 * node + 1;
+
+        if child >= len {
+            break;
+        }
+
+        if child + 1 < len && less(list, is_less, start + child, start + child + 1) {
+            child += 1;
+        }
+
+        if !less(list, is_less, start + node, start + child) {
+            break;
+        }
+
+        list.swap(start + node, start + child);
+        node = child;
+    }
+}
+
+/// Sorts the items in `start..end` with heapsort.
+fn heapsort_range<L: ?Sized + List + Mutate, F>(list: &mut L, start: usize, end: usize,
+                                                 is_less: &mut F)
+    where F: FnMut(&L::Item, &L::Item) -> bool
+{
+    let len = end - start;
+
+    for node in (0..len / 2).rev() {
+        sift_down(list, start, len, node, is_less);
+    }
+
+    for last in (1..len).rev() {
+        list.swap(start, start + last);
+        sift_down(list, start, last, 0, is_less);
+    }
+}
+
+/// Reverses the items in `start..end`.
+fn reverse_range<L: ?Sized + List + Mutate>(list: &mut L, start: usize, end: usize) {
+    let len = end - start;
+
+    for i in 0..len / 2 {
+        list.swap(start + i, end - 1 - i);
+    }
+}
+
+/// Partially sorts the items in `start..end` by shifting a few out-of-order items.
+///
+/// Returns `true` if the range is sorted afterwards.
+fn partial_insertion_sort<L: ?Sized + List + Mutate, F>(list: &mut L, start: usize, end: usize,
+                                                         is_less: &mut F) -> bool
+    where F: FnMut(&L::Item, &L::Item) -> bool
+{
+    let mut i = start + 1;
+
+    for _ in 0..MAX_SHIFTING_STEPS {
+        while i < end && !less(list, is_less, i, i - 1) {
+            i += 1;
+        }
+
+        if i == end {
+            return true;
+        }
+
+        if end - start < SHORTEST_SHIFTING {
+            return false;
+        }
+
+        list.swap(i - 1, i);
+
+        let mut j = i - 1;
+
+        while j > start && less(list, is_less, j, j - 1) {
+            list.swap(j, j - 1);
+            j -= 1;
+        }
+
+        let mut j = i;
+
+        while j + 1 < end && less(list, is_less, j + 1, j) {
+            list.swap(j, j + 1);
+            j += 1;
+        }
+    }
+
+    false
+}
+
+/// Scatters some items in `start..end` in order to break patterns that cause imbalanced
+/// partitions.
+fn break_patterns<L: ?Sized + List + Mutate>(list: &mut L, start: usize, end: usize) {
+    let len = end - start;
+
+    if len < 8 {
+        return;
+    }
+
+    // xorshift
+    let mut seed = len as u32;
+    let mut random = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed as usize
+    };
+
+    let mask = len.next_power_of_two() - 1;
+    let pos = start + len / 4 * 2;
+
+    for i in 0..3 {
+        let mut other = random() & mask;
+        if other >= len { other -= len; }
+        list.swap(pos - 1 + i, start + other);
+    }
+}
+
+/// Chooses a pivot in `start..end` and returns its index.
+///
+/// Also returns `true` if the range is likely to be sorted.
+fn choose_pivot<L: ?Sized + List + Mutate, F>(list: &mut L, start: usize, end: usize,
+                                               is_less: &mut F) -> (usize, bool)
+    where F: FnMut(&L::Item, &L::Item) -> bool
+{
+    const MAX_SWAPS: usize = 4 * 3;
+
+    let len = end - start;
+
+    let mut a = start + len / 4;
+    let mut b = start + len / 4 * 2;
+    let mut c = start + len / 4 * 3;
+    let mut swaps = 0;
+
+    {
+        let mut sort2 = |a: &mut usize, b: &mut usize| {
+            if less(list, is_less, *b, *a) {
+                mem::swap(a, b);
+                swaps += 1;
+            }
+        };
+
+        let mut sort3 = |a: &mut usize, b: &mut usize, c: &mut usize| {
+            sort2(a, b);
+            sort2(b, c);
+            sort2(a, b);
+        };
+
+        if len >= SHORTEST_NINTHER {
+            for x in &mut [&mut a, &mut b, &mut c] {
+                let (mut prev, mut next) = (**x - 1, **x + 1);
+                sort3(&mut prev, *x, &mut next);
+            }
+        }
+
+        sort3(&mut a, &mut b, &mut c);
+    }
+
+    if swaps < MAX_SWAPS {
+        (b, swaps == 0)
+    } else {
+        // the range is likely to be descending, so reverse it
+        reverse_range(list, start, end);
+        (start + end - 1 - b, true)
+    }
+}
+
+/// Partitions the items in `start..end` around the pivot at `start`.
+///
+/// Returns the pivot's final index, and `true` if the range was already partitioned.
+fn partition<L: ?Sized + List + Mutate, F>(list: &mut L, start: usize, end: usize,
+                                            is_less: &mut F) -> (usize, bool)
+    where F: FnMut(&L::Item, &L::Item) -> bool
+{
+    let mut l = start + 1;
+    let mut r = end;
+    let mut was_partitioned = true;
+
+    loop {
+        while l < r && less(list, is_less, l, start) {
+            l += 1;
+        }
+
+        while l < r && !less(list, is_less, r - 1, start) {
+            r -= 1;
+        }
+
+        if l >= r {
+            break;
+        }
+
+        r -= 1;
+        list.swap(l, r);
+        l += 1;
+        was_partitioned = false;
+    }
+
+    list.swap(start, l - 1);
+    (l - 1, was_partitioned)
+}
+
+/// Partitions the items in `start..end` into those that are equal to the pivot at `start` and
+/// those that are greater than it, assuming that no item is less than the pivot.
+///
+/// Returns the number of items that are equal to the pivot.
+fn partition_equal<L: ?Sized + List + Mutate, F>(list: &mut L, start: usize, end: usize,
+                                                  is_less: &mut F) -> usize
+    where F: FnMut(&L::Item, &L::Item) -> bool
+{
+    let mut l = start + 1;
+    let mut r = end;
+
+    loop {
+        while l < r && !less(list, is_less, start, l) {
+            l += 1;
+        }
+
+        while l < r && less(list, is_less, start, r - 1) {
+            r -= 1;
+        }
+
+        if l >= r {
+            break;
+        }
+
+        r -= 1;
+        list.swap(l, r);
+        l += 1;
+    }
+
+    l - start
+}
+
+/// Sorts the items in `start..end` with pattern-defeating quicksort.
+///
+/// `pred` is the index of an item that precedes the range and is not greater than any item in
+/// it, if any. `limit` is the number of imbalanced partitions that are allowed before switching
+/// to heapsort.
+fn quicksort<L: ?Sized + List + Mutate, F>(list: &mut L, mut start: usize, mut end: usize,
+                                            is_less: &mut F, mut pred: Option<usize>,
+                                            mut limit: usize)
+    where F: FnMut(&L::Item, &L::Item) -> bool
+{
+    let mut was_balanced = true;
+    let mut was_partitioned = true;
+
+    loop {
+        let len = end - start;
+
+        if len <= MAX_INSERTION {
+            insertion_sort(list, start, end, is_less);
+            return;
+        }
+
+        if limit == 0 {
+            heapsort_range(list, start, end, is_less);
+            return;
+        }
+
+        if !was_balanced {
+            break_patterns(list, start, end);
+            limit -= 1;
+        }
+
+        let (pivot, likely_sorted) = choose_pivot(list, start, end, is_less);
+
+        if was_balanced && was_partitioned && likely_sorted &&
+           partial_insertion_sort(list, start, end, is_less) {
+            return;
+        }
+
+        if let Some(pred) = pred {
+            if !less(list, is_less, pred, pivot) {
+                // the pivot is equal to its predecessor, so it is the smallest item in the range
+                list.swap(start, pivot);
+                start += partition_equal(list, start, end, is_less);
+                continue;
+            }
+        }
+
+        list.swap(start, pivot);
+        let (mid, partitioned) = partition(list, start, end, is_less);
+
+        let (left_len, right_len) = (mid - start, end - mid - 1);
+        was_balanced = cmp::min(left_len, right_len) >= len / 8;
+        was_partitioned = partitioned;
+
+        if left_len < right_len {
+            quicksort(list, start, mid, is_less, pred, limit);
+            start = mid + 1;
+            pred = Some(mid);
+        } else {
+            quicksort(list, mid + 1, end, is_less, Some(mid), limit);
+            end = mid;
+        }
+    }
+}
+
+#[cfg(test)]
+fn random_items(len: usize, modulus: u32) -> Vec<(u32, usize)> {
+    let mut seed = 0x2545_f491_u32;
+
+    (0..len).map(|i| {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        (seed % modulus, i)
+    }).collect()
+}
+
+#[test]
+fn test_sort_generic() {
+    use std::collections::LinkedList;
+
+    for &len in &[0, 1, 2, 19, 20, 21, 100, 1000] {
+        for &modulus in &[1, 4, 1000] {
+            let items = random_items(len, modulus);
+
+            let mut expected = items.clone();
+            expected.sort_by_key(|item| item.0);
+
+            let mut reversed: Vec<_> = items.clone().into_iter().rev().collect();
+            sort_by_key(&mut list::RevMut::new(&mut reversed), |item| item.0);
+            assert!(list::Rev::new(&reversed).iter().eq(&expected));
+
+            expected.sort();
+
+            let mut list: LinkedList<_> = items.iter().cloned().collect();
+            sort_unstable(&mut list);
+            assert!(list.iter().eq(&expected));
+
+            let mut actual = items.clone();
+            heapsort_by(&mut list::RevMut::new(&mut actual), |a, b| b.cmp(a));
+            assert_eq!(actual, expected);
+
+            let mut actual = expected.clone();
+            sort_unstable(&mut list::RevMut::new(&mut actual));
+            actual.reverse();
+            assert_eq!(actual, expected);
+        }
+    }
+}
+
+#[test]
+fn test_sort_contiguous() {
+    use std::collections::VecDeque;
+
+    let items = random_items(100, 10);
+
+    let mut expected = items.clone();
+    expected.sort_by_key(|item| item.0);
+
+    let mut list = VecDeque::new();
+    for item in items.into_iter().rev() { list.push_front(item); }
+    sort_by_key(&mut list, |item| item.0);
+    assert!(list.iter().eq(&expected));
+}
@@ -0,0 +1,455 @@
+use std::error::Error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem::MaybeUninit;
+use std::ops::Range;
+use std::ptr;
+use std::slice;
+
+use super::super::*;
+
+/// A list that stores up to `N` items inline, without allocating.
+///
+/// The capacity of an `ArrayVec` is always `N`. The fallible methods [`try_push`] and
+/// [`try_insert`] return a [`CapacityError`] when the list is full, while the methods of the
+/// collection traits that insert items (e.g. [`List::push`]) panic instead.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::List;
+/// use eclectic::collections::ArrayVec;
+///
+/// let mut list = ArrayVec::<_, 3>::new();
+/// list.push(1);
+/// list.insert(0, 0);
+/// assert_eq!(list.try_push(2), Ok(()));
+///
+/// assert!(list.is_full());
+/// assert_eq!(list.try_push(3).unwrap_err().into_inner(), 3);
+///
+/// assert_eq!(list.remove(1), Some(1));
+/// assert_eq!(list.as_slice(), [0, 2]);
+/// ```
+///
+/// [`CapacityError`]: struct.CapacityError.html
+/// [`List::push`]: ../trait.List.html#method.push
+/// [`try_insert`]: #method.try_insert
+/// [`try_push`]: #method.try_push
+pub struct ArrayVec<T, const N: usize> {
+    items: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayVec<T, N> {
+    /// Returns a new, empty list.
+    pub fn new() -> Self {
+        ArrayVec { items: [const { MaybeUninit::uninit() }; N], len: 0 }
+    }
+
+    /// Checks if the list contains `N` items.
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Pushes the given item onto the back of the list.
+    ///
+    /// Returns an error containing the item if the list is full.
+    pub fn try_push(&mut self, item: T) -> Result<(), CapacityError<T>> {
+        let len = self.len;
+        self.try_insert(len, item)
+    }
+
+    /// Inserts the given item into the list at the given index.
+    ///
+    /// Returns an error containing the item if the list is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > self.len()`.
+    pub fn try_insert(&mut self, index: usize, item: T) -> Result<(), CapacityError<T>> {
+        assert!(index <= self.len, "index out of bounds");
+
+        if self.is_full() {
+            return Err(CapacityError { item: item });
+        }
+
+        unsafe {
+            let ptr = self.as_mut_ptr().add(index);
+            ptr::copy(ptr, ptr.add(1), self.len - index);
+            ptr::write(ptr, item);
+        }
+
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Returns a slice that contains the list's items.
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.items.as_ptr() as *const T, self.len) }
+    }
+
+    /// Returns a mutable slice that contains the list's items.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.items.as_mut_ptr() as *mut T
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayVec<T, N> {
+    fn clone(&self) -> Self {
+        let mut list = Self::new();
+
+        for item in self.as_slice() {
+            list.push(item.clone());
+        }
+
+        list
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<T, const N: usize> Default for ArrayVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayVec<T, N> {
+    fn drop(&mut self) {
+        self.truncate(0);
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayVec<T, N> {}
+
+impl<T: Hash, const N: usize> Hash for ArrayVec<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for ArrayVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T, const N: usize> Mutate for ArrayVec<T, N> {}
+
+impl<T, const N: usize> AddRemove for ArrayVec<T, N> {}
+
+impl<T, const N: usize> Collection for ArrayVec<T, N> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn extend_object(&mut self, items: &mut Iterator<Item = T>) {
+        for item in items {
+            self.push(item);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = T> + 'a> {
+        let len = self.len;
+        self.drain_range(0..len)
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn shrink_to_fit(&mut self) {}
+
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::new()
+    }
+
+    fn into_vec(mut self) -> Vec<T> {
+        self.drain().collect()
+    }
+}
+
+impl<T, const N: usize> Iter for ArrayVec<T, N> {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a T> + 'a> {
+        Box::new(self.as_slice().iter())
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut T> + 'a> {
+        Box::new(self.as_mut_slice().iter_mut())
+    }
+}
+
+impl<T, const N: usize> DrainRange<Range<usize>> for ArrayVec<T, N> {
+    fn drain_range<'a>(&'a mut self, range: Range<usize>) -> Box<Iterator<Item = T> + 'a> {
+        assert!(range.start <= range.end, "range start is greater than range end");
+        assert!(range.end <= self.len, "range end out of bounds");
+
+        let tail_len = self.len - range.end;
+
+        // the list is truncated first so that the drained items and the tail are leaked instead of
+        // dropped twice if the iterator is leaked
+        self.len = range.start;
+
+        Box::new(Drain {
+            list: self,
+            index: range.start,
+            end: range.end,
+            tail_len: tail_len,
+        })
+    }
+}
+
+impl<T, const N: usize> List for ArrayVec<T, N> {
+    fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.as_mut_slice().swap(i, j);
+    }
+
+    fn reverse(&mut self) {
+        self.as_mut_slice().reverse();
+    }
+
+    fn rotate_left(&mut self, mid: usize) {
+        self.as_mut_slice().rotate_left(mid);
+    }
+
+    fn rotate_right(&mut self, k: usize) {
+        self.as_mut_slice().rotate_right(k);
+    }
+
+    fn insert(&mut self, index: usize, item: T) {
+        if self.try_insert(index, item).is_err() {
+            panic!("capacity exceeded");
+        }
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        if self.len == 0 { return None; }
+
+        self.len -= 1;
+        Some(unsafe { ptr::read(self.as_mut_ptr().add(self.len)) })
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len { return None; }
+
+        let item = unsafe {
+            let ptr = self.as_mut_ptr().add(index);
+            let item = ptr::read(ptr);
+            ptr::copy(ptr.add(1), ptr, self.len - index - 1);
+            item
+        };
+
+        self.len -= 1;
+        Some(item)
+    }
+
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len { return None; }
+
+        let len = self.len;
+        self.as_mut_slice().swap(index, len - 1);
+        self.pop()
+    }
+
+    fn truncate(&mut self, len: usize) {
+        // the length is updated first so that a panicking destructor leaks the remaining items
+        // instead of dropping them twice
+        while self.len > len {
+            self.len -= 1;
+            unsafe { ptr::drop_in_place(self.as_mut_ptr().add(self.len)); }
+        }
+    }
+
+    fn split_off(&mut self, index: usize) -> Self {
+        assert!(index <= self.len, "index out of bounds");
+
+        let mut tail = Self::new();
+
+        unsafe {
+            ptr::copy_nonoverlapping(self.as_mut_ptr().add(index), tail.as_mut_ptr(),
+                                     self.len - index);
+        }
+
+        tail.len = self.len - index;
+        self.len = index;
+        tail
+    }
+
+    fn contiguous(&self) -> Option<&[T]> {
+        Some(self.as_slice())
+    }
+
+    fn contiguous_mut(&mut self) -> Option<&mut [T]> {
+        Some(self.as_mut_slice())
+    }
+}
+
+impl<T, const N: usize> Segmented for ArrayVec<T, N> {
+    fn as_slices(&self) -> (&[T], &[T]) {
+        (self.as_slice(), &[])
+    }
+
+    fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        (self.as_mut_slice(), &mut [])
+    }
+
+    fn make_contiguous(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> Contiguous for ArrayVec<T, N> {
+    fn as_slice(&self) -> &[T] {
+        self.as_slice()
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+/// A draining iterator over a range of an `ArrayVec`.
+///
+/// The list's length is set to the start of the range while the iterator is alive, and the items
+/// after the range are moved back into place when the iterator is dropped.
+struct Drain<'a, T: 'a, const N: usize> {
+    list: &'a mut ArrayVec<T, N>,
+    /// The index of the next item to yield from the front.
+    index: usize,
+    /// The end of the range, which is where the tail starts.
+    end: usize,
+    tail_len: usize,
+}
+
+impl<'a, T, const N: usize> Iterator for Drain<'a, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.index == self.end { return None; }
+
+        self.index += 1;
+        Some(unsafe { ptr::read(self.list.as_mut_ptr().add(self.index - 1)) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, const N: usize> Drop for Drain<'a, T, N> {
+    fn drop(&mut self) {
+        // a panicking destructor leaks the tail, because the list's length does not include it yet
+        for _ in self.by_ref() {}
+
+        unsafe {
+            let ptr = self.list.as_mut_ptr();
+            ptr::copy(ptr.add(self.end), ptr.add(self.list.len), self.tail_len);
+        }
+
+        self.list.len += self.tail_len;
+    }
+}
+
+/// An error indicating that an item could not be inserted into a full [`ArrayVec`].
+///
+/// [`ArrayVec`]: struct.ArrayVec.html
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct CapacityError<T> {
+    item: T,
+}
+
+impl<T> CapacityError<T> {
+    /// Consumes the error and returns the item that could not be inserted.
+    pub fn into_inner(self) -> T {
+        self.item
+    }
+}
+
+impl<T> fmt::Debug for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CapacityError").finish()
+    }
+}
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("capacity exceeded")
+    }
+}
+
+impl<T> Error for CapacityError<T> {}
+
+#[test]
+fn test_array_vec() {
+    use std::rc::Rc;
+
+    let item = Rc::new(());
+
+    {
+        let mut list = ArrayVec::<_, 4>::new();
+        list.extend_object(&mut (0..3).map(|_| item.clone()));
+        assert_eq!(Rc::strong_count(&item), 4);
+
+        assert_eq!(list.drain_range(1..2).count(), 1);
+        assert_eq!(list.len(), 2);
+        assert_eq!(Rc::strong_count(&item), 3);
+
+        let clone = list.clone();
+        assert_eq!(clone, list);
+        assert_eq!(Rc::strong_count(&item), 5);
+    }
+
+    assert_eq!(Rc::strong_count(&item), 1);
+
+    let mut list = ArrayVec::<_, 4>::new();
+    list.extend_object(&mut vec![3, 1, 2].into_iter());
+    algo::sort::sort(&mut list);
+    assert_eq!(list.swap_remove(0), Some(1));
+    assert_eq!(list.into_vec(), [3, 2]);
+
+    let mut list = ArrayVec::<_, 6>::new();
+    list.extend_object(&mut (0..6).map(|i| i.to_string()));
+
+    {
+        let mut drain = list.drain_range(1..5);
+        assert_eq!(drain.next().unwrap(), "1");
+        assert_eq!(drain.next().unwrap(), "2");
+    }
+
+    assert_eq!(list.as_slice(), ["0", "5"]);
+    assert_eq!(list.split_off(1).as_slice(), ["5"]);
+    assert_eq!(list.as_slice(), ["0"]);
+}
+
+#[test]
+#[should_panic]
+fn test_array_vec_overflow() {
+    let mut list = ArrayVec::<_, 1>::new();
+    list.extend_object(&mut vec![1, 2].into_iter());
+}
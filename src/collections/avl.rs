@@ -0,0 +1,277 @@
+//! A persistent AVL tree whose nodes record the number of items in their subtrees.
+//!
+//! Nodes are shared between trees through `Rc`s. Operations that change a tree copy the shared
+//! nodes on the path to the change (path copying), so they never affect other trees that share
+//! those nodes. Every operation is expressed in terms of indices, which lets the same tree back
+//! both lists and ordered maps.
+
+use std::cmp::{self, Ordering};
+use std::rc::Rc;
+
+/// A possibly empty tree.
+pub type Tree<T> = Option<Rc<Node<T>>>;
+
+#[derive(Clone)]
+pub struct Node<T> {
+    left: Tree<T>,
+    item: T,
+    right: Tree<T>,
+    height: usize,
+    len: usize,
+}
+
+/// Returns the number of items in the tree.
+pub fn len<T>(tree: &Tree<T>) -> usize {
+    tree.as_ref().map_or(0, |node| node.len)
+}
+
+fn height<T>(tree: &Tree<T>) -> usize {
+    tree.as_ref().map_or(0, |node| node.height)
+}
+
+fn node<T>(left: Tree<T>, item: T, right: Tree<T>) -> Tree<T> {
+    let height = cmp::max(height(&left), height(&right)) + 1;
+    let len = len(&left) + len(&right) + 1;
+    Some(Rc::new(Node { left: left, item: item, right: right, height: height, len: len }))
+}
+
+/// Takes the given node apart, copying it if it is shared.
+fn unwrap<T: Clone>(node: Rc<Node<T>>) -> (Tree<T>, T, Tree<T>) {
+    match Rc::try_unwrap(node) {
+        Ok(node) => (node.left, node.item, node.right),
+        Err(node) => (node.left.clone(), node.item.clone(), node.right.clone()),
+    }
+}
+
+/// Returns a tree that consists of the given subtrees and item, whose heights differ by at most
+/// two.
+fn balance<T: Clone>(left: Tree<T>, item: T, right: Tree<T>) -> Tree<T> {
+    if height(&left) > height(&right) + 1 {
+        let (ll, l, lr) = unwrap(left.expect("left subtree is empty"));
+
+        if height(&ll) >= height(&lr) {
+            node(ll, l, node(lr, item, right))
+        } else {
+            let (lrl, lr, lrr) = unwrap(lr.expect("left subtree's right subtree is empty"));
+            node(node(ll, l, lrl), lr, node(lrr, item, right))
+        }
+    } else if height(&right) > height(&left) + 1 {
+        let (rl, r, rr) = unwrap(right.expect("right subtree is empty"));
+
+        if height(&rr) >= height(&rl) {
+            node(node(left, item, rl), r, rr)
+        } else {
+            let (rll, rl, rlr) = unwrap(rl.expect("right subtree's left subtree is empty"));
+            node(node(left, item, rll), rl, node(rlr, r, rr))
+        }
+    } else {
+        node(left, item, right)
+    }
+}
+
+/// Returns a tree that contains the items of `left`, followed by `item`, followed by the items of
+/// `right`.
+pub fn join<T: Clone>(left: Tree<T>, item: T, right: Tree<T>) -> Tree<T> {
+    if height(&left) > height(&right) + 1 {
+        let (ll, l, lr) = unwrap(left.expect("left tree is empty"));
+        balance(ll, l, join(lr, item, right))
+    } else if height(&right) > height(&left) + 1 {
+        let (rl, r, rr) = unwrap(right.expect("right tree is empty"));
+        balance(join(left, item, rl), r, rr)
+    } else {
+        node(left, item, right)
+    }
+}
+
+/// Returns a tree that contains the items of `left`, followed by the items of `right`.
+pub fn concat<T: Clone>(left: Tree<T>, right: Tree<T>) -> Tree<T> {
+    match split_first(right) {
+        Some((item, right)) => join(left, item, right),
+        None => left,
+    }
+}
+
+/// Removes the first item from the tree and returns it and the remaining tree.
+fn split_first<T: Clone>(tree: Tree<T>) -> Option<(T, Tree<T>)> {
+    let (left, item, right) = unwrap(tree?);
+
+    match split_first(left) {
+        Some((first, left)) => Some((first, join(left, item, right))),
+        None => Some((item, right)),
+    }
+}
+
+/// Splits the tree in two at the given index.
+///
+/// # Panics
+///
+/// Panics if `index > len(&tree)`.
+pub fn split_at<T: Clone>(tree: Tree<T>, index: usize) -> (Tree<T>, Tree<T>) {
+    let node = match tree {
+        Some(node) => node,
+        None => {
+            assert_eq!(index, 0, "index out of bounds");
+            return (None, None);
+        }
+    };
+
+    let left_len = len(&node.left);
+    let (left, item, right) = unwrap(node);
+
+    if index <= left_len {
+        let (ll, lr) = split_at(left, index);
+        (ll, join(lr, item, right))
+    } else {
+        let (rl, rr) = split_at(right, index - left_len - 1);
+        (join(left, item, rl), rr)
+    }
+}
+
+/// Returns a balanced tree that contains the first `len` items yielded by the given iterator.
+pub fn build<T, I: Iterator<Item = T>>(items: &mut I, len: usize) -> Tree<T> {
+    if len == 0 { return None; }
+
+    let left = build(items, len / 2);
+    let item = items.next().expect("iterator yielded too few items");
+    let right = build(items, len - len / 2 - 1);
+    node(left, item, right)
+}
+
+/// Returns a reference to the item at the given index in the tree.
+pub fn get<T>(mut tree: &Tree<T>, mut index: usize) -> Option<&T> {
+    while let Some(ref node) = *tree {
+        let left_len = len(&node.left);
+
+        match index.cmp(&left_len) {
+            Ordering::Less => tree = &node.left,
+            Ordering::Equal => return Some(&node.item),
+            Ordering::Greater => {
+                index -= left_len + 1;
+                tree = &node.right;
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns a mutable reference to the item at the given index in the tree, copying the shared
+/// nodes on the path to it.
+pub fn get_mut<T: Clone>(tree: &mut Tree<T>, index: usize) -> Option<&mut T> {
+    let node = match *tree {
+        Some(ref mut node) if index < node.len => Rc::make_mut(node),
+        _ => return None,
+    };
+
+    let left_len = len(&node.left);
+
+    match index.cmp(&left_len) {
+        Ordering::Less => get_mut(&mut node.left, index),
+        Ordering::Equal => Some(&mut node.item),
+        Ordering::Greater => get_mut(&mut node.right, index - left_len - 1),
+    }
+}
+
+/// Searches the tree for an item, assuming that the tree is sorted according to the given
+/// comparison function.
+///
+/// The function returns the ordering of the item that is searched for relative to its argument.
+/// Returns the item's index if it is found, or the index at which it could be inserted otherwise.
+pub fn search<T, F: FnMut(&T) -> Ordering>(mut tree: &Tree<T>, mut f: F) -> Result<usize, usize> {
+    let mut index = 0;
+
+    while let Some(ref node) = *tree {
+        let left_len = len(&node.left);
+
+        match f(&node.item) {
+            Ordering::Less => tree = &node.left,
+            Ordering::Equal => return Ok(index + left_len),
+            Ordering::Greater => {
+                index += left_len + 1;
+                tree = &node.right;
+            }
+        }
+    }
+
+    Err(index)
+}
+
+/// Inserts the given item into the tree at the given index.
+///
+/// # Panics
+///
+/// Panics if `index > len(tree)`.
+pub fn insert<T: Clone>(tree: &mut Tree<T>, index: usize, item: T) {
+    let (left, right) = split_at(tree.take(), index);
+    *tree = join(left, item, right);
+}
+
+/// Removes the item at the given index in the tree and returns it.
+pub fn remove<T: Clone>(tree: &mut Tree<T>, index: usize) -> Option<T> {
+    if index >= len(tree) { return None; }
+
+    let (left, right) = split_at(tree.take(), index);
+    let (item, right) = split_first(right).expect("right tree is empty");
+    *tree = concat(left, right);
+    Some(item)
+}
+
+/// Pushes the tree's items onto the given vector in order, copying only the shared nodes.
+pub fn into_vec<T: Clone>(tree: Tree<T>, items: &mut Vec<T>) {
+    if let Some(node) = tree {
+        let (left, item, right) = unwrap(node);
+        into_vec(left, items);
+        items.push(item);
+        into_vec(right, items);
+    }
+}
+
+/// Pushes mutable references to the tree's items onto the given vector in order, copying the
+/// shared nodes.
+pub fn collect_mut<'a, T: Clone>(tree: &'a mut Tree<T>, items: &mut Vec<&'a mut T>) {
+    if let Some(ref mut node) = *tree {
+        let node = Rc::make_mut(node);
+        collect_mut(&mut node.left, items);
+        items.push(&mut node.item);
+        collect_mut(&mut node.right, items);
+    }
+}
+
+/// An iterator that yields references to a tree's items in order.
+pub struct Iter<'a, T: 'a> {
+    stack: Vec<&'a Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iter<'a, T> {
+    pub fn new(tree: &'a Tree<T>) -> Self {
+        let mut iter = Iter { stack: vec![], len: len(tree) };
+        iter.push_left(tree);
+        iter
+    }
+
+    /// Pushes the nodes on the path to the given tree's first item onto the stack.
+    fn push_left(&mut self, mut tree: &'a Tree<T>) {
+        while let Some(ref node) = *tree {
+            self.stack.push(node);
+            tree = &node.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        self.len -= 1;
+        Some(&node.item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
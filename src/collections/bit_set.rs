@@ -0,0 +1,411 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter;
+use std::mem;
+use std::slice;
+use std::sync::OnceLock;
+
+use super::super::*;
+
+/// The number of bits in a word.
+const BITS: usize = 64;
+
+/// A set of integers that is stored as a vector of bits.
+///
+/// Each integer that the set could contain is represented by a single bit, so a `BitSet` is far
+/// more compact than a `HashSet<usize>` or `BTreeSet<usize>` when its items are small and dense.
+/// Its size is proportional to its largest item. Union, intersection and the subset and
+/// disjointness checks operate on whole words at a time.
+///
+/// Because the set does not store its items, the references that are returned by [`Iter::iter`]
+/// and [`Set::get`] point into a list of the set's items. The list is built by the first call to
+/// either method after the set changes, which takes time proportional to the set's largest item
+/// and a word per item, so those methods are much slower than they are for other sets, and
+/// `Set::get` is no faster than a scan unless the set is queried repeatedly without being changed.
+/// Prefer [`contains`] and [`values`], which never build the list. The list is guarded by a
+/// `OnceLock`, so the set is `Sync`, but building it synchronizes with other threads.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::Collection;
+/// use eclectic::collections::BitSet;
+/// use eclectic::set::Base;
+///
+/// let mut a = BitSet::new();
+/// a.extend_object(&mut vec![1, 3, 5, 100].into_iter());
+///
+/// let mut b = BitSet::new();
+/// b.extend_object(&mut vec![3, 100].into_iter());
+///
+/// assert!(b.is_subset(&a));
+/// assert!(a.contains(100));
+///
+/// a.difference_with(&b);
+/// assert!(a.values().eq(vec![1, 5]));
+/// ```
+///
+/// [`contains`]: #method.contains
+/// [`Iter::iter`]: ../trait.Iter.html#tymethod.iter
+/// [`Set::get`]: ../trait.Set.html#tymethod.get
+/// [`values`]: #method.values
+#[derive(Default)]
+pub struct BitSet {
+    /// The set's bits, without trailing zero words.
+    words: Vec<u64>,
+    len: usize,
+    /// The set's items in ascending order, if they have been needed since the set last changed.
+    items: OnceLock<Vec<usize>>,
+}
+
+impl BitSet {
+    /// Returns a new, empty set.
+    pub fn new() -> Self {
+        BitSet { words: vec![], len: 0, items: OnceLock::new() }
+    }
+
+    /// Checks if the set contains the given integer.
+    pub fn contains(&self, item: usize) -> bool {
+        self.words.get(item / BITS).map_or(false, |word| word & 1 << (item % BITS) != 0)
+    }
+
+    /// Returns an iterator that yields the set's items in ascending order.
+    pub fn values<'a>(&'a self) -> Box<Iterator<Item = usize> + 'a> {
+        Box::new(Values::new(&self.words))
+    }
+
+    /// Inserts the items of the given set into the set.
+    pub fn union_with(&mut self, other: &Self) {
+        if self.words.len() < other.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+
+        for (word, other) in self.words[..].iter_mut().zip(&other.words) {
+            *word |= *other;
+        }
+
+        self.recount();
+    }
+
+    /// Removes the items that the given set does not contain from the set.
+    pub fn intersect_with(&mut self, other: &Self) {
+        self.words.truncate(other.words.len());
+
+        for (word, other) in self.words[..].iter_mut().zip(&other.words) {
+            *word &= *other;
+        }
+
+        self.trim();
+        self.recount();
+    }
+
+    /// Removes the items that the given set contains from the set.
+    pub fn difference_with(&mut self, other: &Self) {
+        for (word, other) in self.words[..].iter_mut().zip(&other.words) {
+            *word &= !*other;
+        }
+
+        self.trim();
+        self.recount();
+    }
+
+    /// Replaces the set with the items that are in exactly one of the set and the given set.
+    pub fn symmetric_difference_with(&mut self, other: &Self) {
+        if self.words.len() < other.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+
+        for (word, other) in self.words[..].iter_mut().zip(&other.words) {
+            *word ^= *other;
+        }
+
+        self.trim();
+        self.recount();
+    }
+
+    /// Removes the given integer from the set.
+    ///
+    /// Returns `true` if the set contained the integer, `false` otherwise.
+    fn remove_item(&mut self, item: usize) -> bool {
+        if !BitSet::contains(self, item) { return false; }
+
+        self.words[item / BITS] &= !(1 << (item % BITS));
+        self.len -= 1;
+        self.items.take();
+        self.trim();
+        true
+    }
+
+    /// Returns the set's items in ascending order, building the list if necessary.
+    fn items(&self) -> &[usize] {
+        self.items.get_or_init(|| self.values().collect())
+    }
+
+    /// Removes the trailing zero words from the set.
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+
+    /// Recomputes the number of items in the set after a bulk operation and drops the list of
+    /// items.
+    fn recount(&mut self) {
+        self.len = self.words[..].iter().map(|word| word.count_ones() as usize).sum();
+        self.items.take();
+    }
+}
+
+impl Clone for BitSet {
+    fn clone(&self) -> Self {
+        BitSet { words: self.words.clone(), len: self.len, items: OnceLock::new() }
+    }
+}
+
+impl fmt::Debug for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.values()).finish()
+    }
+}
+
+impl Eq for BitSet {}
+
+impl Hash for BitSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.words.hash(state);
+    }
+}
+
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.words == other.words
+    }
+}
+
+impl AddRemove for BitSet {}
+
+impl Collection for BitSet {
+    type Item = usize;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn capacity(&self) -> usize {
+        self.words.capacity() * BITS
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        self.union_with(other);
+        other.clear();
+    }
+
+    fn extend_object(&mut self, items: &mut Iterator<Item = usize>) {
+        for item in items {
+            set::Base::insert(self, item);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.words.clear();
+        self.len = 0;
+        self.items.take();
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = usize> + 'a> {
+        Box::new(mem::replace(self, Self::new()).into_vec().into_iter())
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.words.reserve((additional + BITS - 1) / BITS);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.words.shrink_to_fit();
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        BitSet {
+            words: Vec::with_capacity((capacity + BITS - 1) / BITS),
+            len: 0,
+            items: OnceLock::new(),
+        }
+    }
+
+    fn into_vec(self) -> Vec<usize> {
+        self.values().collect()
+    }
+}
+
+impl Iter for BitSet {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a usize> + 'a> {
+        Box::new(self.items().iter())
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut usize> + 'a> {
+        unimplemented!()
+    }
+}
+
+impl set::Base for BitSet {
+    fn is_disjoint(&self, other: &Self) -> bool {
+        self.words[..].iter().zip(&other.words).all(|(a, b)| a & b == 0)
+    }
+
+    fn is_subset(&self, other: &Self) -> bool {
+        self.words.len() <= other.words.len() &&
+            self.words[..].iter().zip(&other.words).all(|(a, b)| a & !b == 0)
+    }
+
+    fn insert(&mut self, item: usize) -> bool {
+        if BitSet::contains(self, item) { return false; }
+
+        if self.words.len() <= item / BITS {
+            self.words.resize(item / BITS + 1, 0);
+        }
+
+        self.words[item / BITS] |= 1 << (item % BITS);
+        self.len += 1;
+        self.items.take();
+        true
+    }
+
+    fn replace(&mut self, item: usize) -> Option<usize> {
+        if set::Base::insert(self, item) { None } else { Some(item) }
+    }
+
+    fn entry<'a>(&'a mut self, item: usize) -> set::Entry<'a, usize> {
+        if BitSet::contains(self, item) {
+            set::Entry::Occupied(Box::new(OccupiedEntry { set: self, item: item }))
+        } else {
+            set::Entry::Vacant(Box::new(VacantEntry { set: self, item: item }))
+        }
+    }
+}
+
+impl Set<usize> for BitSet {
+    fn contains(&self, item: &usize) -> bool {
+        BitSet::contains(self, *item)
+    }
+
+    fn get(&self, item: &usize) -> Option<&usize> {
+        let items = self.items();
+        items.binary_search(item).ok().map(|index| &items[index])
+    }
+
+    fn remove(&mut self, item: &usize) -> bool {
+        self.remove_item(*item)
+    }
+
+    fn take(&mut self, item: &usize) -> Option<usize> {
+        if self.remove_item(*item) { Some(*item) } else { None }
+    }
+}
+
+/// An iterator that yields the indices of the set bits in a slice of words in ascending order.
+struct Values<'a> {
+    words: iter::Enumerate<slice::Iter<'a, u64>>,
+    base: usize,
+    word: u64,
+}
+
+impl<'a> Values<'a> {
+    fn new(words: &'a [u64]) -> Self {
+        Values { words: words.iter().enumerate(), base: 0, word: 0 }
+    }
+}
+
+impl<'a> Iterator for Values<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.word == 0 {
+            let (index, &word) = self.words.next()?;
+            self.base = index * BITS;
+            self.word = word;
+        }
+
+        let bit = self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1;
+        Some(self.base + bit)
+    }
+}
+
+struct OccupiedEntry<'a> {
+    set: &'a mut BitSet,
+    item: usize,
+}
+
+impl<'a> set::OccupiedEntry for OccupiedEntry<'a> {
+    type Item = usize;
+
+    fn get(&self) -> &usize {
+        &self.item
+    }
+
+    fn take(self: Box<Self>) -> usize {
+        self.set.remove_item(self.item);
+        self.item
+    }
+}
+
+struct VacantEntry<'a> {
+    set: &'a mut BitSet,
+    item: usize,
+}
+
+impl<'a> set::VacantEntry for VacantEntry<'a> {
+    type Item = usize;
+
+    fn get(&self) -> &usize {
+        &self.item
+    }
+
+    fn into_item(self: Box<Self>) -> usize {
+        self.item
+    }
+
+    fn insert(self: Box<Self>) {
+        set::Base::insert(self.set, self.item);
+    }
+}
+
+#[test]
+fn test_bit_set() {
+    use super::super::set::Base;
+
+    let mut a = BitSet::new();
+    a.extend_object(&mut (0..200).filter(|i| i % 3 == 0));
+    let mut b = BitSet::new();
+    b.extend_object(&mut (0..100).filter(|i| i % 2 == 0));
+
+    fn assert_sync<T: Sync>(_: &T) {}
+    assert_sync(&a);
+
+    assert_eq!(a.len(), 67);
+    assert!(a.iter().cloned().eq((0..200).filter(|i| i % 3 == 0)));
+    assert_eq!(Set::get(&a, &198), Some(&198));
+    assert!(!a.is_disjoint(&b) && !b.is_subset(&a));
+
+    let mut c = a.clone();
+    c.intersect_with(&b);
+    assert!(c.values().eq((0..100).filter(|i| i % 6 == 0)));
+    assert!(c.is_subset(&a) && c.is_subset(&b));
+
+    c.symmetric_difference_with(&b);
+    c.union_with(&a);
+    a.union_with(&b);
+    assert_eq!(a, c);
+
+    a.difference_with(&b);
+    assert!(a.iter().cloned().eq((0..200).filter(|i| i % 3 == 0 && (i % 2 == 1 || *i >= 100))));
+    assert_eq!(Set::get(&a, &1), None);
+    assert!(Base::insert(&mut a, 1));
+    assert_eq!(Set::get(&a, &1), Some(&1));
+    assert_eq!(a.clone(), a);
+    assert!(a.is_disjoint(&b));
+
+    assert!(Set::remove(&mut b, &98));
+    b.retain(&mut |&i| i < 64);
+    assert!(b.values().eq((0..64).filter(|i| i % 2 == 0)));
+}
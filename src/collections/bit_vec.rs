@@ -0,0 +1,324 @@
+use std::fmt;
+use std::mem;
+use std::ops::{Deref, DerefMut, Range};
+
+use super::super::*;
+
+/// The number of bits in a word.
+const BITS: usize = 64;
+
+/// Returns the number of words that are needed to store the given number of bits.
+fn words(len: usize) -> usize {
+    (len + BITS - 1) / BITS
+}
+
+/// Returns a word whose lowest `n` bits are set, where `n < BITS`.
+fn mask(n: usize) -> u64 {
+    (1 << n) - 1
+}
+
+/// A list of booleans that stores each item in a single bit.
+///
+/// A `BitVec` is a compact alternative to a `Vec<bool>`. Because its items are packed into words,
+/// it cannot hand out mutable references to them, so it does not implement [`Mutate`]. Items are
+/// changed with [`set`] instead, or through the [`BitMut`] proxy that is returned by [`bit_mut`].
+/// References to its items point to static `true` and `false` values.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::{Collection, Iter, List};
+/// use eclectic::collections::BitVec;
+///
+/// let mut bits = BitVec::new();
+/// bits.extend_object(&mut vec![true, false, true].into_iter());
+/// bits.insert(1, true);
+///
+/// bits.set(0, false);
+/// *bits.bit_mut(2).unwrap() = true;
+///
+/// assert!(bits.iter().eq(&[false, true, true, true]));
+/// ```
+///
+/// [`bit_mut`]: #method.bit_mut
+/// [`BitMut`]: struct.BitMut.html
+/// [`Mutate`]: ../trait.Mutate.html
+/// [`set`]: #method.set
+#[derive(Clone, Default, Eq, Hash, PartialEq)]
+pub struct BitVec {
+    /// The list's bits, in which the bits past the list's length are zero.
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVec {
+    /// Returns a new, empty list.
+    pub fn new() -> Self {
+        BitVec { words: vec![], len: 0 }
+    }
+
+    /// Sets the item at the given index in the list to the given value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= self.len()`.
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(index < self.len, "index out of bounds");
+
+        if value {
+            self.words[index / BITS] |= 1 << (index % BITS);
+        } else {
+            self.words[index / BITS] &= !(1 << (index % BITS));
+        }
+    }
+
+    /// Returns a proxy for the item at the given index in the list, which can be used to change
+    /// the item.
+    ///
+    /// Returns `None` if `index >= self.len()`.
+    pub fn bit_mut<'a>(&'a mut self, index: usize) -> Option<BitMut<'a>> {
+        let value = self.bit(index)?;
+        Some(BitMut { list: self, index: index, value: value })
+    }
+
+    /// Returns the number of items in the list that are `true`.
+    pub fn count_ones(&self) -> usize {
+        self.words[..].iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    fn bit(&self, index: usize) -> Option<bool> {
+        if index < self.len {
+            Some(self.words[index / BITS] & 1 << (index % BITS) != 0)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Debug for BitVec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries((0..self.len).map(|i| self.bit(i).expect("index out of bounds")))
+            .finish()
+    }
+}
+
+impl From<Vec<bool>> for BitVec {
+    fn from(items: Vec<bool>) -> Self {
+        let mut list = BitVec::new();
+        list.extend_object(&mut items.into_iter());
+        list
+    }
+}
+
+/// A proxy for an item in a `BitVec`.
+///
+/// The proxy dereferences to a copy of the item, which is written back to the list when the proxy
+/// is dropped.
+pub struct BitMut<'a> {
+    list: &'a mut BitVec,
+    index: usize,
+    value: bool,
+}
+
+impl<'a> Deref for BitMut<'a> {
+    type Target = bool;
+
+    fn deref(&self) -> &bool {
+        &self.value
+    }
+}
+
+impl<'a> DerefMut for BitMut<'a> {
+    fn deref_mut(&mut self) -> &mut bool {
+        &mut self.value
+    }
+}
+
+impl<'a> Drop for BitMut<'a> {
+    fn drop(&mut self) {
+        self.list.set(self.index, self.value);
+    }
+}
+
+impl AddRemove for BitVec {}
+
+impl Collection for BitVec {
+    type Item = bool;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn capacity(&self) -> usize {
+        self.words.capacity() * BITS
+    }
+
+    fn extend_object(&mut self, items: &mut Iterator<Item = bool>) {
+        for item in items {
+            self.push(item);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.words.clear();
+        self.len = 0;
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = bool> + 'a> {
+        Box::new(mem::replace(self, Self::new()).into_vec().into_iter())
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        let words = words(self.len + additional);
+        if words > self.words.len() { self.words.reserve(words - self.words.len()); }
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.words.shrink_to_fit();
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        BitVec { words: Vec::with_capacity(words(capacity)), len: 0 }
+    }
+
+    fn into_vec(self) -> Vec<bool> {
+        (0..self.len).map(|i| self.bit(i).expect("index out of bounds")).collect()
+    }
+}
+
+impl Iter for BitVec {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a bool> + 'a> {
+        Box::new((0..self.len).map(move |i| self.get(i).expect("index out of bounds")))
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut bool> + 'a> {
+        unimplemented!()
+    }
+}
+
+impl DrainRange<Range<usize>> for BitVec {
+    fn drain_range<'a>(&'a mut self, range: Range<usize>) -> Box<Iterator<Item = bool> + 'a> {
+        assert!(range.start <= range.end, "range start is greater than range end");
+        assert!(range.end <= self.len, "range end out of bounds");
+
+        let mut tail = self.split_off(range.start);
+        let mut rest = tail.split_off(range.end - range.start);
+        self.append(&mut rest);
+        Box::new(tail.into_vec().into_iter())
+    }
+}
+
+impl List for BitVec {
+    fn get(&self, index: usize) -> Option<&bool> {
+        self.bit(index).map(|bit| if bit { &true } else { &false })
+    }
+
+    fn get_mut(&mut self, _index: usize) -> Option<&mut bool> {
+        unimplemented!()
+    }
+
+    fn swap(&mut self, _i: usize, _j: usize) {
+        unimplemented!()
+    }
+
+    fn push(&mut self, item: bool) {
+        if self.len % BITS == 0 { self.words.push(0); }
+        self.len += 1;
+        self.set(self.len - 1, item);
+    }
+
+    fn insert(&mut self, index: usize, item: bool) {
+        assert!(index <= self.len, "index out of bounds");
+
+        self.push(false);
+        let (word, bit) = (index / BITS, index % BITS);
+
+        for i in (word + 1..self.words.len()).rev() {
+            self.words[i] = self.words[i] << 1 | self.words[i - 1] >> (BITS - 1);
+        }
+
+        let low = self.words[word] & mask(bit);
+        self.words[word] = low | (self.words[word] & !mask(bit)) << 1;
+        self.set(index, item);
+    }
+
+    fn pop(&mut self) -> Option<bool> {
+        let item = self.bit(self.len.wrapping_sub(1))?;
+        self.set(self.len - 1, false);
+        self.len -= 1;
+        if self.len % BITS == 0 { self.words.pop(); }
+        Some(item)
+    }
+
+    fn remove(&mut self, index: usize) -> Option<bool> {
+        let item = self.bit(index)?;
+        let (word, bit) = (index / BITS, index % BITS);
+
+        let low = self.words[word] & mask(bit);
+        self.words[word] = low | self.words[word] >> 1 & !mask(bit);
+
+        for i in word + 1..self.words.len() {
+            self.words[i - 1] |= self.words[i] << (BITS - 1);
+            self.words[i] >>= 1;
+        }
+
+        self.len -= 1;
+        if self.len % BITS == 0 { self.words.pop(); }
+        Some(item)
+    }
+
+    fn swap_remove(&mut self, index: usize) -> Option<bool> {
+        let item = self.bit(index)?;
+        let last = self.pop().expect("list is empty");
+        if index < self.len { self.set(index, last); }
+        Some(item)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.words.truncate(words(len));
+            if len % BITS != 0 { self.words[len / BITS] &= mask(len % BITS); }
+            self.len = len;
+        }
+    }
+
+    fn split_off(&mut self, index: usize) -> Self {
+        assert!(index <= self.len, "index out of bounds");
+
+        let mut tail = BitVec::with_capacity(self.len - index);
+        let mut items = (index..self.len).map(|i| self.bit(i).expect("index out of bounds"));
+        tail.extend_object(&mut items);
+        self.truncate(index);
+        tail
+    }
+}
+
+#[test]
+fn test_bit_vec() {
+    let mut list = BitVec::new();
+    let mut items = vec![];
+
+    for i in 0..300 {
+        let index = i * 7 % (items.len() + 1);
+        list.insert(index, i % 3 == 0);
+        items.insert(index, i % 3 == 0);
+    }
+
+    assert!(list.iter().eq(&items));
+    assert_eq!(list.count_ones(), 100);
+
+    for i in 0..100 {
+        let index = i * 13 % items.len();
+        assert_eq!(list.remove(index), Some(items.remove(index)));
+    }
+
+    assert!(list.drain_range(50..150).eq(items.drain(50..150)));
+    assert_eq!(list.swap_remove(10), Some(items.swap_remove(10)));
+    *list.bit_mut(20).unwrap() ^= true;
+    items[20] ^= true;
+
+    let tail = list.split_off(70);
+    assert!(tail.iter().eq(&items[70..]));
+    items.truncate(70);
+    assert_eq!(list, BitVec::from(items));
+}
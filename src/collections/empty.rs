@@ -0,0 +1,199 @@
+use std::fmt;
+use std::iter;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use super::super::*;
+
+/// A list that contains no items.
+///
+/// `Empty` implements [`Mutate`], but not [`AddRemove`], so it can be passed without allocating
+/// to generic code that accepts a list of items but does not need to change its length.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::List;
+/// use eclectic::collections::Empty;
+///
+/// fn total<L: ?Sized + List<Item = u32>>(list: &L) -> u32 {
+///     list.iter().sum()
+/// }
+///
+/// assert_eq!(total(&Empty::new()), 0);
+/// ```
+///
+/// [`AddRemove`]: ../trait.AddRemove.html
+/// [`Mutate`]: ../trait.Mutate.html
+pub struct Empty<T> {
+    marker: PhantomData<T>,
+}
+
+impl<T> Empty<T> {
+    /// Returns a new list.
+    pub fn new() -> Self {
+        Empty { marker: PhantomData }
+    }
+}
+
+impl<T> Clone for Empty<T> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Copy for Empty<T> {}
+
+impl<T> fmt::Debug for Empty<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Empty")
+    }
+}
+
+impl<T> Default for Empty<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Eq for Empty<T> {}
+
+impl<T> PartialEq for Empty<T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> Mutate for Empty<T> {}
+
+impl<T> Collection for Empty<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        0
+    }
+
+    fn capacity(&self) -> usize {
+        0
+    }
+
+    fn extend_object(&mut self, _items: &mut Iterator<Item = T>) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = T> + 'a> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn reserve(&mut self, _additional: usize) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn shrink_to_fit(&mut self) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::new()
+    }
+
+    fn into_vec(self) -> Vec<T> {
+        vec![]
+    }
+}
+
+impl<T> Iter for Empty<T> {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a T> + 'a> {
+        Box::new(iter::empty())
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut T> + 'a> {
+        Box::new(iter::empty())
+    }
+}
+
+impl<T> DrainRange<Range<usize>> for Empty<T> {
+    fn drain_range<'a>(&'a mut self, _range: Range<usize>)
+        -> Box<Iterator<Item = T> + 'a> where Self: AddRemove
+    {
+        unimplemented!()
+    }
+}
+
+impl<T> List for Empty<T> {
+    fn get(&self, _index: usize) -> Option<&T> {
+        None
+    }
+
+    fn get_mut(&mut self, _index: usize) -> Option<&mut T> {
+        None
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.as_mut_slice().swap(i, j);
+    }
+
+    fn contiguous(&self) -> Option<&[T]> {
+        Some(&[])
+    }
+
+    fn contiguous_mut(&mut self) -> Option<&mut [T]> {
+        Some(&mut [])
+    }
+
+    fn insert(&mut self, _index: usize, _item: T) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn remove(&mut self, _index: usize) -> Option<T> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn swap_remove(&mut self, _index: usize) -> Option<T> where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+impl<T> Segmented for Empty<T> {
+    fn as_slices(&self) -> (&[T], &[T]) {
+        (&[], &[])
+    }
+
+    fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        (&mut [], &mut [])
+    }
+
+    fn make_contiguous(&mut self) -> &mut [T] {
+        &mut []
+    }
+}
+
+impl<T> Contiguous for Empty<T> {
+    fn as_slice(&self) -> &[T] {
+        &[]
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut []
+    }
+}
+
+impl<T> Queue for Empty<T> {
+    fn push(&mut self, _item: T) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn front(&self) -> Option<&T> {
+        None
+    }
+
+    fn pop_front(&mut self) -> Option<T> where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+impl<T> FifoQueue for Empty<T> {
+    fn front_mut(&mut self) -> Option<&mut T> {
+        None
+    }
+}
@@ -0,0 +1,33 @@
+//! Collections that are built on top of the collection traits.
+//!
+//! The collections in this module are generic over their underlying storage where possible, so
+//! they can be backed by any suitable implementor of the collection traits.
+
+pub use self::array_vec::{ArrayVec, CapacityError};
+pub use self::bit_set::BitSet;
+pub use self::bit_vec::{BitMut, BitVec};
+pub use self::empty::Empty;
+pub use self::once::Once;
+pub use self::p_map::{PMap, PSet};
+pub use self::p_ord_map::POrdMap;
+pub use self::p_vec::PVec;
+pub use self::range_map::{RangeMap, RangeSet};
+pub use self::rope::Rope;
+pub use self::small_vec::SmallVec;
+pub use self::sorted_list::SortedList;
+pub use self::trie_map::TrieMap;
+
+mod array_vec;
+mod avl;
+mod bit_set;
+mod bit_vec;
+mod empty;
+mod once;
+mod p_map;
+mod p_ord_map;
+mod p_vec;
+mod range_map;
+mod rope;
+mod small_vec;
+mod sorted_list;
+mod trie_map;
@@ -0,0 +1,197 @@
+use std::ops::Range;
+use std::slice;
+
+use super::super::*;
+
+/// A list that contains exactly one item.
+///
+/// `Once` implements [`Mutate`], but not [`AddRemove`], so it can be passed without allocating
+/// to generic code that accepts a list of items but does not need to change its length.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::List;
+/// use eclectic::collections::Once;
+///
+/// fn total<L: ?Sized + List<Item = u32>>(list: &L) -> u32 {
+///     list.iter().sum()
+/// }
+///
+/// assert_eq!(total(&Once::new(5)), 5);
+/// assert_eq!(total(&vec![1, 2, 3]), 6);
+/// ```
+///
+/// [`AddRemove`]: ../trait.AddRemove.html
+/// [`Mutate`]: ../trait.Mutate.html
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Once<T> {
+    item: T,
+}
+
+impl<T> Once<T> {
+    /// Returns a new list that contains the given item.
+    pub fn new(item: T) -> Self {
+        Once { item: item }
+    }
+
+    /// Consumes the list and returns its item.
+    pub fn into_inner(self) -> T {
+        self.item
+    }
+}
+
+impl<T> Mutate for Once<T> {}
+
+impl<T> Collection for Once<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        1
+    }
+
+    fn capacity(&self) -> usize {
+        1
+    }
+
+    fn extend_object(&mut self, _items: &mut Iterator<Item = T>) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = T> + 'a> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn reserve(&mut self, _additional: usize) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn shrink_to_fit(&mut self) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    // a `Once` cannot be empty, so this returns a list that contains `T`'s default value
+    fn with_capacity(_capacity: usize) -> Self where Self: Default {
+        Self::default()
+    }
+
+    fn into_vec(self) -> Vec<T> {
+        vec![self.item]
+    }
+}
+
+impl<T> Iter for Once<T> {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a T> + 'a> {
+        Box::new(Some(&self.item).into_iter())
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut T> + 'a> {
+        Box::new(Some(&mut self.item).into_iter())
+    }
+}
+
+impl<T> DrainRange<Range<usize>> for Once<T> {
+    fn drain_range<'a>(&'a mut self, _range: Range<usize>)
+        -> Box<Iterator<Item = T> + 'a> where Self: AddRemove
+    {
+        unimplemented!()
+    }
+}
+
+impl<T> List for Once<T> {
+    fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.as_mut_slice().swap(i, j);
+    }
+
+    fn contiguous(&self) -> Option<&[T]> {
+        Some(self.as_slice())
+    }
+
+    fn contiguous_mut(&mut self) -> Option<&mut [T]> {
+        Some(self.as_mut_slice())
+    }
+
+    fn insert(&mut self, _index: usize, _item: T) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn remove(&mut self, _index: usize) -> Option<T> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn swap_remove(&mut self, _index: usize) -> Option<T> where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+impl<T> Segmented for Once<T> {
+    fn as_slices(&self) -> (&[T], &[T]) {
+        (self.as_slice(), &[])
+    }
+
+    fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        (self.as_mut_slice(), &mut [])
+    }
+
+    fn make_contiguous(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T> Contiguous for Once<T> {
+    fn as_slice(&self) -> &[T] {
+        slice::from_ref(&self.item)
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        slice::from_mut(&mut self.item)
+    }
+}
+
+impl<T> Queue for Once<T> {
+    fn push(&mut self, _item: T) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn front(&self) -> Option<&T> {
+        Some(&self.item)
+    }
+
+    fn pop_front(&mut self) -> Option<T> where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+impl<T> FifoQueue for Once<T> {
+    fn front_mut(&mut self) -> Option<&mut T> {
+        Some(&mut self.item)
+    }
+}
+
+#[test]
+fn test_once_empty() {
+    use super::Empty;
+
+    fn check<L: List + Contiguous + Queue + Mutate>(mut list: L) -> Vec<L::Item>
+        where L::Item: Ord + ::std::fmt::Debug
+    {
+        algo::sort::sort(&mut list);
+        assert_eq!(list.len(), list.as_slice().len());
+        assert_eq!(list.front(), list.get(0));
+        list.into_vec()
+    }
+
+    assert_eq!(check(Once::new(1)), [1]);
+    assert_eq!(check(Empty::<i32>::new()), []);
+
+    assert_eq!(<Once<i32> as Collection>::with_capacity(0), Once::new(0));
+    assert_eq!(<Empty<i32> as Collection>::with_capacity(0), Empty::new());
+}
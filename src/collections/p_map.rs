@@ -0,0 +1,771 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::mem;
+use std::rc::Rc;
+use std::slice;
+
+use super::super::*;
+
+/// The number of hash bits that are consumed at each level of the trie.
+const BITS: u32 = 5;
+
+/// A persistent hash map.
+///
+/// A `PMap` is a hash array mapped trie whose nodes are shared with its clones, so cloning it
+/// takes constant time. Changing a `PMap` copies only the shared nodes on the path to the change,
+/// and never affects its clones. Lookups, insertions and removals take `O(log n)` time, with a
+/// branching factor of 32.
+///
+/// Because nodes may be shared, the collection traits are implemented only for keys and values
+/// that implement `Clone`.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::Map;
+/// use eclectic::collections::PMap;
+/// use eclectic::map::Base;
+///
+/// let mut map = PMap::new();
+/// map.insert("a", 1);
+///
+/// let snapshot = map.clone();
+/// map.insert("b", 2);
+/// map.remove("a");
+///
+/// assert_eq!(map.get("b"), Some(&2));
+/// assert!(!map.contains_key("a"));
+/// assert_eq!(snapshot.get("a"), Some(&1));
+/// assert!(!snapshot.contains_key("b"));
+/// ```
+pub struct PMap<K, V> {
+    root: Rc<Branch<K, V>>,
+    len: usize,
+    hasher: RandomState,
+}
+
+/// A node in the trie.
+///
+/// The bitmap contains a bit for each non-empty slot of the node, and the slots are stored in
+/// ascending order of those bits.
+#[derive(Clone)]
+struct Branch<K, V> {
+    bitmap: u32,
+    slots: Vec<Slot<K, V>>,
+}
+
+#[derive(Clone)]
+enum Slot<K, V> {
+    /// An entry and the hash of its key.
+    Entry(u64, K, V),
+    /// Entries whose keys have the same hash.
+    Collision(u64, Vec<(K, V)>),
+    Branch(Rc<Branch<K, V>>),
+}
+
+impl<K, V> Branch<K, V> {
+    fn new() -> Self {
+        Branch { bitmap: 0, slots: vec![] }
+    }
+
+    /// Returns the bit for the given hash at the given level, and the index of its slot.
+    fn locate(&self, hash: u64, shift: u32) -> (u32, usize) {
+        let bit = 1 << ((hash >> shift) & ((1 << BITS) - 1));
+        (bit, (self.bitmap & (bit - 1)).count_ones() as usize)
+    }
+}
+
+impl<K, V> Slot<K, V> {
+    fn hash(&self) -> u64 {
+        match *self {
+            Slot::Entry(hash, ..) | Slot::Collision(hash, _) => hash,
+            Slot::Branch(_) => unreachable!(),
+        }
+    }
+}
+
+fn get<'a, K, V, Q>(mut branch: &'a Branch<K, V>, hash: u64, key: &Q) -> Option<(&'a K, &'a V)>
+    where K: Borrow<Q>, Q: ?Sized + Eq
+{
+    let mut shift = 0;
+
+    loop {
+        let (bit, index) = branch.locate(hash, shift);
+        if branch.bitmap & bit == 0 { return None; }
+
+        match branch.slots[index] {
+            Slot::Entry(h, ref k, ref v) => {
+                return if h == hash && k.borrow() == key { Some((k, v)) } else { None };
+            }
+            Slot::Collision(h, ref entries) => {
+                if h != hash { return None; }
+                return entries.iter().find(|e| e.0.borrow() == key).map(|e| (&e.0, &e.1));
+            }
+            Slot::Branch(ref child) => {
+                branch = child;
+                shift += BITS;
+            }
+        }
+    }
+}
+
+fn get_mut<'a, K, V, Q>(branch: &'a mut Branch<K, V>, hash: u64, shift: u32, key: &Q)
+    -> Option<&'a mut V> where K: Clone + Borrow<Q>, V: Clone, Q: ?Sized + Eq
+{
+    let (bit, index) = branch.locate(hash, shift);
+    if branch.bitmap & bit == 0 { return None; }
+
+    match branch.slots[index] {
+        Slot::Entry(h, ref k, ref mut v) => {
+            if h == hash && k.borrow() == key { Some(v) } else { None }
+        }
+        Slot::Collision(h, ref mut entries) => {
+            if h != hash { return None; }
+            entries.iter_mut().find(|e| e.0.borrow() == key).map(|e| &mut e.1)
+        }
+        Slot::Branch(ref mut child) => get_mut(Rc::make_mut(child), hash, shift + BITS, key),
+    }
+}
+
+/// Inserts the given entry into the branch.
+///
+/// If the branch contains an equal key, returns its value and either the old key (if
+/// `replace_key` is `true`) or the given key.
+fn insert<K: Eq + Clone, V: Clone>(branch: &mut Branch<K, V>, hash: u64, shift: u32, key: K,
+                                   value: V, replace_key: bool) -> Option<(K, V)> {
+    let (bit, index) = branch.locate(hash, shift);
+
+    if branch.bitmap & bit == 0 {
+        branch.bitmap |= bit;
+        branch.slots.insert(index, Slot::Entry(hash, key, value));
+        return None;
+    }
+
+    let slot = &mut branch.slots[index];
+
+    match *slot {
+        Slot::Entry(h, ref mut k, ref mut v) if h == hash && *k == key => {
+            let value = mem::replace(v, value);
+            return Some((if replace_key { mem::replace(k, key) } else { key }, value));
+        }
+        Slot::Collision(h, ref mut entries) if h == hash => {
+            return match entries[..].iter().position(|e| e.0 == key) {
+                Some(i) => {
+                    let entry = &mut entries[i];
+                    let value = mem::replace(&mut entry.1, value);
+                    Some((if replace_key { mem::replace(&mut entry.0, key) } else { key }, value))
+                }
+                None => {
+                    entries.push((key, value));
+                    None
+                }
+            };
+        }
+        Slot::Branch(ref mut child) => {
+            return insert(Rc::make_mut(child), hash, shift + BITS, key, value, replace_key);
+        }
+        _ => {}
+    }
+
+    let other = mem::replace(slot, Slot::Collision(hash, vec![]));
+    *slot = merge(other, Slot::Entry(hash, key, value), shift + BITS);
+    None
+}
+
+/// Returns a slot that contains the entries of the given slots, whose keys are not equal.
+///
+/// Each slot must hold either an entry or a collision.
+fn merge<K, V>(a: Slot<K, V>, b: Slot<K, V>, shift: u32) -> Slot<K, V> {
+    let (hash_a, hash_b) = (a.hash(), b.hash());
+
+    if hash_a == hash_b {
+        let mut entries = vec![];
+
+        for slot in vec![a, b] {
+            match slot {
+                Slot::Entry(_, key, value) => entries.push((key, value)),
+                Slot::Collision(_, others) => entries.extend(others),
+                Slot::Branch(_) => unreachable!(),
+            }
+        }
+
+        return Slot::Collision(hash_a, entries);
+    }
+
+    let mut branch = Branch::new();
+    let (bit_a, _) = branch.locate(hash_a, shift);
+    let (bit_b, _) = branch.locate(hash_b, shift);
+
+    if bit_a == bit_b {
+        branch.bitmap = bit_a;
+        branch.slots.push(merge(a, b, shift + BITS));
+    } else {
+        branch.bitmap = bit_a | bit_b;
+        branch.slots = if bit_a < bit_b { vec![a, b] } else { vec![b, a] };
+    }
+
+    Slot::Branch(Rc::new(branch))
+}
+
+/// Removes the entry with the given key from the branch and returns it.
+fn remove<K, V, Q>(branch: &mut Branch<K, V>, hash: u64, shift: u32, key: &Q) -> Option<(K, V)>
+    where K: Clone + Borrow<Q>, V: Clone, Q: ?Sized + Eq
+{
+    let (bit, index) = branch.locate(hash, shift);
+    if branch.bitmap & bit == 0 { return None; }
+
+    let entry = match branch.slots[index] {
+        Slot::Entry(h, ref k, _) => {
+            if h != hash || k.borrow() != key { return None; }
+            branch.bitmap &= !bit;
+
+            match branch.slots.remove(index) {
+                Slot::Entry(_, k, v) => return Some((k, v)),
+                _ => unreachable!(),
+            }
+        }
+        Slot::Collision(h, ref mut entries) => {
+            if h != hash { return None; }
+            let i = entries.iter().position(|e| e.0.borrow() == key)?;
+            entries.swap_remove(i)
+        }
+        Slot::Branch(ref mut child) => remove(Rc::make_mut(child), hash, shift + BITS, key)?,
+    };
+
+    // a collision or branch that is left with a single entry is replaced by that entry, so that
+    // each branch other than the root contains at least two entries
+    let single = match branch.slots[index] {
+        Slot::Collision(h, ref mut entries) if entries.len() == 1 => {
+            let (k, v) = entries.pop().expect("collision is empty");
+            Some(Slot::Entry(h, k, v))
+        }
+        Slot::Branch(ref mut child) if child.slots.len() == 1 => {
+            match child.slots[0] {
+                Slot::Branch(_) => None,
+                _ => Rc::make_mut(child).slots.pop(),
+            }
+        }
+        _ => None,
+    };
+
+    if let Some(single) = single {
+        branch.slots[index] = single;
+    }
+
+    Some(entry)
+}
+
+/// Pushes the branch's entries onto the given vector, copying only the shared nodes.
+fn into_vec<K: Clone, V: Clone>(branch: Rc<Branch<K, V>>, entries: &mut Vec<(K, V)>) {
+    let branch = Rc::try_unwrap(branch).unwrap_or_else(|branch| (*branch).clone());
+
+    for slot in branch.slots {
+        match slot {
+            Slot::Entry(_, key, value) => entries.push((key, value)),
+            Slot::Collision(_, others) => entries.extend(others),
+            Slot::Branch(child) => into_vec(child, entries),
+        }
+    }
+}
+
+/// Pushes references to the branch's keys and mutable references to their values onto the given
+/// vector, copying the shared nodes.
+fn collect_mut<'a, K: Clone, V: Clone>(branch: &'a mut Branch<K, V>,
+                                       entries: &mut Vec<(&'a K, &'a mut V)>) {
+    for slot in &mut branch.slots {
+        match *slot {
+            Slot::Entry(_, ref key, ref mut value) => entries.push((key, value)),
+            Slot::Collision(_, ref mut others) => {
+                entries.extend(others.iter_mut().map(|e| (&e.0, &mut e.1)));
+            }
+            Slot::Branch(ref mut child) => collect_mut(Rc::make_mut(child), entries),
+        }
+    }
+}
+
+/// An iterator that yields references to the keys and values of a trie.
+struct Entries<'a, K: 'a, V: 'a> {
+    stack: Vec<slice::Iter<'a, Slot<K, V>>>,
+    collision: slice::Iter<'a, (K, V)>,
+}
+
+impl<'a, K, V> Iterator for Entries<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        loop {
+            if let Some(entry) = self.collision.next() {
+                return Some((&entry.0, &entry.1));
+            }
+
+            let slot = match self.stack.last_mut()?.next() {
+                Some(slot) => slot,
+                None => {
+                    self.stack.pop();
+                    continue;
+                }
+            };
+
+            match *slot {
+                Slot::Entry(_, ref key, ref value) => return Some((key, value)),
+                Slot::Collision(_, ref entries) => self.collision = entries[..].iter(),
+                Slot::Branch(ref child) => self.stack.push(child.slots[..].iter()),
+            }
+        }
+    }
+}
+
+impl<K, V> PMap<K, V> {
+    /// Returns a new, empty map.
+    pub fn new() -> Self {
+        PMap { root: Rc::new(Branch::new()), len: 0, hasher: RandomState::new() }
+    }
+
+    fn hash<Q: ?Sized + Hash>(&self, key: &Q) -> u64 {
+        self.hasher.hash_one(key)
+    }
+
+    fn entries<'a>(&'a self) -> Entries<'a, K, V> {
+        Entries { stack: vec![self.root.slots[..].iter()], collision: [][..].iter() }
+    }
+}
+
+impl<K, V> Clone for PMap<K, V> {
+    fn clone(&self) -> Self {
+        PMap { root: self.root.clone(), len: self.len, hasher: self.hasher.clone() }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for PMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.entries()).finish()
+    }
+}
+
+impl<K, V> Default for PMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash, V: Eq> Eq for PMap<K, V> {}
+
+impl<K: Eq + Hash, V: PartialEq> PartialEq for PMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.entries().all(|(key, value)| {
+            get(&other.root, other.hash(key), key).map_or(false, |(_, v)| *value == *v)
+        })
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Mutate for PMap<K, V> {}
+
+impl<K: Eq + Hash + Clone, V: Clone> AddRemove for PMap<K, V> {}
+
+impl<K: Eq + Hash + Clone, V: Clone> Collection for PMap<K, V> {
+    type Item = (K, V);
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn capacity(&self) -> usize {
+        self.len
+    }
+
+    fn extend_object(&mut self, items: &mut Iterator<Item = (K, V)>) {
+        for (key, value) in items {
+            map::Base::insert(self, key, value);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.root = Rc::new(Branch::new());
+        self.len = 0;
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = (K, V)> + 'a> {
+        let root = mem::replace(&mut self.root, Rc::new(Branch::new()));
+        let mut entries = Vec::with_capacity(mem::replace(&mut self.len, 0));
+        into_vec(root, &mut entries);
+        Box::new(entries.into_iter())
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn shrink_to_fit(&mut self) {}
+
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::new()
+    }
+
+    fn into_vec(self) -> Vec<(K, V)> {
+        let mut entries = Vec::with_capacity(self.len);
+        into_vec(self.root, &mut entries);
+        entries
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> map::Base for PMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = (&'a K, &'a V)> + 'a> {
+        Box::new(self.entries())
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = (&'a K, &'a mut V)> + 'a> {
+        let mut entries = Vec::with_capacity(self.len);
+        collect_mut(Rc::make_mut(&mut self.root), &mut entries);
+        Box::new(entries.into_iter())
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let hash = self.hash(&key);
+        let old = insert(Rc::make_mut(&mut self.root), hash, 0, key, value, false);
+        if old.is_none() { self.len += 1; }
+        old.map(|(_, value)| value)
+    }
+
+    fn entry<'a>(&'a mut self, key: K) -> map::Entry<'a, K, V> {
+        if self.contains_key(&key) {
+            map::Entry::Occupied(Box::new(OccupiedEntry { map: self, key: key }))
+        } else {
+            map::Entry::Vacant(Box::new(VacantEntry { map: self, key: key }))
+        }
+    }
+
+    fn replace(&mut self, key: K, value: V) -> Option<(K, V)> {
+        let hash = self.hash(&key);
+        let old = insert(Rc::make_mut(&mut self.root), hash, 0, key, value, true);
+        if old.is_none() { self.len += 1; }
+        old
+    }
+}
+
+impl<K, V, Q> Map<Q> for PMap<K, V>
+    where K: Eq + Hash + Clone + Borrow<Q>, V: Clone, Q: ?Sized + Eq + Hash
+{
+    fn get(&self, key: &Q) -> Option<&V> {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    fn get_key_value(&self, key: &Q) -> Option<(&K, &V)> {
+        get(&self.root, self.hash(key), key)
+    }
+
+    fn get_mut(&mut self, key: &Q) -> Option<&mut V> {
+        let hash = self.hash(key);
+        get_mut(Rc::make_mut(&mut self.root), hash, 0, key)
+    }
+
+    fn remove(&mut self, key: &Q) -> Option<V> {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    fn remove_entry(&mut self, key: &Q) -> Option<(K, V)> {
+        let hash = self.hash(key);
+        let entry = remove(Rc::make_mut(&mut self.root), hash, 0, key);
+        if entry.is_some() { self.len -= 1; }
+        entry
+    }
+}
+
+/// An occupied `PMap` entry.
+///
+/// The entry holds a copy of the key, because the trie may be copied when the entry is used.
+struct OccupiedEntry<'a, K: 'a, V: 'a> {
+    map: &'a mut PMap<K, V>,
+    key: K,
+}
+
+impl<'a, K: Eq + Hash + Clone, V: Clone> map::OccupiedEntry for OccupiedEntry<'a, K, V> {
+    type Key = K;
+    type Value = V;
+    type MutValue = &'a mut V;
+
+    fn key(&self) -> &K {
+        self.map.get_key_value(&self.key).expect("occupied entry's key is missing from the map").0
+    }
+
+    fn get(&self) -> &V {
+        self.map.get(&self.key).expect("occupied entry's key is missing from the map")
+    }
+
+    fn get_mut(&mut self) -> &mut V {
+        self.map.get_mut(&self.key).expect("occupied entry's key is missing from the map")
+    }
+
+    fn into_mut(self: Box<Self>) -> &'a mut V {
+        let this = *self;
+        this.map.get_mut(&this.key).expect("occupied entry's key is missing from the map")
+    }
+
+    fn remove(self: Box<Self>) -> V {
+        self.remove_entry().1
+    }
+
+    fn remove_entry(self: Box<Self>) -> (K, V) {
+        self.map.remove_entry(&self.key).expect("occupied entry's key is missing from the map")
+    }
+
+    fn replace_key(self: Box<Self>, key: K) -> K {
+        let (old_key, value) = self.map.remove_entry(&self.key)
+            .expect("occupied entry's key is missing from the map");
+        map::Base::insert(self.map, key, value);
+        old_key
+    }
+}
+
+struct VacantEntry<'a, K: 'a, V: 'a> {
+    map: &'a mut PMap<K, V>,
+    key: K,
+}
+
+impl<'a, K: Eq + Hash + Clone, V: Clone> map::VacantEntry for VacantEntry<'a, K, V> {
+    type Key = K;
+    type Value = V;
+    type MutValue = &'a mut V;
+
+    fn key(&self) -> &K {
+        &self.key
+    }
+
+    fn into_key(self: Box<Self>) -> K {
+        self.key
+    }
+
+    fn insert(self: Box<Self>, value: V) -> &'a mut V {
+        let this = *self;
+        map::Base::insert(this.map, this.key.clone(), value);
+        this.map.get_mut(&this.key).expect("inserted key is missing from the map")
+    }
+}
+
+/// A persistent hash set.
+///
+/// A `PSet` is a [`PMap`] whose values are `()`, so it shares the map's performance
+/// characteristics, and cloning it takes constant time.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::Set;
+/// use eclectic::collections::PSet;
+/// use eclectic::set::Base;
+///
+/// let mut set = PSet::new();
+/// set.insert(1);
+///
+/// let snapshot = set.clone();
+/// assert!(set.insert(2));
+/// assert!(set.remove(&1));
+///
+/// assert!(set.contains(&2) && !set.contains(&1));
+/// assert!(snapshot.contains(&1) && !snapshot.contains(&2));
+/// ```
+///
+/// [`PMap`]: struct.PMap.html
+pub struct PSet<T> {
+    map: PMap<T, ()>,
+}
+
+impl<T> PSet<T> {
+    /// Returns a new, empty set.
+    pub fn new() -> Self {
+        PSet { map: PMap::new() }
+    }
+}
+
+impl<T> Clone for PSet<T> {
+    fn clone(&self) -> Self {
+        PSet { map: self.map.clone() }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for PSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.map.entries().map(|(item, _)| item)).finish()
+    }
+}
+
+impl<T> Default for PSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Eq + Hash> Eq for PSet<T> {}
+
+impl<T: Eq + Hash> PartialEq for PSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T: Eq + Hash + Clone> AddRemove for PSet<T> {}
+
+impl<T: Eq + Hash + Clone> Collection for PSet<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    fn extend_object(&mut self, items: &mut Iterator<Item = T>) {
+        for item in items {
+            set::Base::insert(self, item);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = T> + 'a> {
+        Box::new(self.map.drain().map(|(item, ())| item))
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn shrink_to_fit(&mut self) {}
+
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::new()
+    }
+
+    fn into_vec(self) -> Vec<T> {
+        self.map.into_vec().into_iter().map(|(item, ())| item).collect()
+    }
+}
+
+impl<T: Eq + Hash + Clone> Iter for PSet<T> {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a T> + 'a> {
+        Box::new(self.map.entries().map(|(item, _)| item))
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut T> + 'a> where Self: Mutate {
+        unimplemented!()
+    }
+}
+
+impl<T: Eq + Hash + Clone> set::Base for PSet<T> {
+    fn is_disjoint(&self, other: &Self) -> bool {
+        let (small, large) = if self.len() <= other.len() { (self, other) } else { (other, self) };
+        small.iter().all(|item| !large.contains(item))
+    }
+
+    fn is_subset(&self, other: &Self) -> bool {
+        self.len() <= other.len() && self.iter().all(|item| other.contains(item))
+    }
+
+    fn insert(&mut self, item: T) -> bool {
+        map::Base::insert(&mut self.map, item, ()).is_none()
+    }
+
+    fn replace(&mut self, item: T) -> Option<T> {
+        map::Base::replace(&mut self.map, item, ()).map(|(item, ())| item)
+    }
+
+    fn entry<'a>(&'a mut self, item: T) -> set::Entry<'a, T> {
+        match map::Base::entry(&mut self.map, item) {
+            map::Entry::Occupied(e) => set::Entry::Occupied(Box::new(SetOccupiedEntry(e))),
+            map::Entry::Vacant(e) => set::Entry::Vacant(Box::new(SetVacantEntry(e))),
+        }
+    }
+}
+
+impl<T, Q> Set<Q> for PSet<T> where T: Eq + Hash + Clone + Borrow<Q>, Q: ?Sized + Eq + Hash {
+    fn get(&self, item: &Q) -> Option<&T> {
+        self.map.get_key_value(item).map(|(item, _)| item)
+    }
+
+    fn take(&mut self, item: &Q) -> Option<T> {
+        self.map.remove_entry(item).map(|(item, ())| item)
+    }
+}
+
+/// An occupied `PSet` entry, which wraps the underlying map's entry.
+struct SetOccupiedEntry<'a, T: 'a>(Box<map::OccupiedEntry<Key = T, Value = (),
+                                                         MutValue = &'a mut ()> + 'a>);
+
+impl<'a, T> set::OccupiedEntry for SetOccupiedEntry<'a, T> {
+    type Item = T;
+
+    fn get(&self) -> &T {
+        self.0.key()
+    }
+
+    fn take(self: Box<Self>) -> T {
+        self.0.remove_entry().0
+    }
+}
+
+/// A vacant `PSet` entry, which wraps the underlying map's entry.
+struct SetVacantEntry<'a, T: 'a>(Box<map::VacantEntry<Key = T, Value = (),
+                                                     MutValue = &'a mut ()> + 'a>);
+
+impl<'a, T> set::VacantEntry for SetVacantEntry<'a, T> {
+    type Item = T;
+
+    fn get(&self) -> &T {
+        self.0.key()
+    }
+
+    fn into_item(self: Box<Self>) -> T {
+        self.0.into_key()
+    }
+
+    fn insert(self: Box<Self>) {
+        self.0.insert(());
+    }
+}
+
+#[test]
+fn test_p_map() {
+    use std::hash::Hasher;
+    use map::Base;
+
+    /// A key whose hash is determined by its first field only, so that keys can collide.
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    struct Key(u8, u32);
+
+    impl Hash for Key {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.0.hash(state);
+        }
+    }
+
+    let mut map = PMap::new();
+    map.extend_object(&mut (0..1000).map(|i| (Key((i % 7) as u8, i), i)));
+    assert_eq!(map.len(), 1000);
+
+    let snapshot = map.clone();
+
+    for i in 0..1000 {
+        if i % 3 == 0 {
+            assert_eq!(map.remove(&Key((i % 7) as u8, i)), Some(i));
+        } else {
+            *map.get_mut(&Key((i % 7) as u8, i)).unwrap() += 1;
+        }
+    }
+
+    assert_eq!(map.len(), 666);
+    assert!(map.iter().all(|(key, &value)| key.1 % 3 != 0 && value == key.1 + 1));
+    assert_eq!(map.into_vec().len(), 666);
+
+    assert_eq!(snapshot.len(), 1000);
+    assert!(snapshot.iter().all(|(key, &value)| value == key.1));
+    assert!((0..1000).all(|i| snapshot.get(&Key((i % 7) as u8, i)) == Some(&i)));
+
+    let mut map = PMap::new();
+    map.extend_object(&mut (0..1000).map(|i| (i, i)));
+    for i in 0..999 { map.remove(&i); }
+    assert!(map.root.slots.len() == 1 && map.iter().eq(vec![(&999, &999)]));
+}
@@ -0,0 +1,416 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::mem;
+
+use super::super::*;
+use super::avl::{self, Tree};
+
+/// A persistent ordered map.
+///
+/// A `POrdMap` is a balanced tree whose nodes are shared with its clones, so cloning it takes
+/// constant time. Changing a `POrdMap` copies only the shared nodes on the path to the change, and
+/// never affects its clones. Lookups, insertions and removals take `O(log n)` time.
+///
+/// Because nodes may be shared, the collection traits are implemented only for keys and values
+/// that implement `Clone`.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::Map;
+/// use eclectic::collections::POrdMap;
+/// use eclectic::map::Base;
+///
+/// let mut map = POrdMap::new();
+/// map.insert("b", 2);
+/// map.insert("a", 1);
+///
+/// let snapshot = map.clone();
+/// map.insert("c", 3);
+/// *map.get_mut("a").unwrap() = 0;
+///
+/// assert!(map.iter().eq(vec![(&"a", &0), (&"b", &2), (&"c", &3)]));
+/// assert!(snapshot.iter().eq(vec![(&"a", &1), (&"b", &2)]));
+/// ```
+pub struct POrdMap<K, V> {
+    tree: Tree<(K, V)>,
+}
+
+impl<K, V> POrdMap<K, V> {
+    /// Returns a new, empty map.
+    pub fn new() -> Self {
+        POrdMap { tree: None }
+    }
+
+    /// Searches the map for the given key.
+    ///
+    /// Returns `Ok(index)` if the map contains an equivalent key, where `index` is the number of
+    /// smaller keys in the map. Otherwise, returns `Err(index)`, where `index` is the number of
+    /// keys in the map that are smaller than the given key.
+    fn search<Q: ?Sized + Ord>(&self, key: &Q) -> Result<usize, usize> where K: Borrow<Q> {
+        avl::search(&self.tree, |&(ref k, _)| key.cmp(k.borrow()))
+    }
+
+    fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        avl::get(&self.tree, index).map(|&(ref key, ref value)| (key, value))
+    }
+}
+
+impl<K, V> Clone for POrdMap<K, V> {
+    fn clone(&self) -> Self {
+        POrdMap { tree: self.tree.clone() }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for POrdMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(avl::Iter::new(&self.tree).map(|&(ref k, ref v)| (k, v))).finish()
+    }
+}
+
+impl<K, V> Default for POrdMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for POrdMap<K, V> {}
+
+impl<K: PartialEq, V: PartialEq> PartialEq for POrdMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        avl::Iter::new(&self.tree).eq(avl::Iter::new(&other.tree))
+    }
+}
+
+impl<K: Ord + Clone, V: Clone> Mutate for POrdMap<K, V> {}
+
+impl<K: Ord + Clone, V: Clone> AddRemove for POrdMap<K, V> {}
+
+impl<K: Ord + Clone, V: Clone> Collection for POrdMap<K, V> {
+    type Item = (K, V);
+
+    fn len(&self) -> usize {
+        avl::len(&self.tree)
+    }
+
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+
+    fn extend_object(&mut self, items: &mut Iterator<Item = (K, V)>) {
+        for (key, value) in items {
+            map::Base::insert(self, key, value);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.tree = None;
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = (K, V)> + 'a> {
+        Box::new(mem::replace(self, Self::new()).into_vec().into_iter())
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn shrink_to_fit(&mut self) {}
+
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::new()
+    }
+
+    fn into_vec(self) -> Vec<(K, V)> {
+        let mut items = Vec::with_capacity(self.len());
+        avl::into_vec(self.tree, &mut items);
+        items
+    }
+}
+
+impl<K: Ord + Clone, V: Clone> map::Base for POrdMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = (&'a K, &'a V)> + 'a> {
+        Box::new(avl::Iter::new(&self.tree).map(|&(ref key, ref value)| (key, value)))
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = (&'a K, &'a mut V)> + 'a> {
+        let mut items = Vec::with_capacity(self.len());
+        avl::collect_mut(&mut self.tree, &mut items);
+        Box::new(items.into_iter().map(|&mut (ref key, ref mut value)| (key, value)))
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.search(&key) {
+            Ok(index) => {
+                let entry = avl::get_mut(&mut self.tree, index).expect("index out of bounds");
+                Some(mem::replace(&mut entry.1, value))
+            }
+            Err(index) => {
+                avl::insert(&mut self.tree, index, (key, value));
+                None
+            }
+        }
+    }
+
+    fn entry<'a>(&'a mut self, key: K) -> map::Entry<'a, K, V> {
+        match self.search(&key) {
+            Ok(index) => map::Entry::Occupied(Box::new(OccupiedEntry { map: self, index: index })),
+            Err(index) => {
+                map::Entry::Vacant(Box::new(VacantEntry { map: self, index: index, key: key }))
+            }
+        }
+    }
+
+    fn replace(&mut self, key: K, value: V) -> Option<(K, V)> {
+        match self.search(&key) {
+            Ok(index) => {
+                let entry = avl::get_mut(&mut self.tree, index).expect("index out of bounds");
+                Some(mem::replace(entry, (key, value)))
+            }
+            Err(index) => {
+                avl::insert(&mut self.tree, index, (key, value));
+                None
+            }
+        }
+    }
+}
+
+impl<K: Ord + Clone + Borrow<Q>, V: Clone, Q: ?Sized + Ord> Map<Q> for POrdMap<K, V> {
+    fn get(&self, key: &Q) -> Option<&V> {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    fn get_key_value(&self, key: &Q) -> Option<(&K, &V)> {
+        self.search(key).ok().and_then(|index| self.get_index(index))
+    }
+
+    fn get_mut(&mut self, key: &Q) -> Option<&mut V> {
+        match self.search(key) {
+            Ok(index) => avl::get_mut(&mut self.tree, index).map(|entry| &mut entry.1),
+            Err(_) => None,
+        }
+    }
+
+    fn remove(&mut self, key: &Q) -> Option<V> {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    fn remove_entry(&mut self, key: &Q) -> Option<(K, V)> {
+        match self.search(key) {
+            Ok(index) => avl::remove(&mut self.tree, index),
+            Err(_) => None,
+        }
+    }
+}
+
+impl<K: Ord + Clone, V: Clone> map::Ordered for POrdMap<K, V> {
+    fn cursor_front<'a>(&'a self) -> Box<map::Cursor<Key = K, Value = V> + 'a> {
+        Box::new(Cursor { map: self, index: 0 })
+    }
+
+    fn lower_bound<'a>(&'a self, key: &K) -> Box<map::Cursor<Key = K, Value = V> + 'a> {
+        let index = self.search(key).unwrap_or_else(|index| index);
+        Box::new(Cursor { map: self, index: index })
+    }
+
+    fn cursor_front_mut<'a>(&'a mut self) -> Box<map::CursorMut<Key = K, Value = V> + 'a> {
+        Box::new(CursorMut { map: self, index: 0 })
+    }
+
+    fn lower_bound_mut<'a>(&'a mut self, key: &K) -> Box<map::CursorMut<Key = K, Value = V> + 'a> {
+        let index = self.search(key).unwrap_or_else(|index| index);
+        Box::new(CursorMut { map: self, index: index })
+    }
+}
+
+/// Returns the index that follows the given one in a map with the given length.
+///
+/// The index that is equal to the length represents the ghost position.
+fn next_index(index: usize, len: usize) -> usize {
+    if index == len { 0 } else { index + 1 }
+}
+
+/// Returns the index that precedes the given one in a map with the given length.
+fn prev_index(index: usize, len: usize) -> usize {
+    if index == 0 { len } else { index - 1 }
+}
+
+/// A cursor over a `POrdMap`.
+///
+/// The cursor points at the entry whose index in the map is `index`, or at the ghost position if
+/// `index` is equal to the map's length.
+struct Cursor<'a, K: 'a, V: 'a> {
+    map: &'a POrdMap<K, V>,
+    index: usize,
+}
+
+impl<'a, K: Ord + Clone, V: Clone> map::Cursor for Cursor<'a, K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn move_next(&mut self) {
+        self.index = next_index(self.index, self.map.len());
+    }
+
+    fn move_prev(&mut self) {
+        self.index = prev_index(self.index, self.map.len());
+    }
+
+    fn current(&self) -> Option<(&K, &V)> {
+        self.map.get_index(self.index)
+    }
+
+    fn peek_next(&self) -> Option<(&K, &V)> {
+        self.map.get_index(next_index(self.index, self.map.len()))
+    }
+
+    fn peek_prev(&self) -> Option<(&K, &V)> {
+        self.map.get_index(prev_index(self.index, self.map.len()))
+    }
+}
+
+/// A cursor over a `POrdMap` that supports editing the map.
+struct CursorMut<'a, K: 'a, V: 'a> {
+    map: &'a mut POrdMap<K, V>,
+    index: usize,
+}
+
+impl<'a, K: Ord + Clone, V: Clone> map::Cursor for CursorMut<'a, K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn move_next(&mut self) {
+        self.index = next_index(self.index, self.map.len());
+    }
+
+    fn move_prev(&mut self) {
+        self.index = prev_index(self.index, self.map.len());
+    }
+
+    fn current(&self) -> Option<(&K, &V)> {
+        self.map.get_index(self.index)
+    }
+
+    fn peek_next(&self) -> Option<(&K, &V)> {
+        self.map.get_index(next_index(self.index, self.map.len()))
+    }
+
+    fn peek_prev(&self) -> Option<(&K, &V)> {
+        self.map.get_index(prev_index(self.index, self.map.len()))
+    }
+}
+
+impl<'a, K: Ord + Clone, V: Clone> map::CursorMut for CursorMut<'a, K, V> {
+    fn current_mut(&mut self) -> Option<(&K, &mut V)> {
+        avl::get_mut(&mut self.map.tree, self.index).map(|&mut (ref key, ref mut value)| {
+            (key, value)
+        })
+    }
+
+    fn remove_current(&mut self) -> Option<(K, V)> {
+        // the next entry moves to the current index, or the cursor moves to the ghost position
+        avl::remove(&mut self.map.tree, self.index)
+    }
+}
+
+struct OccupiedEntry<'a, K: 'a, V: 'a> {
+    map: &'a mut POrdMap<K, V>,
+    index: usize,
+}
+
+impl<'a, K: Ord + Clone, V: Clone> map::OccupiedEntry for OccupiedEntry<'a, K, V> {
+    type Key = K;
+    type Value = V;
+    type MutValue = &'a mut V;
+
+    fn key(&self) -> &K {
+        self.map.get_index(self.index).expect("index out of bounds").0
+    }
+
+    fn get(&self) -> &V {
+        self.map.get_index(self.index).expect("index out of bounds").1
+    }
+
+    fn get_mut(&mut self) -> &mut V {
+        &mut avl::get_mut(&mut self.map.tree, self.index).expect("index out of bounds").1
+    }
+
+    fn into_mut(self: Box<Self>) -> &'a mut V {
+        let this = *self;
+        &mut avl::get_mut(&mut this.map.tree, this.index).expect("index out of bounds").1
+    }
+
+    fn remove(self: Box<Self>) -> V {
+        self.remove_entry().1
+    }
+
+    fn remove_entry(self: Box<Self>) -> (K, V) {
+        avl::remove(&mut self.map.tree, self.index).expect("index out of bounds")
+    }
+
+    fn replace_key(self: Box<Self>, key: K) -> K {
+        let entry = avl::get_mut(&mut self.map.tree, self.index).expect("index out of bounds");
+        mem::replace(&mut entry.0, key)
+    }
+}
+
+struct VacantEntry<'a, K: 'a, V: 'a> {
+    map: &'a mut POrdMap<K, V>,
+    index: usize,
+    key: K,
+}
+
+impl<'a, K: Ord + Clone, V: Clone> map::VacantEntry for VacantEntry<'a, K, V> {
+    type Key = K;
+    type Value = V;
+    type MutValue = &'a mut V;
+
+    fn key(&self) -> &K {
+        &self.key
+    }
+
+    fn into_key(self: Box<Self>) -> K {
+        self.key
+    }
+
+    fn insert(self: Box<Self>, value: V) -> &'a mut V {
+        let this = *self;
+        avl::insert(&mut this.map.tree, this.index, (this.key, value));
+        &mut avl::get_mut(&mut this.map.tree, this.index).expect("index out of bounds").1
+    }
+}
+
+#[test]
+fn test_p_ord_map() {
+    use map::{Base, Ordered};
+
+    let mut map = POrdMap::new();
+    map.extend_object(&mut (0..20).rev().map(|i| (i * 2, i)));
+    let snapshot = map.clone();
+
+    {
+        let mut cursor = map.lower_bound_mut(&5);
+        assert_eq!(cursor.current(), Some((&6, &3)));
+        assert_eq!(cursor.remove_current(), Some((6, 3)));
+        assert_eq!(cursor.current(), Some((&8, &4)));
+        *cursor.current_mut().unwrap().1 = 40;
+        cursor.move_prev();
+        assert_eq!(cursor.peek_prev(), Some((&2, &1)));
+    }
+
+    *map.entry(11).or_insert(0) += 1;
+    *map.entry(8).or_insert(0) += 1;
+    assert_eq!(Map::remove(&mut map, &0), Some(0));
+
+    assert!(map.iter().map(|(&k, &v)| (k, v)).take(5).eq(vec![(2, 1), (4, 2), (8, 41), (10, 5),
+                                                              (11, 1)]));
+    assert_eq!(map.len(), 19);
+
+    let mut cursor = snapshot.cursor_front();
+    cursor.move_prev();
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.peek_next(), Some((&0, &0)));
+    assert_eq!(cursor.peek_prev(), Some((&38, &19)));
+    assert!(snapshot.iter().map(|(&k, &v)| (k, v)).eq((0..20).map(|i| (i * 2, i))));
+}
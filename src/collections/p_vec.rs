@@ -0,0 +1,288 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::ops::Range;
+
+use super::super::*;
+use super::avl::{self, Tree};
+
+/// A persistent list.
+///
+/// A `PVec` is a balanced tree whose nodes are shared with its clones, so cloning it takes
+/// constant time. Changing a `PVec` copies only the shared nodes on the path to the change, and
+/// never affects its clones. Indexing, insertion, removal, splitting and concatenation take
+/// `O(log n)` time.
+///
+/// Because nodes may be shared, the collection traits are implemented only for items that
+/// implement `Clone`.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::{Collection, Iter, List};
+/// use eclectic::collections::PVec;
+///
+/// let mut list = PVec::new();
+/// list.extend_object(&mut (0..5).into_iter());
+///
+/// let snapshot = list.clone();
+/// list.insert(2, 10);
+/// list.remove(0);
+///
+/// assert!(list.iter().eq(&[1, 10, 2, 3, 4]));
+/// assert!(snapshot.iter().eq(&[0, 1, 2, 3, 4]));
+/// ```
+pub struct PVec<T> {
+    tree: Tree<T>,
+}
+
+impl<T> PVec<T> {
+    /// Returns a new, empty list.
+    pub fn new() -> Self {
+        PVec { tree: None }
+    }
+}
+
+impl<T> Clone for PVec<T> {
+    fn clone(&self) -> Self {
+        PVec { tree: self.tree.clone() }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for PVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(avl::Iter::new(&self.tree)).finish()
+    }
+}
+
+impl<T> Default for PVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Eq> Eq for PVec<T> {}
+
+impl<T: Hash> Hash for PVec<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(avl::len(&self.tree));
+
+        for item in avl::Iter::new(&self.tree) {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for PVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        avl::Iter::new(&self.tree).eq(avl::Iter::new(&other.tree))
+    }
+}
+
+impl<T: Clone> Mutate for PVec<T> {}
+
+impl<T: Clone> AddRemove for PVec<T> {}
+
+impl<T: Clone> Collection for PVec<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        avl::len(&self.tree)
+    }
+
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        self.tree = avl::concat(self.tree.take(), other.tree.take());
+    }
+
+    fn extend_object(&mut self, items: &mut Iterator<Item = T>) {
+        let items: Vec<_> = items.collect();
+        let len = items.len();
+        self.tree = avl::concat(self.tree.take(), avl::build(&mut items.into_iter(), len));
+    }
+
+    fn clear(&mut self) {
+        self.tree = None;
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = T> + 'a> {
+        Box::new(mem::replace(self, Self::new()).into_vec().into_iter())
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn shrink_to_fit(&mut self) {}
+
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::new()
+    }
+
+    fn into_vec(self) -> Vec<T> {
+        let mut items = Vec::with_capacity(self.len());
+        avl::into_vec(self.tree, &mut items);
+        items
+    }
+}
+
+impl<T: Clone> Iter for PVec<T> {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a T> + 'a> {
+        Box::new(avl::Iter::new(&self.tree))
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut T> + 'a> {
+        let mut items = Vec::with_capacity(self.len());
+        avl::collect_mut(&mut self.tree, &mut items);
+        Box::new(items.into_iter())
+    }
+}
+
+impl<T: Clone> DrainRange<Range<usize>> for PVec<T> {
+    fn drain_range<'a>(&'a mut self, range: Range<usize>) -> Box<Iterator<Item = T> + 'a> {
+        assert!(range.start <= range.end, "range start is greater than range end");
+        assert!(range.end <= self.len(), "range end out of bounds");
+
+        let (rest, right) = avl::split_at(self.tree.take(), range.end);
+        let (left, middle) = avl::split_at(rest, range.start);
+        self.tree = avl::concat(left, right);
+        Box::new(PVec { tree: middle }.into_vec().into_iter())
+    }
+}
+
+impl<T: Clone> List for PVec<T> {
+    fn get(&self, index: usize) -> Option<&T> {
+        avl::get(&self.tree, index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        avl::get_mut(&mut self.tree, index)
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        let len = self.len();
+        assert!(i < len && j < len, "index out of bounds");
+        if i == j { return; }
+
+        let item = self.get(i).cloned().expect("index out of bounds");
+        let item = mem::replace(self.get_mut(j).expect("index out of bounds"), item);
+        *self.get_mut(i).expect("index out of bounds") = item;
+    }
+
+    fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len(), "rotation amount out of bounds");
+        let (left, right) = avl::split_at(self.tree.take(), mid);
+        self.tree = avl::concat(right, left);
+    }
+
+    fn insert(&mut self, index: usize, item: T) {
+        assert!(index <= self.len(), "index out of bounds");
+        avl::insert(&mut self.tree, index, item);
+    }
+
+    fn insert_many(&mut self, index: usize, items: &mut Iterator<Item = T>) {
+        assert!(index <= self.len(), "index out of bounds");
+
+        let (left, right) = avl::split_at(self.tree.take(), index);
+        let mut middle = PVec::new();
+        middle.extend_object(items);
+        self.tree = avl::concat(avl::concat(left, middle.tree), right);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let len = self.len();
+        avl::remove(&mut self.tree, len.wrapping_sub(1))
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        avl::remove(&mut self.tree, index)
+    }
+
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() { return None; }
+
+        let last = self.pop().expect("list is empty");
+
+        match self.get_mut(index) {
+            Some(item) => Some(mem::replace(item, last)),
+            None => Some(last),
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.tree = avl::split_at(self.tree.take(), len).0;
+        }
+    }
+
+    fn split_off(&mut self, index: usize) -> Self {
+        assert!(index <= self.len(), "index out of bounds");
+
+        let (left, right) = avl::split_at(self.tree.take(), index);
+        self.tree = left;
+        PVec { tree: right }
+    }
+}
+
+impl<T: Clone> Queue for PVec<T> {
+    fn push(&mut self, item: T) {
+        List::push(self, item);
+    }
+
+    fn front(&self) -> Option<&T> {
+        self.first()
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        self.remove(0)
+    }
+}
+
+impl<T: Clone> Deque for PVec<T> {
+    fn back(&self) -> Option<&T> {
+        self.last()
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        self.pop()
+    }
+}
+
+impl<T: Clone> FifoQueue for PVec<T> {
+    fn front_mut(&mut self) -> Option<&mut T> {
+        self.first_mut()
+    }
+}
+
+impl<T: Clone> FifoDeque for PVec<T> {
+    fn push_front(&mut self, item: T) {
+        self.insert(0, item);
+    }
+
+    fn back_mut(&mut self) -> Option<&mut T> {
+        self.last_mut()
+    }
+}
+
+impl<T: Clone> RandomAccessDeque for PVec<T> {}
+
+#[test]
+fn test_p_vec() {
+    let mut list = PVec::new();
+    list.extend_object(&mut (0..100).into_iter());
+
+    let snapshot = list.clone();
+    assert_eq!(list.drain_range(10..90).count(), 80);
+    list.rotate_left(5);
+    list.insert_many(10, &mut (100..103).into_iter());
+    *list.get_mut(0).unwrap() = 0;
+    assert_eq!(list.swap_remove(1), Some(6));
+
+    let tail = list.split_off(10);
+    assert!(list.iter().cloned().eq(vec![0, 4, 7, 8, 9, 90, 91, 92, 93, 94]));
+    assert!(tail.iter().cloned().eq((100..103).chain(95..100).chain(0..4)));
+
+    assert!(snapshot.iter().cloned().eq(0..100));
+    assert_eq!(snapshot.get(50), Some(&50));
+}
@@ -0,0 +1,407 @@
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::collections::Bound::{Excluded, Included, Unbounded};
+use std::fmt;
+use std::mem;
+use std::ops::Range;
+
+use super::super::*;
+
+/// A range that is ordered by its start.
+///
+/// The ranges in a `RangeMap` do not overlap, so their starts identify them.
+#[derive(Clone)]
+struct Start<K>(Range<K>);
+
+impl<K: Ord> Eq for Start<K> {}
+
+impl<K: Ord> Ord for Start<K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.start.cmp(&other.0.start)
+    }
+}
+
+impl<K: Ord> PartialEq for Start<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.start == other.0.start
+    }
+}
+
+impl<K: Ord> PartialOrd for Start<K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K> Borrow<K> for Start<K> {
+    fn borrow(&self) -> &K {
+        &self.0.start
+    }
+}
+
+/// A map from non-overlapping ranges to values.
+///
+/// The ranges are stored in a `BTreeMap` that is ordered by their starts. Inserting a range
+/// removes its keys from any ranges that overlap it, splitting them if necessary, and ranges that
+/// touch and are associated with equal values are coalesced into one.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::RangeMapping;
+/// use eclectic::collections::RangeMap;
+///
+/// let mut bookings = RangeMap::new();
+/// bookings.insert_range(9..12, "alice");
+/// bookings.insert_range(12..13, "alice");
+/// bookings.insert_range(14..17, "bob");
+///
+/// assert_eq!(bookings.get_containing(&12), Some((&(9..13), &"alice")));
+/// assert!(bookings.gaps(8..18).eq(vec![8..9, 13..14, 17..18]));
+///
+/// bookings.remove_range(10..11);
+/// let ranges = bookings.overlapping(0..15).map(|(range, _)| range.clone());
+/// assert!(ranges.eq(vec![9..10, 11..13, 14..17]));
+/// ```
+pub struct RangeMap<K, V> {
+    map: BTreeMap<Start<K>, V>,
+}
+
+impl<K: Ord, V> RangeMap<K, V> {
+    /// Returns a new, empty map.
+    pub fn new() -> Self {
+        RangeMap { map: BTreeMap::new() }
+    }
+
+    /// Returns an iterator that yields references to the map's ranges and to their values, in
+    /// ascending order.
+    pub fn iter<'a>(&'a self) -> Box<Iterator<Item = (&'a Range<K>, &'a V)> + 'a> {
+        Box::new(self.map.iter().map(|(start, value)| (&start.0, value)))
+    }
+
+    /// Returns the entry whose range starts before the given key and is the last to do so.
+    fn before(&self, key: &K) -> Option<(&Range<K>, &V)> {
+        self.map.range::<K, _>((Unbounded, Excluded(key))).next_back()
+            .map(|(start, value)| (&start.0, value))
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for RangeMap<K, V> {
+    fn clone(&self) -> Self {
+        RangeMap { map: self.map.clone() }
+    }
+}
+
+impl<K: Ord + fmt::Debug, V: fmt::Debug> fmt::Debug for RangeMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ord, V> Default for RangeMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V: Eq> Eq for RangeMap<K, V> {}
+
+impl<K: Ord, V: PartialEq> PartialEq for RangeMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map.len() == other.map.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Ord + Clone, V: Eq + Clone> AddRemove for RangeMap<K, V> {}
+
+impl<K: Ord + Clone, V: Eq + Clone> Collection for RangeMap<K, V> {
+    type Item = (Range<K>, V);
+
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.map.len()
+    }
+
+    fn extend_object(&mut self, items: &mut Iterator<Item = (Range<K>, V)>) {
+        for (range, value) in items {
+            self.insert_range(range, value);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = (Range<K>, V)> + 'a> {
+        Box::new(mem::replace(self, Self::new()).into_vec().into_iter())
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn shrink_to_fit(&mut self) {}
+
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::new()
+    }
+
+    fn into_vec(self) -> Vec<(Range<K>, V)> {
+        self.map.into_iter().map(|(start, value)| (start.0, value)).collect()
+    }
+}
+
+impl<K: Ord + Clone, V: Eq + Clone> RangeMapping for RangeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn insert_range(&mut self, mut range: Range<K>, value: V) {
+        if range.start >= range.end { return; }
+
+        self.remove_range(range.clone());
+
+        let left = match self.before(&range.start) {
+            Some((left, left_value)) if left.end == range.start && *left_value == value =>
+                Some(left.start.clone()),
+            _ => None,
+        };
+
+        if let Some(start) = left {
+            self.map.remove(&start);
+            range.start = start;
+        }
+
+        if self.map.get(&range.end) == Some(&value) {
+            let right = self.map.remove_entry(&range.end).expect("right range is missing").0;
+            range.end = right.0.end;
+        }
+
+        self.map.insert(Start(range), value);
+    }
+
+    fn remove_range(&mut self, range: Range<K>) {
+        if range.start >= range.end { return; }
+
+        let starts: Vec<K> =
+            self.overlapping(range.clone()).map(|(r, _)| r.start.clone()).collect();
+
+        for start in starts {
+            let (Start(old), value) = self.map.remove_entry(&start).expect("range is missing");
+
+            if old.start < range.start {
+                self.map.insert(Start(old.start..range.start.clone()), value.clone());
+            }
+
+            if old.end > range.end {
+                self.map.insert(Start(range.end.clone()..old.end), value);
+            }
+        }
+    }
+
+    fn get_containing(&self, key: &K) -> Option<(&Range<K>, &V)> {
+        self.map.range::<K, _>((Unbounded, Included(key))).next_back()
+            .map(|(start, value)| (&start.0, value))
+            .filter(|&(range, _)| *key < range.end)
+    }
+
+    fn overlapping<'a>(&'a self, range: Range<K>)
+        -> Box<Iterator<Item = (&'a Range<K>, &'a V)> + 'a>
+    {
+        if range.start >= range.end { return Box::new(None.into_iter()); }
+
+        let left = self.before(&range.start).filter(|&(left, _)| left.end > range.start);
+        let rest = self.map.range::<K, _>((Included(&range.start), Excluded(&range.end)));
+        Box::new(left.into_iter().chain(rest.map(|(start, value)| (&start.0, value))))
+    }
+
+    fn gaps<'a>(&'a self, range: Range<K>) -> Box<Iterator<Item = Range<K>> + 'a> {
+        let mut gaps = vec![];
+        let mut start = range.start.clone();
+
+        for (r, _) in self.overlapping(range.clone()) {
+            if r.start > start { gaps.push(start.clone()..r.start.clone()); }
+            if r.end > start { start = r.end.clone(); }
+        }
+
+        if start < range.end { gaps.push(start..range.end); }
+        Box::new(gaps.into_iter())
+    }
+}
+
+/// A set of non-overlapping ranges.
+///
+/// A `RangeSet` is a [`RangeMap`] whose values are `()`, so ranges that overlap or touch are
+/// always coalesced into one.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::{Iter, RangeMapping};
+/// use eclectic::collections::RangeSet;
+///
+/// let mut allocated = RangeSet::new();
+/// allocated.insert_range(10..20, ());
+/// allocated.insert_range(15..30, ());
+/// allocated.insert_range(40..50, ());
+///
+/// assert!(allocated.contains(&29));
+/// assert!(allocated.iter().eq(&[10..30, 40..50]));
+/// assert!(allocated.gaps(0..64).eq(vec![0..10, 30..40, 50..64]));
+/// ```
+///
+/// [`RangeMap`]: struct.RangeMap.html
+pub struct RangeSet<K> {
+    map: RangeMap<K, ()>,
+}
+
+impl<K: Ord> RangeSet<K> {
+    /// Returns a new, empty set.
+    pub fn new() -> Self {
+        RangeSet { map: RangeMap::new() }
+    }
+}
+
+impl<K: Ord + Clone> RangeSet<K> {
+    /// Checks if a range in the set contains the given key.
+    pub fn contains(&self, key: &K) -> bool {
+        self.map.get_containing(key).is_some()
+    }
+}
+
+impl<K: Clone> Clone for RangeSet<K> {
+    fn clone(&self) -> Self {
+        RangeSet { map: self.map.clone() }
+    }
+}
+
+impl<K: Ord + fmt::Debug> fmt::Debug for RangeSet<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.map.iter().map(|(range, _)| range)).finish()
+    }
+}
+
+impl<K: Ord> Default for RangeSet<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord> Eq for RangeSet<K> {}
+
+impl<K: Ord> PartialEq for RangeSet<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<K: Ord + Clone> AddRemove for RangeSet<K> {}
+
+impl<K: Ord + Clone> Collection for RangeSet<K> {
+    type Item = Range<K>;
+
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    fn extend_object(&mut self, items: &mut Iterator<Item = Range<K>>) {
+        for range in items {
+            self.map.insert_range(range, ());
+        }
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = Range<K>> + 'a> {
+        Box::new(self.map.drain().map(|(range, _)| range))
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn shrink_to_fit(&mut self) {}
+
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::new()
+    }
+
+    fn into_vec(self) -> Vec<Range<K>> {
+        self.map.into_vec().into_iter().map(|(range, _)| range).collect()
+    }
+}
+
+impl<K: Ord + Clone> Iter for RangeSet<K> {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a Range<K>> + 'a> {
+        Box::new(self.map.iter().map(|(range, _)| range))
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut Range<K>> + 'a>
+        where Self: Mutate
+    {
+        unimplemented!()
+    }
+}
+
+impl<K: Ord + Clone> RangeMapping for RangeSet<K> {
+    type Key = K;
+    type Value = ();
+
+    fn insert_range(&mut self, range: Range<K>, value: ()) {
+        self.map.insert_range(range, value);
+    }
+
+    fn remove_range(&mut self, range: Range<K>) {
+        self.map.remove_range(range);
+    }
+
+    fn get_containing(&self, key: &K) -> Option<(&Range<K>, &())> {
+        self.map.get_containing(key)
+    }
+
+    fn overlapping<'a>(&'a self, range: Range<K>)
+        -> Box<Iterator<Item = (&'a Range<K>, &'a ())> + 'a>
+    {
+        self.map.overlapping(range)
+    }
+
+    fn gaps<'a>(&'a self, range: Range<K>) -> Box<Iterator<Item = Range<K>> + 'a> {
+        self.map.gaps(range)
+    }
+}
+
+#[test]
+fn test_range_map() {
+    let mut map = RangeMap::new();
+    map.extend_object(&mut vec![(0..10, 'a'), (20..30, 'b'), (30..40, 'b')].into_iter());
+    assert_eq!(map.len(), 2);
+
+    map.insert_range(5..25, 'c');
+    map.insert_range(25..27, 'b');
+    assert_eq!(map.clone().into_vec(), vec![(0..5, 'a'), (5..25, 'c'), (25..40, 'b')]);
+
+    map.insert_range(3..7, 'a');
+    map.remove_range(20..26);
+    map.insert_range(0..0, 'z');
+    assert_eq!(map.get_containing(&19), Some((&(7..20), &'c')));
+    assert_eq!(map.get_containing(&20), None);
+    assert_eq!(map.get_containing(&40), None);
+
+    assert!(map.overlapping(6..27).map(|(_, &v)| v).eq(vec!['a', 'c', 'b']));
+    assert_eq!(map.overlapping(20..26).count(), 0);
+    assert!(map.gaps(0..45).eq(vec![20..26, 40..45]));
+    assert!(map.gaps(22..24).eq(vec![22..24]));
+    assert_eq!(map.gaps(1..2).count(), 0);
+
+    let mut set = RangeSet::new();
+    set.extend_object(&mut vec![5..8, 0..3, 3..4, 7..10].into_iter());
+    assert!(set.iter().eq(&[0..4, 5..10]));
+    set.remove_range(2..6);
+    assert!(set.iter().eq(&[0..2, 6..10]));
+    assert!(!set.contains(&2) && set.contains(&9));
+}
@@ -0,0 +1,675 @@
+use std::cmp;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::ops::Range;
+use std::slice;
+
+use super::super::*;
+
+/// The maximum number of items in a leaf.
+const CHUNK: usize = 64;
+
+/// A node in a rope's tree.
+///
+/// Branches never have empty children, and the heights of a branch's children differ by at most
+/// one. Only the root may be an empty leaf.
+#[derive(Clone)]
+enum Node<T> {
+    Leaf(Vec<T>),
+    Branch(Box<Branch<T>>),
+}
+
+#[derive(Clone)]
+struct Branch<T> {
+    left: Node<T>,
+    right: Node<T>,
+    len: usize,
+    height: usize,
+}
+
+impl<T> Node<T> {
+    fn empty() -> Self {
+        Node::Leaf(vec![])
+    }
+
+    fn len(&self) -> usize {
+        match *self {
+            Node::Leaf(ref items) => items.len(),
+            Node::Branch(ref branch) => branch.len,
+        }
+    }
+
+    fn height(&self) -> usize {
+        match *self {
+            Node::Leaf(_) => 0,
+            Node::Branch(ref branch) => branch.height,
+        }
+    }
+
+    fn branch(left: Self, right: Self) -> Self {
+        let height = cmp::max(left.height(), right.height()) + 1;
+        let len = left.len() + right.len();
+        Node::Branch(Box::new(Branch { left: left, right: right, len: len, height: height }))
+    }
+
+    /// Takes the given branch apart.
+    fn unwrap(self) -> (Self, Self) {
+        match self {
+            Node::Leaf(_) => panic!("node is a leaf"),
+            Node::Branch(branch) => { let branch = *branch; (branch.left, branch.right) }
+        }
+    }
+
+    /// Returns a node that consists of the given children, whose heights differ by at most two.
+    fn balance(left: Self, right: Self) -> Self {
+        if left.height() > right.height() + 1 {
+            let (ll, lr) = left.unwrap();
+
+            if ll.height() >= lr.height() {
+                Node::branch(ll, Node::branch(lr, right))
+            } else {
+                let (lrl, lrr) = lr.unwrap();
+                Node::branch(Node::branch(ll, lrl), Node::branch(lrr, right))
+            }
+        } else if right.height() > left.height() + 1 {
+            let (rl, rr) = right.unwrap();
+
+            if rr.height() >= rl.height() {
+                Node::branch(Node::branch(left, rl), rr)
+            } else {
+                let (rll, rlr) = rl.unwrap();
+                Node::branch(Node::branch(left, rll), Node::branch(rlr, rr))
+            }
+        } else {
+            Node::branch(left, right)
+        }
+    }
+
+    /// Returns a node that consists of the given children, whose heights differ by at most one.
+    ///
+    /// If both children are leaves whose items fit in one leaf, they are merged.
+    fn merge(left: Self, right: Self) -> Self {
+        match (left, right) {
+            (Node::Leaf(mut left), Node::Leaf(mut right)) if left.len() + right.len() <= CHUNK => {
+                left.append(&mut right);
+                Node::Leaf(left)
+            }
+            (left, right) => Node::branch(left, right),
+        }
+    }
+
+    /// Returns a node that contains the items of `left`, followed by the items of `right`.
+    fn join(left: Self, right: Self) -> Self {
+        if left.len() == 0 { return right; }
+        if right.len() == 0 { return left; }
+
+        if left.height() > right.height() + 1 {
+            let (ll, lr) = left.unwrap();
+            Node::balance(ll, Node::join(lr, right))
+        } else if right.height() > left.height() + 1 {
+            let (rl, rr) = right.unwrap();
+            Node::balance(Node::join(left, rl), rr)
+        } else {
+            Node::merge(left, right)
+        }
+    }
+
+    /// Splits the node in two at the given index.
+    fn split_at(self, index: usize) -> (Self, Self) {
+        match self {
+            Node::Leaf(mut items) => {
+                let tail = items.split_off(index);
+                (Node::Leaf(items), Node::Leaf(tail))
+            }
+            Node::Branch(branch) => {
+                let branch = *branch;
+                let left_len = branch.left.len();
+
+                if index <= left_len {
+                    let (ll, lr) = branch.left.split_at(index);
+                    (ll, Node::join(lr, branch.right))
+                } else {
+                    let (rl, rr) = branch.right.split_at(index - left_len);
+                    (Node::join(branch.left, rl), rr)
+                }
+            }
+        }
+    }
+
+    /// Returns a balanced node that contains the given items.
+    fn build(mut items: Vec<T>) -> Self {
+        let mut leaves = vec![];
+
+        while items.len() > CHUNK {
+            let tail = items.split_off(CHUNK);
+            leaves.push(mem::replace(&mut items, tail));
+        }
+
+        leaves.push(items);
+        let len = leaves.len();
+        Node::build_leaves(&mut leaves.into_iter(), len)
+    }
+
+    /// Returns a balanced node that contains the first `len` leaves yielded by the given iterator.
+    fn build_leaves<I: Iterator<Item = Vec<T>>>(leaves: &mut I, len: usize) -> Self {
+        if len == 1 { return Node::Leaf(leaves.next().expect("iterator yielded too few leaves")); }
+
+        let left = Node::build_leaves(leaves, len / 2);
+        let right = Node::build_leaves(leaves, len - len / 2);
+        Node::branch(left, right)
+    }
+
+    /// Returns the leaf that contains the item at the given index, along with the index of the
+    /// leaf's first item.
+    fn leaf_at(&self, mut index: usize) -> Option<(&[T], usize)> {
+        if index >= self.len() { return None; }
+
+        let mut node = self;
+        let mut start = 0;
+
+        loop {
+            match *node {
+                Node::Leaf(ref items) => return Some((items, start)),
+                Node::Branch(ref branch) => {
+                    let left_len = branch.left.len();
+
+                    if index < left_len {
+                        node = &branch.left;
+                    } else {
+                        index -= left_len;
+                        start += left_len;
+                        node = &branch.right;
+                    }
+                }
+            }
+        }
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match *self {
+            Node::Leaf(ref mut items) => items.get_mut(index),
+            Node::Branch(ref mut branch) => {
+                let left_len = branch.left.len();
+
+                if index < left_len {
+                    branch.left.get_mut(index)
+                } else {
+                    branch.right.get_mut(index - left_len)
+                }
+            }
+        }
+    }
+
+    /// Inserts the given item into the node at the given index.
+    fn insert(&mut self, index: usize, item: T) {
+        match *self {
+            Node::Leaf(ref mut items) => {
+                items.insert(index, item);
+                if items.len() <= CHUNK { return; }
+            }
+            Node::Branch(ref mut branch) => {
+                let left_len = branch.left.len();
+
+                if index <= left_len {
+                    branch.left.insert(index, item);
+                } else {
+                    branch.right.insert(index - left_len, item);
+                }
+            }
+        }
+
+        *self = match mem::replace(self, Node::empty()) {
+            Node::Leaf(mut items) => {
+                let tail = items.split_off(items.len() / 2);
+                Node::branch(Node::Leaf(items), Node::Leaf(tail))
+            }
+            node => { let (left, right) = node.unwrap(); Node::balance(left, right) }
+        };
+    }
+
+    /// Removes the item at the given index in the node and returns it.
+    fn remove(&mut self, index: usize) -> T {
+        let item = match *self {
+            Node::Leaf(ref mut items) => return items.remove(index),
+            Node::Branch(ref mut branch) => {
+                let left_len = branch.left.len();
+
+                if index < left_len {
+                    branch.left.remove(index)
+                } else {
+                    branch.right.remove(index - left_len)
+                }
+            }
+        };
+
+        let (left, right) = mem::replace(self, Node::empty()).unwrap();
+
+        *self = Node::join(left, right);
+
+        item
+    }
+
+    /// Pushes the node's items onto the given vector in order.
+    fn into_vec(self, items: &mut Vec<T>) {
+        match self {
+            Node::Leaf(mut leaf) => items.append(&mut leaf),
+            Node::Branch(branch) => {
+                let branch = *branch;
+                branch.left.into_vec(items);
+                branch.right.into_vec(items);
+            }
+        }
+    }
+}
+
+/// A list that stores its items in a balanced tree of small vectors.
+///
+/// A `Rope` is suited to large lists that are edited at arbitrary positions, such as the contents
+/// of a text buffer. Indexing, insertion and removal take `O(log n)` time, as do splitting and
+/// concatenation. Its cursors move between neighboring items in amortized constant time.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::{Collection, Iter, List};
+/// use eclectic::collections::Rope;
+///
+/// let mut text: Rope<_> = "hello world".chars().collect::<Vec<_>>().into();
+/// text.insert(5, ',');
+///
+/// let mut tail = text.split_off(7);
+/// tail.extend_object(&mut "!".chars());
+/// text.append(&mut tail);
+///
+/// assert_eq!(text.iter().cloned().collect::<String>(), "hello, world!");
+/// ```
+pub struct Rope<T> {
+    root: Node<T>,
+}
+
+impl<T> Rope<T> {
+    /// Returns a new, empty rope.
+    pub fn new() -> Self {
+        Rope { root: Node::empty() }
+    }
+}
+
+impl<T: Clone> Clone for Rope<T> {
+    fn clone(&self) -> Self {
+        Rope { root: self.root.clone() }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Rope<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(Items::new(&self.root)).finish()
+    }
+}
+
+impl<T> Default for Rope<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Eq> Eq for Rope<T> {}
+
+impl<T> From<Vec<T>> for Rope<T> {
+    fn from(items: Vec<T>) -> Self {
+        Rope { root: Node::build(items) }
+    }
+}
+
+impl<T: Hash> Hash for Rope<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.root.len());
+
+        for item in Items::new(&self.root) {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for Rope<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Items::new(&self.root).eq(Items::new(&other.root))
+    }
+}
+
+impl<T> Mutate for Rope<T> {}
+
+impl<T> AddRemove for Rope<T> {}
+
+impl<T> Collection for Rope<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        self.root.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        let left = mem::replace(&mut self.root, Node::empty());
+        self.root = Node::join(left, mem::replace(&mut other.root, Node::empty()));
+    }
+
+    fn extend_object(&mut self, items: &mut Iterator<Item = T>) {
+        let left = mem::replace(&mut self.root, Node::empty());
+        self.root = Node::join(left, Node::build(items.collect()));
+    }
+
+    fn clear(&mut self) {
+        self.root = Node::empty();
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = T> + 'a> {
+        Box::new(mem::replace(self, Self::new()).into_vec().into_iter())
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn shrink_to_fit(&mut self) {}
+
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::new()
+    }
+
+    fn into_vec(self) -> Vec<T> {
+        let mut items = Vec::with_capacity(self.len());
+        self.root.into_vec(&mut items);
+        items
+    }
+}
+
+impl<T> Iter for Rope<T> {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a T> + 'a> {
+        Box::new(Items::new(&self.root))
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut T> + 'a> {
+        Box::new(ItemsMut::new(&mut self.root))
+    }
+}
+
+impl<T> DrainRange<Range<usize>> for Rope<T> {
+    fn drain_range<'a>(&'a mut self, range: Range<usize>) -> Box<Iterator<Item = T> + 'a> {
+        assert!(range.start <= range.end, "range start is greater than range end");
+        assert!(range.end <= self.len(), "range end out of bounds");
+
+        let (rest, right) = mem::replace(&mut self.root, Node::empty()).split_at(range.end);
+        let (left, middle) = rest.split_at(range.start);
+        self.root = Node::join(left, right);
+        Box::new(Rope { root: middle }.into_vec().into_iter())
+    }
+}
+
+impl<T> List for Rope<T> {
+    fn get(&self, index: usize) -> Option<&T> {
+        self.root.leaf_at(index).map(|(leaf, start)| &leaf[index - start])
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.root.get_mut(index)
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        let len = self.len();
+        assert!(i < len && j < len, "index out of bounds");
+        if i == j { return; }
+
+        let (i, j) = (cmp::min(i, j), cmp::max(i, j));
+        let item = self.root.remove(j);
+        let item = mem::replace(self.root.get_mut(i).expect("index out of bounds"), item);
+        self.root.insert(j, item);
+    }
+
+    fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len(), "rotation amount out of bounds");
+        let (left, right) = mem::replace(&mut self.root, Node::empty()).split_at(mid);
+        self.root = Node::join(right, left);
+    }
+
+    fn insert(&mut self, index: usize, item: T) {
+        assert!(index <= self.len(), "index out of bounds");
+        self.root.insert(index, item);
+    }
+
+    fn insert_many(&mut self, index: usize, items: &mut Iterator<Item = T>) {
+        assert!(index <= self.len(), "index out of bounds");
+
+        let (left, right) = mem::replace(&mut self.root, Node::empty()).split_at(index);
+        let middle = Node::build(items.collect());
+        self.root = Node::join(Node::join(left, middle), right);
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        if index < self.len() { Some(self.root.remove(index)) } else { None }
+    }
+
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        let len = self.len();
+        if index >= len { return None; }
+
+        let last = self.root.remove(len - 1);
+
+        match self.root.get_mut(index) {
+            Some(item) => Some(mem::replace(item, last)),
+            None => Some(last),
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.root = mem::replace(&mut self.root, Node::empty()).split_at(len).0;
+        }
+    }
+
+    fn split_off(&mut self, index: usize) -> Self {
+        assert!(index <= self.len(), "index out of bounds");
+
+        let (left, right) = mem::replace(&mut self.root, Node::empty()).split_at(index);
+        self.root = left;
+        Rope { root: right }
+    }
+
+    fn cursor_front<'a>(&'a self) -> Box<list::Cursor<Item = T> + 'a> {
+        Box::new(Cursor::new(self, 0))
+    }
+
+    fn cursor_back<'a>(&'a self) -> Box<list::Cursor<Item = T> + 'a> {
+        Box::new(Cursor::new(self, self.len().saturating_sub(1)))
+    }
+}
+
+/// An iterator that yields references to a rope's items in order.
+struct Items<'a, T: 'a> {
+    stack: Vec<&'a Node<T>>,
+    leaf: slice::Iter<'a, T>,
+    len: usize,
+}
+
+impl<'a, T> Items<'a, T> {
+    fn new(root: &'a Node<T>) -> Self {
+        Items { stack: vec![root], leaf: [][..].iter(), len: root.len() }
+    }
+}
+
+impl<'a, T> Iterator for Items<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            if let Some(item) = self.leaf.next() {
+                self.len -= 1;
+                return Some(item);
+            }
+
+            match *self.stack.pop()? {
+                Node::Leaf(ref items) => self.leaf = items[..].iter(),
+                Node::Branch(ref branch) => {
+                    self.stack.push(&branch.right);
+                    self.stack.push(&branch.left);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Items<'a, T> {}
+
+/// An iterator that yields mutable references to a rope's items in order.
+struct ItemsMut<'a, T: 'a> {
+    stack: Vec<&'a mut Node<T>>,
+    leaf: slice::IterMut<'a, T>,
+    len: usize,
+}
+
+impl<'a, T> ItemsMut<'a, T> {
+    fn new(root: &'a mut Node<T>) -> Self {
+        let len = root.len();
+        ItemsMut { stack: vec![root], leaf: [][..].iter_mut(), len: len }
+    }
+}
+
+impl<'a, T> Iterator for ItemsMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        loop {
+            if let Some(item) = self.leaf.next() {
+                self.len -= 1;
+                return Some(item);
+            }
+
+            match *self.stack.pop()? {
+                Node::Leaf(ref mut items) => self.leaf = items[..].iter_mut(),
+                Node::Branch(ref mut branch) => {
+                    let branch = &mut **branch;
+                    self.stack.push(&mut branch.right);
+                    self.stack.push(&mut branch.left);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ItemsMut<'a, T> {}
+
+/// A cursor over a `Rope`.
+///
+/// The cursor remembers the leaf that contains the item it points at, so it only searches the
+/// tree when it moves to another leaf.
+struct Cursor<'a, T: 'a> {
+    rope: &'a Rope<T>,
+    index: usize,
+    leaf: &'a [T],
+    start: usize,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    fn new(rope: &'a Rope<T>, index: usize) -> Self {
+        let mut cursor = Cursor { rope: rope, index: index, leaf: &[], start: 0 };
+        cursor.locate();
+        cursor
+    }
+
+    /// Finds the leaf that contains the item that the cursor points at.
+    fn locate(&mut self) {
+        if self.index >= self.start && self.index - self.start < self.leaf.len() { return; }
+
+        if let Some((leaf, start)) = self.rope.root.leaf_at(self.index) {
+            self.leaf = leaf;
+            self.start = start;
+        }
+    }
+
+    fn item(&self, index: usize) -> Option<&'a T> {
+        if index >= self.start && index - self.start < self.leaf.len() {
+            Some(&self.leaf[index - self.start])
+        } else {
+            self.rope.root.leaf_at(index).map(|(leaf, start)| &leaf[index - start])
+        }
+    }
+}
+
+impl<'a, T> list::Cursor for Cursor<'a, T> {
+    type Item = T;
+
+    fn index(&self) -> Option<usize> {
+        if self.index < self.rope.len() { Some(self.index) } else { None }
+    }
+
+    fn move_next(&mut self) {
+        self.index = (self.index + 1) % (self.rope.len() + 1);
+        self.locate();
+    }
+
+    fn move_prev(&mut self) {
+        let len = self.rope.len();
+        self.index = (self.index + len) % (len + 1);
+        self.locate();
+    }
+
+    fn current(&self) -> Option<&T> {
+        self.item(self.index)
+    }
+
+    fn peek_next(&self) -> Option<&T> {
+        self.item((self.index + 1) % (self.rope.len() + 1))
+    }
+
+    fn peek_prev(&self) -> Option<&T> {
+        let len = self.rope.len();
+        self.item((self.index + len) % (len + 1))
+    }
+}
+
+#[test]
+fn test_rope() {
+    let mut list: Rope<_> = (0..1000).collect::<Vec<_>>().into();
+    let mut items: Vec<_> = (0..1000).collect();
+
+    for i in 0..500 {
+        list.insert(i * 3, 1000 + i);
+        items.insert(i * 3, 1000 + i);
+    }
+
+    while items.len() > 100 {
+        let index = items.len() / 3;
+        assert_eq!(list.remove(index), Some(items.remove(index)));
+    }
+
+    assert!(list.drain_range(20..40).eq(items.drain(20..40)));
+    list.insert_many(5, &mut (0..200).into_iter());
+    items.splice(5..5, 0..200);
+
+    let mut tail = list.split_off(50);
+    list.rotate_left(10);
+    list.append(&mut tail);
+    items[..50].rotate_left(10);
+    list.swap(0, 99);
+    items.swap(0, 99);
+    for item in list.iter_mut() { *item += 1; }
+    for item in &mut items { *item += 1; }
+    assert!(list.iter().eq(&items));
+
+    let mut cursor = list.cursor_back();
+    for item in items[..].iter().rev() {
+        assert_eq!(cursor.current(), Some(item));
+        cursor.move_prev();
+    }
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.peek_next(), items.first());
+}
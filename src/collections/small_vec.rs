@@ -0,0 +1,479 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::ops::Range;
+
+use super::super::*;
+use super::ArrayVec;
+
+/// A list that stores up to `N` items inline and moves them to the heap when it grows beyond
+/// that.
+///
+/// Once a `SmallVec` has spilled to the heap, it stays there until [`shrink_to_fit`] is called
+/// while it contains no more than `N` items.
+///
+/// This crate has no `Stack` trait, so a `SmallVec` is used as a stack through [`List::push`],
+/// [`List::pop`] and [`List::last`], which it implements without going through the defaults.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::{Collection, List};
+/// use eclectic::collections::SmallVec;
+///
+/// let mut list = SmallVec::<_, 2>::new();
+/// list.push(1);
+/// list.push(2);
+/// assert!(!list.spilled());
+///
+/// list.push(3);
+/// assert!(list.spilled());
+///
+/// list.pop();
+/// list.shrink_to_fit();
+/// assert!(!list.spilled());
+/// assert_eq!(list.as_slice(), [1, 2]);
+/// ```
+///
+/// [`List::last`]: ../trait.List.html#method.last
+/// [`List::pop`]: ../trait.List.html#method.pop
+/// [`List::push`]: ../trait.List.html#method.push
+/// [`shrink_to_fit`]: ../trait.Collection.html#tymethod.shrink_to_fit
+#[derive(Clone)]
+pub struct SmallVec<T, const N: usize> {
+    data: Data<T, N>,
+}
+
+#[derive(Clone)]
+enum Data<T, const N: usize> {
+    Inline(ArrayVec<T, N>),
+    Heap(Vec<T>),
+}
+
+impl<T, const N: usize> SmallVec<T, N> {
+    /// Returns a new, empty list.
+    pub fn new() -> Self {
+        SmallVec { data: Data::Inline(ArrayVec::new()) }
+    }
+
+    /// Checks if the list's items are stored on the heap.
+    pub fn spilled(&self) -> bool {
+        match self.data {
+            Data::Inline(_) => false,
+            Data::Heap(_) => true,
+        }
+    }
+
+    /// Returns a slice that contains the list's items.
+    pub fn as_slice(&self) -> &[T] {
+        match self.data {
+            Data::Inline(ref items) => items.as_slice(),
+            Data::Heap(ref items) => items,
+        }
+    }
+
+    /// Returns a mutable slice that contains the list's items.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        match self.data {
+            Data::Inline(ref mut items) => items.as_mut_slice(),
+            Data::Heap(ref mut items) => items,
+        }
+    }
+
+    /// Moves the list's items to the heap if they would not fit inline after inserting the given
+    /// number of additional items.
+    fn grow(&mut self, additional: usize) {
+        let len = self.len();
+
+        if let Data::Inline(ref mut items) = self.data {
+            if len + additional <= N { return; }
+
+            let mut heap = Vec::with_capacity(len + additional);
+            heap.extend(Collection::drain(items));
+            self.data = Data::Heap(heap);
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for SmallVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<T, const N: usize> Default for SmallVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for SmallVec<T, N> {}
+
+impl<T: Hash, const N: usize> Hash for SmallVec<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for SmallVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T, const N: usize> From<Vec<T>> for SmallVec<T, N> {
+    fn from(items: Vec<T>) -> Self {
+        SmallVec { data: Data::Heap(items) }
+    }
+}
+
+impl<T, const N: usize> Mutate for SmallVec<T, N> {}
+
+impl<T, const N: usize> AddRemove for SmallVec<T, N> {}
+
+impl<T, const N: usize> Collection for SmallVec<T, N> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        match self.data {
+            Data::Inline(ref items) => items.len(),
+            Data::Heap(ref items) => items.len(),
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        match self.data {
+            Data::Inline(_) => N,
+            Data::Heap(ref items) => items.capacity(),
+        }
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        self.reserve(other.len());
+        self.extend_object(&mut Collection::drain(other));
+    }
+
+    fn extend_object(&mut self, items: &mut Iterator<Item = T>) {
+        self.reserve(items.size_hint().0);
+
+        for item in items {
+            self.push(item);
+        }
+    }
+
+    fn clear(&mut self) {
+        match self.data {
+            Data::Inline(ref mut items) => items.clear(),
+            Data::Heap(ref mut items) => items.clear(),
+        }
+    }
+
+    fn retain(&mut self, f: &mut FnMut(&T) -> bool) {
+        match self.data {
+            Data::Inline(ref mut items) => {
+                let mut len = 0;
+
+                for i in 0..items.len() {
+                    if f(&items.as_slice()[i]) {
+                        items.as_mut_slice().swap(len, i);
+                        len += 1;
+                    }
+                }
+
+                items.truncate(len);
+            }
+            Data::Heap(ref mut items) => items.retain(f),
+        }
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = T> + 'a> {
+        match self.data {
+            Data::Inline(ref mut items) => Collection::drain(items),
+            Data::Heap(ref mut items) => Box::new(items.drain(..)),
+        }
+    }
+
+    fn extract_if<'a>(&'a mut self, pred: &'a mut FnMut(&T) -> bool)
+        -> Box<Iterator<Item = T> + 'a>
+    {
+        match self.data {
+            Data::Inline(ref mut items) => Collection::extract_if(items, pred),
+            Data::Heap(ref mut items) => Box::new(items.extract_if(.., move |item| pred(item))),
+        }
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.grow(additional);
+
+        if let Data::Heap(ref mut items) = self.data {
+            items.reserve(additional);
+        }
+    }
+
+    fn shrink_to_fit(&mut self) {
+        let items = match self.data {
+            Data::Inline(_) => return,
+            Data::Heap(ref mut items) if items.len() > N => return items.shrink_to_fit(),
+            Data::Heap(ref mut items) => mem::replace(items, vec![]),
+        };
+
+        let mut inline = ArrayVec::new();
+        inline.extend_object(&mut items.into_iter());
+        self.data = Data::Inline(inline);
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        if capacity <= N {
+            Self::new()
+        } else {
+            SmallVec { data: Data::Heap(Vec::with_capacity(capacity)) }
+        }
+    }
+
+    fn into_vec(self) -> Vec<T> {
+        match self.data {
+            Data::Inline(items) => items.into_vec(),
+            Data::Heap(items) => items,
+        }
+    }
+}
+
+impl<T, const N: usize> Iter for SmallVec<T, N> {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a T> + 'a> {
+        Box::new(self.as_slice().iter())
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut T> + 'a> {
+        Box::new(self.as_mut_slice().iter_mut())
+    }
+}
+
+impl<T, const N: usize> DrainRange<Range<usize>> for SmallVec<T, N> {
+    fn drain_range<'a>(&'a mut self, range: Range<usize>) -> Box<Iterator<Item = T> + 'a> {
+        match self.data {
+            Data::Inline(ref mut items) => items.drain_range(range),
+            Data::Heap(ref mut items) => Box::new(items.drain(range)),
+        }
+    }
+}
+
+impl<T, const N: usize> List for SmallVec<T, N> {
+    fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.as_mut_slice().swap(i, j);
+    }
+
+    fn reverse(&mut self) {
+        self.as_mut_slice().reverse();
+    }
+
+    fn rotate_left(&mut self, mid: usize) {
+        self.as_mut_slice().rotate_left(mid);
+    }
+
+    fn rotate_right(&mut self, k: usize) {
+        self.as_mut_slice().rotate_right(k);
+    }
+
+    fn first(&self) -> Option<&T> {
+        self.as_slice().first()
+    }
+
+    fn first_mut(&mut self) -> Option<&mut T> {
+        self.as_mut_slice().first_mut()
+    }
+
+    fn last(&self) -> Option<&T> {
+        self.as_slice().last()
+    }
+
+    fn last_mut(&mut self) -> Option<&mut T> {
+        self.as_mut_slice().last_mut()
+    }
+
+    fn push(&mut self, item: T) {
+        self.grow(1);
+
+        match self.data {
+            Data::Inline(ref mut items) => items.push(item),
+            Data::Heap(ref mut items) => items.push(item),
+        }
+    }
+
+    fn insert(&mut self, index: usize, item: T) {
+        assert!(index <= self.len(), "index out of bounds");
+        self.grow(1);
+
+        match self.data {
+            Data::Inline(ref mut items) => items.insert(index, item),
+            Data::Heap(ref mut items) => items.insert(index, item),
+        }
+    }
+
+    fn insert_many(&mut self, index: usize, items: &mut Iterator<Item = T>) {
+        assert!(index <= self.len(), "index out of bounds");
+
+        // the items are buffered so that the list moves to the heap at most once
+        let items: Vec<_> = items.collect();
+        self.grow(items.len());
+
+        match self.data {
+            Data::Inline(ref mut list) => {
+                let len = list.len();
+                list.extend_object(&mut items.into_iter());
+                list.as_mut_slice()[index..].rotate_left(len - index);
+            }
+            Data::Heap(ref mut list) => {
+                list.splice(index..index, items);
+            }
+        }
+    }
+
+    fn splice<'a>(&'a mut self, range: Range<usize>, items: &'a mut Iterator<Item = T>)
+        -> Box<Iterator<Item = T> + 'a>
+    {
+        match self.data {
+            Data::Heap(ref mut list) => Box::new(list.splice(range, items)),
+            Data::Inline(_) => {
+                let index = range.start;
+                let removed: Vec<_> = self.drain_range(range).collect();
+                self.insert_many(index, items);
+                Box::new(removed.into_iter())
+            }
+        }
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        match self.data {
+            Data::Inline(ref mut items) => items.pop(),
+            Data::Heap(ref mut items) => items.pop(),
+        }
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        match self.data {
+            Data::Inline(ref mut items) => items.remove(index),
+            Data::Heap(ref mut items) => List::remove(items, index),
+        }
+    }
+
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        match self.data {
+            Data::Inline(ref mut items) => items.swap_remove(index),
+            Data::Heap(ref mut items) => List::swap_remove(items, index),
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        match self.data {
+            Data::Inline(ref mut items) => items.truncate(len),
+            Data::Heap(ref mut items) => items.truncate(len),
+        }
+    }
+
+    fn resize_with(&mut self, len: usize, f: &mut FnMut() -> T) {
+        let additional = len.saturating_sub(self.len());
+        self.grow(additional);
+
+        match self.data {
+            Data::Inline(ref mut items) => items.resize_with(len, f),
+            Data::Heap(ref mut items) => items.resize_with(len, f),
+        }
+    }
+
+    fn split_off(&mut self, index: usize) -> Self {
+        assert!(index <= self.len(), "index out of bounds");
+
+        let len = self.len();
+        let mut other = Self::with_capacity(len - index);
+        other.extend_object(&mut self.drain_range(index..len));
+        other
+    }
+
+    fn dedup_by(&mut self, same_bucket: &mut FnMut(&mut T, &mut T) -> bool) {
+        match self.data {
+            Data::Inline(ref mut items) => {
+                if items.len() <= 1 { return; }
+
+                let mut len = 1;
+
+                for i in 1..items.len() {
+                    let slice = items.as_mut_slice();
+                    let (retained, rest) = slice.split_at_mut(i);
+
+                    if !same_bucket(&mut rest[0], &mut retained[len - 1]) {
+                        slice.swap(len, i);
+                        len += 1;
+                    }
+                }
+
+                items.truncate(len);
+            }
+            Data::Heap(ref mut items) => items.dedup_by(|a, b| same_bucket(a, b)),
+        }
+    }
+
+    fn contiguous(&self) -> Option<&[T]> {
+        Some(self.as_slice())
+    }
+
+    fn contiguous_mut(&mut self) -> Option<&mut [T]> {
+        Some(self.as_mut_slice())
+    }
+}
+
+impl<T, const N: usize> Segmented for SmallVec<T, N> {
+    fn as_slices(&self) -> (&[T], &[T]) {
+        (self.as_slice(), &[])
+    }
+
+    fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        (self.as_mut_slice(), &mut [])
+    }
+
+    fn make_contiguous(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> Contiguous for SmallVec<T, N> {
+    fn as_slice(&self) -> &[T] {
+        self.as_slice()
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+#[test]
+fn test_small_vec() {
+    let mut list = SmallVec::<_, 5>::new();
+    list.extend_object(&mut vec![5, 1, 1, 3].into_iter());
+    assert!(!list.spilled());
+
+    list.dedup();
+    list.insert_many(1, &mut vec![4, 2].into_iter());
+    assert!(!list.spilled());
+    assert_eq!(list.as_slice(), [5, 4, 2, 1, 3]);
+
+    list.retain(&mut |&item| item != 4);
+    list.insert_many(0, &mut vec![6, 7].into_iter());
+    assert!(list.spilled());
+
+    algo::sort::sort(&mut list);
+    assert_eq!(list.split_off(3).into_vec(), [5, 6, 7]);
+
+    list.shrink_to_fit();
+    assert!(!list.spilled());
+    assert_eq!(list.splice(0..1, &mut vec![0; 4].into_iter()).collect::<Vec<_>>(), [1]);
+    assert!(list.spilled());
+    assert_eq!(list.into_vec(), [0, 0, 0, 0, 2, 3]);
+}
@@ -0,0 +1,306 @@
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+
+use super::super::*;
+use super::super::algo::search;
+
+/// A set that is backed by a list whose items are kept in ascending order.
+///
+/// Lookups use binary search on the underlying list, so a `SortedList` backed by a `Vec` is a
+/// compact alternative to a `BTreeSet` for sets that are small or rarely modified. The list can be
+/// borrowed with [`as_list`] for use with algorithms that operate on sorted lists, but it cannot
+/// be mutated directly because that could break the ordering.
+///
+/// The underlying list must implement [`AddRemove`], because every insertion into the set is an
+/// insertion into the list at the index found by the search. A list with cheap insertion in the
+/// middle (e.g. a `VecDeque`, or a `Vec` for small sets) keeps the set's updates fast.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::Set;
+/// use eclectic::collections::SortedList;
+/// use eclectic::set::Base;
+/// use std::collections::VecDeque;
+///
+/// let mut set = SortedList::from_list(VecDeque::from(vec![3, 1, 2, 1]));
+/// assert!(set.as_list().iter().eq(&[1, 2, 3]));
+///
+/// assert!(set.insert(0));
+/// assert!(!set.insert(2));
+/// assert!(set.contains(&3));
+///
+/// assert_eq!(set.take(&1), Some(1));
+/// assert!(set.as_list().iter().eq(&[0, 2, 3]));
+/// ```
+///
+/// [`AddRemove`]: ../trait.AddRemove.html
+/// [`as_list`]: #method.as_list
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SortedList<L> {
+    list: L,
+}
+
+impl<L: List + AddRemove> SortedList<L> where L::Item: Ord {
+    /// Returns a new set that contains the given list's items.
+    ///
+    /// The list is sorted and duplicate items are removed from it, keeping the first of each run
+    /// of equal items.
+    pub fn from_list(mut list: L) -> Self {
+        let mut items: Vec<_> = list.drain_range(..).collect();
+        items.sort();
+        items.dedup();
+        list.extend_object(&mut items.into_iter());
+        SortedList { list: list }
+    }
+
+    /// Returns a reference to the underlying list.
+    pub fn as_list(&self) -> &L {
+        &self.list
+    }
+
+    /// Consumes the set and returns the underlying list.
+    pub fn into_inner(self) -> L {
+        self.list
+    }
+
+    /// Searches the set for the given item.
+    ///
+    /// Returns `Ok(index)` if the set contains an equivalent item, where `index` is the item's
+    /// index in the underlying list. Otherwise, returns `Err(index)`, where `index` is the index
+    /// at which the item would be inserted.
+    pub fn binary_search<Q: ?Sized + Ord>(&self, item: &Q) -> Result<usize, usize>
+        where L::Item: Borrow<Q>
+    {
+        search::binary_search_by(&self.list, |probe| probe.borrow().cmp(item))
+    }
+}
+
+impl<L: List + AddRemove> AddRemove for SortedList<L> where L::Item: Ord {}
+
+impl<L: List + AddRemove> Collection for SortedList<L> where L::Item: Ord {
+    type Item = L::Item;
+
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    fn capacity(&self) -> usize {
+        self.list.capacity()
+    }
+
+    fn extend_object(&mut self, items: &mut Iterator<Item = L::Item>) {
+        let mut items: Vec<_> = items.collect();
+        items.sort();
+        items.dedup();
+
+        let mut merged = Vec::with_capacity(self.list.len() + items.len());
+        let mut items = items.into_iter().peekable();
+
+        for item in self.list.drain_range(..) {
+            while let Some(other) = items.next_if(|other| *other < item) {
+                merged.push(other);
+            }
+
+            items.next_if(|other| *other == item);
+            merged.push(item);
+        }
+
+        merged.extend(items);
+        self.list.extend_object(&mut merged.into_iter());
+    }
+
+    fn clear(&mut self) {
+        self.list.clear();
+    }
+
+    fn retain(&mut self, f: &mut FnMut(&L::Item) -> bool) {
+        self.list.retain(f);
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = L::Item> + 'a> {
+        self.list.drain_range(..)
+    }
+
+    fn extract_if<'a>(&'a mut self, pred: &'a mut FnMut(&L::Item) -> bool)
+        -> Box<Iterator<Item = L::Item> + 'a>
+    {
+        self.list.extract_if(pred)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.list.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.list.shrink_to_fit();
+    }
+
+    fn with_capacity(capacity: usize) -> Self where Self: Default {
+        let mut set = Self::default();
+        set.list.reserve(capacity);
+        set
+    }
+
+    fn into_vec(self) -> Vec<L::Item> {
+        self.list.into_vec()
+    }
+}
+
+impl<L: List + AddRemove> Iter for SortedList<L> where L::Item: Ord {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a L::Item> + 'a> {
+        self.list.iter()
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut L::Item> + 'a>
+        where Self: Mutate
+    {
+        unimplemented!()
+    }
+}
+
+impl<L: List + AddRemove> set::Base for SortedList<L> where L::Item: Ord {
+    fn is_disjoint(&self, other: &Self) -> bool {
+        let mut items = self.list.iter();
+        let mut others = other.list.iter();
+        let (mut item, mut other) = (items.next(), others.next());
+
+        while let (Some(a), Some(b)) = (item, other) {
+            match a.cmp(b) {
+                Ordering::Less => item = items.next(),
+                Ordering::Greater => other = others.next(),
+                Ordering::Equal => return false,
+            }
+        }
+
+        true
+    }
+
+    fn is_subset(&self, other: &Self) -> bool {
+        if self.len() > other.len() { return false; }
+
+        let mut others = other.list.iter();
+
+        self.list.iter().all(|item| {
+            others.by_ref().find(|&other| other >= item) == Some(item)
+        })
+    }
+
+    fn insert(&mut self, item: L::Item) -> bool {
+        match self.binary_search(&item) {
+            Ok(_) => false,
+            Err(index) => {
+                self.list.insert(index, item);
+                true
+            }
+        }
+    }
+
+    fn replace(&mut self, item: L::Item) -> Option<L::Item> {
+        match self.binary_search(&item) {
+            Ok(index) => {
+                let old = self.list.remove(index);
+                self.list.insert(index, item);
+                old
+            }
+            Err(index) => {
+                self.list.insert(index, item);
+                None
+            }
+        }
+    }
+
+    fn entry<'a>(&'a mut self, item: L::Item) -> set::Entry<'a, L::Item> {
+        match self.binary_search(&item) {
+            Ok(index) => {
+                set::Entry::Occupied(Box::new(OccupiedEntry { list: &mut self.list, index: index }))
+            }
+            Err(index) => set::Entry::Vacant(Box::new(VacantEntry {
+                list: &mut self.list,
+                index: index,
+                item: item,
+            })),
+        }
+    }
+}
+
+impl<L: List + AddRemove, Q: ?Sized + Ord> Set<Q> for SortedList<L>
+    where L::Item: Ord + Borrow<Q>
+{
+    fn get(&self, item: &Q) -> Option<&L::Item> {
+        self.binary_search(item).ok().and_then(|index| self.list.get(index))
+    }
+
+    fn take(&mut self, item: &Q) -> Option<L::Item> {
+        self.binary_search(item).ok().and_then(|index| self.list.remove(index))
+    }
+}
+
+struct OccupiedEntry<'a, L: 'a> {
+    list: &'a mut L,
+    index: usize,
+}
+
+impl<'a, L: List + AddRemove> set::OccupiedEntry for OccupiedEntry<'a, L> {
+    type Item = L::Item;
+
+    fn get(&self) -> &L::Item {
+        self.list.get(self.index).expect("index out of bounds")
+    }
+
+    fn take(self: Box<Self>) -> L::Item {
+        self.list.remove(self.index).expect("index out of bounds")
+    }
+}
+
+struct VacantEntry<'a, L: 'a + List> {
+    list: &'a mut L,
+    index: usize,
+    item: L::Item,
+}
+
+impl<'a, L: List + AddRemove> set::VacantEntry for VacantEntry<'a, L> {
+    type Item = L::Item;
+
+    fn get(&self) -> &L::Item {
+        &self.item
+    }
+
+    fn into_item(self: Box<Self>) -> L::Item {
+        self.item
+    }
+
+    fn insert(self: Box<Self>) {
+        let this = *self;
+        this.list.insert(this.index, this.item);
+    }
+}
+
+#[test]
+fn test_sorted_list() {
+    use std::collections::LinkedList;
+    use super::super::set::Base;
+
+    let mut set = SortedList::from_list(vec![5, 1, 3]);
+    set.extend_object(&mut vec![4, 3, 0, 4].into_iter());
+    assert_eq!(*set.as_list(), [0, 1, 3, 4, 5]);
+
+    let subset = SortedList::from_list(vec![1, 4]);
+    assert!(subset.is_subset(&set));
+    assert!(!set.is_subset(&subset));
+    assert!(!subset.is_disjoint(&set));
+    assert!(SortedList::from_list(vec![2, 6]).is_disjoint(&set));
+
+    let mut set: SortedList<LinkedList<_>> = SortedList::from_list(set.into_inner().into_iter()
+                                                                     .rev().collect());
+    assert_eq!(set.replace(3), Some(3));
+    assert!(set.remove(&0));
+    assert_eq!(set.into_vec(), [1, 3, 4, 5]);
+
+    let set: SortedList<Vec<i32>> = Collection::with_capacity(4);
+    assert!(set.is_empty() && set.as_list().capacity() >= 4);
+}
@@ -0,0 +1,452 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::mem;
+
+use super::super::*;
+
+/// A node in a trie.
+///
+/// The node's entry, if any, is the one whose key consists of the items on the path from the root
+/// to the node.
+struct Node<K: List, V> {
+    entry: Option<(K, V)>,
+    children: BTreeMap<K::Item, Node<K, V>>,
+}
+
+impl<K: List, V> Node<K, V> where K::Item: Ord + Clone {
+    fn new() -> Self {
+        Node { entry: None, children: BTreeMap::new() }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.entry.is_none() && self.children.is_empty()
+    }
+
+    /// Returns the node whose path consists of the given key's items.
+    fn find<Q: ?Sized + List<Item = K::Item>>(&self, key: &Q) -> Option<&Self> {
+        let mut node = self;
+        for item in key.iter() { node = node.children.get(item)?; }
+        Some(node)
+    }
+
+    /// Returns the node whose path consists of the given key's items.
+    fn find_mut<Q: ?Sized + List<Item = K::Item>>(&mut self, key: &Q) -> Option<&mut Self> {
+        let mut node = self;
+        for item in key.iter() { node = node.children.get_mut(item)?; }
+        Some(node)
+    }
+
+    /// Returns the node whose path consists of the given key's items, creating the nodes on the
+    /// path that do not exist.
+    fn find_or_insert(&mut self, key: &K) -> &mut Self {
+        let mut node = self;
+
+        for item in key.iter() {
+            node = node.children.entry(item.clone()).or_insert_with(Node::new);
+        }
+
+        node
+    }
+
+    /// Calls the given function with the node whose path consists of the items yielded by the
+    /// given iterator and returns its result, removing the nodes on the path that are left empty.
+    fn remove_with<'b, I, R, F>(&mut self, items: &mut I, f: F) -> Option<R>
+        where K::Item: 'b, I: Iterator<Item = &'b K::Item>, F: FnOnce(&mut Self) -> Option<R>
+    {
+        let item = match items.next() {
+            Some(item) => item,
+            None => return f(self),
+        };
+
+        let (result, empty) = {
+            let child = self.children.get_mut(item)?;
+            let result = child.remove_with(items, f);
+            (result, child.is_empty())
+        };
+
+        if empty { self.children.remove(item); }
+        result
+    }
+
+    /// Pushes the entries in the node's subtree onto the given vector in order.
+    fn into_vec(self, entries: &mut Vec<(K, V)>) {
+        entries.extend(self.entry);
+
+        for (_, child) in self.children {
+            child.into_vec(entries);
+        }
+    }
+
+    /// Pushes references to the entries in the node's subtree onto the given vector in order.
+    fn collect_mut<'a>(&'a mut self, entries: &mut Vec<(&'a K, &'a mut V)>) {
+        let Node { ref mut entry, ref mut children } = *self;
+
+        if let Some((ref key, ref mut value)) = *entry {
+            entries.push((key, value));
+        }
+
+        for child in children.values_mut() {
+            child.collect_mut(entries);
+        }
+    }
+}
+
+impl<K: List + Clone, V: Clone> Clone for Node<K, V> where K::Item: Clone {
+    fn clone(&self) -> Self {
+        Node { entry: self.entry.clone(), children: self.children.clone() }
+    }
+}
+
+/// A map whose keys are lists, stored as a trie.
+///
+/// Each key is stored along the path of nodes that corresponds to its items, so keys that share a
+/// prefix share the nodes for that prefix. Lookups take time proportional to the length of the
+/// key rather than the number of keys, and the map supports the queries of [`PrefixMap`]. The
+/// iteration methods yield the entries in lexicographic order of their keys' items.
+///
+/// Keys can be looked up with any list whose items are of the same type, so a `TrieMap` whose keys
+/// are `String`s can be queried with `str`s or `[u8]`s.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::Collection;
+/// use eclectic::map::{Base, Map, PrefixMap};
+/// use eclectic::collections::TrieMap;
+///
+/// let mut routes = TrieMap::new();
+/// routes.insert("/".to_string(), 0);
+/// routes.insert("/api".to_string(), 1);
+/// routes.insert("/api/users".to_string(), 2);
+/// routes.insert("/about".to_string(), 3);
+///
+/// assert_eq!(routes.get("/api"), Some(&1));
+/// assert_eq!(routes.longest_prefix_match("/api/items/7"), Some((&"/api".to_string(), &1)));
+/// assert!(routes.iter_prefix("/a").map(|(_, &v)| v).eq(vec![3, 1, 2]));
+///
+/// assert_eq!(routes.remove_prefix("/api").count(), 2);
+/// assert_eq!(routes.len(), 2);
+/// ```
+///
+/// [`PrefixMap`]: ../map/trait.PrefixMap.html
+pub struct TrieMap<K: List, V> {
+    root: Node<K, V>,
+    len: usize,
+}
+
+impl<K: List, V> TrieMap<K, V> where K::Item: Ord + Clone {
+    /// Returns a new, empty map.
+    pub fn new() -> Self {
+        TrieMap { root: Node::new(), len: 0 }
+    }
+}
+
+impl<K: List + Clone, V: Clone> Clone for TrieMap<K, V> where K::Item: Clone {
+    fn clone(&self) -> Self {
+        TrieMap { root: self.root.clone(), len: self.len }
+    }
+}
+
+impl<K: List + fmt::Debug, V: fmt::Debug> fmt::Debug for TrieMap<K, V> where K::Item: Ord + Clone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(Entries::new(&self.root)).finish()
+    }
+}
+
+impl<K: List, V> Default for TrieMap<K, V> where K::Item: Ord + Clone {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: List + Eq, V: Eq> Eq for TrieMap<K, V> where K::Item: Ord + Clone {}
+
+impl<K: List + PartialEq, V: PartialEq> PartialEq for TrieMap<K, V> where K::Item: Ord + Clone {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && Entries::new(&self.root).eq(Entries::new(&other.root))
+    }
+}
+
+impl<K: List, V> Mutate for TrieMap<K, V> where K::Item: Ord + Clone {}
+
+impl<K: List, V> AddRemove for TrieMap<K, V> where K::Item: Ord + Clone {}
+
+impl<K: List, V> Collection for TrieMap<K, V> where K::Item: Ord + Clone {
+    type Item = (K, V);
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn capacity(&self) -> usize {
+        self.len
+    }
+
+    fn extend_object(&mut self, entries: &mut Iterator<Item = (K, V)>) {
+        for (key, value) in entries {
+            map::Base::insert(self, key, value);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.root = Node::new();
+        self.len = 0;
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = (K, V)> + 'a> {
+        Box::new(mem::replace(self, Self::new()).into_vec().into_iter())
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn shrink_to_fit(&mut self) {}
+
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::new()
+    }
+
+    fn into_vec(self) -> Vec<(K, V)> {
+        let mut entries = Vec::with_capacity(self.len);
+        self.root.into_vec(&mut entries);
+        entries
+    }
+}
+
+impl<K: List, V> map::Base for TrieMap<K, V> where K::Item: Ord + Clone {
+    type Key = K;
+    type Value = V;
+
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = (&'a K, &'a V)> + 'a> {
+        Box::new(Entries::new(&self.root))
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = (&'a K, &'a mut V)> + 'a> {
+        let mut entries = Vec::with_capacity(self.len);
+        self.root.collect_mut(&mut entries);
+        Box::new(entries.into_iter())
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let node = self.root.find_or_insert(&key);
+
+        if let Some((_, ref mut old)) = node.entry {
+            return Some(mem::replace(old, value));
+        }
+
+        node.entry = Some((key, value));
+        self.len += 1;
+        None
+    }
+
+    fn entry<'a>(&'a mut self, key: K) -> map::Entry<'a, K, V> {
+        if Map::contains_key(self, &key) {
+            map::Entry::Occupied(Box::new(OccupiedEntry { map: self, key: key }))
+        } else {
+            map::Entry::Vacant(Box::new(VacantEntry { map: self, key: key }))
+        }
+    }
+
+    fn replace(&mut self, key: K, value: V) -> Option<(K, V)> {
+        let old = mem::replace(&mut self.root.find_or_insert(&key).entry, Some((key, value)));
+        if old.is_none() { self.len += 1; }
+        old
+    }
+}
+
+impl<K: List, V, Q: ?Sized + List<Item = K::Item>> Map<Q> for TrieMap<K, V>
+    where K::Item: Ord + Clone
+{
+    fn get(&self, key: &Q) -> Option<&V> {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    fn get_key_value(&self, key: &Q) -> Option<(&K, &V)> {
+        self.root.find(key)?.entry.as_ref().map(|&(ref key, ref value)| (key, value))
+    }
+
+    fn get_mut(&mut self, key: &Q) -> Option<&mut V> {
+        self.root.find_mut(key)?.entry.as_mut().map(|&mut (_, ref mut value)| value)
+    }
+
+    fn remove(&mut self, key: &Q) -> Option<V> {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    fn remove_entry(&mut self, key: &Q) -> Option<(K, V)> {
+        let entry = self.root.remove_with(&mut key.iter(), |node| node.entry.take());
+        if entry.is_some() { self.len -= 1; }
+        entry
+    }
+}
+
+impl<K: List, V, Q: ?Sized + List<Item = K::Item>> map::PrefixMap<Q> for TrieMap<K, V>
+    where K::Item: Ord + Clone
+{
+    fn iter_prefix<'a>(&'a self, prefix: &Q) -> Box<Iterator<Item = (&'a K, &'a V)> + 'a> {
+        Box::new(Entries { stack: self.root.find(prefix).into_iter().collect() })
+    }
+
+    fn longest_prefix_match(&self, key: &Q) -> Option<(&K, &V)> {
+        let mut node = &self.root;
+        let mut entry = node.entry.as_ref();
+
+        for item in key.iter() {
+            node = match node.children.get(item) {
+                Some(child) => child,
+                None => break,
+            };
+
+            if node.entry.is_some() { entry = node.entry.as_ref(); }
+        }
+
+        entry.map(|&(ref key, ref value)| (key, value))
+    }
+
+    fn remove_prefix<'a>(&'a mut self, prefix: &Q) -> Box<Iterator<Item = (K, V)> + 'a> {
+        let mut entries = vec![];
+
+        if let Some(node) = self.root.remove_with(&mut prefix.iter(), |node| {
+            Some(mem::replace(node, Node::new()))
+        }) {
+            node.into_vec(&mut entries);
+        }
+
+        self.len -= entries.len();
+        Box::new(entries.into_iter())
+    }
+}
+
+/// An iterator that yields references to the entries in a trie in order.
+struct Entries<'a, K: 'a + List, V: 'a> {
+    stack: Vec<&'a Node<K, V>>,
+}
+
+impl<'a, K: List, V> Entries<'a, K, V> {
+    fn new(root: &'a Node<K, V>) -> Self {
+        Entries { stack: vec![root] }
+    }
+}
+
+impl<'a, K: List, V> Iterator for Entries<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        while let Some(node) = self.stack.pop() {
+            self.stack.extend(node.children.values().rev());
+
+            if let Some((ref key, ref value)) = node.entry {
+                return Some((key, value));
+            }
+        }
+
+        None
+    }
+}
+
+struct OccupiedEntry<'a, K: 'a + List, V: 'a> {
+    map: &'a mut TrieMap<K, V>,
+    key: K,
+}
+
+impl<'a, K: List, V> map::OccupiedEntry for OccupiedEntry<'a, K, V> where K::Item: Ord + Clone {
+    type Key = K;
+    type Value = V;
+    type MutValue = &'a mut V;
+
+    fn key(&self) -> &K {
+        Map::get_key_value(self.map, &self.key)
+            .expect("occupied entry's key is missing from the map").0
+    }
+
+    fn get(&self) -> &V {
+        Map::get(self.map, &self.key).expect("occupied entry's key is missing from the map")
+    }
+
+    fn get_mut(&mut self) -> &mut V {
+        Map::get_mut(self.map, &self.key).expect("occupied entry's key is missing from the map")
+    }
+
+    fn into_mut(self: Box<Self>) -> &'a mut V {
+        let this = *self;
+        Map::get_mut(this.map, &this.key).expect("occupied entry's key is missing from the map")
+    }
+
+    fn remove(self: Box<Self>) -> V {
+        self.remove_entry().1
+    }
+
+    fn remove_entry(self: Box<Self>) -> (K, V) {
+        Map::remove_entry(self.map, &self.key)
+            .expect("occupied entry's key is missing from the map")
+    }
+
+    fn replace_key(self: Box<Self>, key: K) -> K {
+        let this = *self;
+        let node = this.map.root.find_mut(&this.key)
+            .expect("occupied entry's key is missing from the map");
+        let entry = node.entry.as_mut().expect("occupied entry's key is missing from the map");
+        mem::replace(&mut entry.0, key)
+    }
+}
+
+struct VacantEntry<'a, K: 'a + List, V: 'a> {
+    map: &'a mut TrieMap<K, V>,
+    key: K,
+}
+
+impl<'a, K: List, V> map::VacantEntry for VacantEntry<'a, K, V> where K::Item: Ord + Clone {
+    type Key = K;
+    type Value = V;
+    type MutValue = &'a mut V;
+
+    fn key(&self) -> &K {
+        &self.key
+    }
+
+    fn into_key(self: Box<Self>) -> K {
+        self.key
+    }
+
+    fn insert(self: Box<Self>, value: V) -> &'a mut V {
+        let this = *self;
+        this.map.len += 1;
+
+        let node = this.map.root.find_or_insert(&this.key);
+        node.entry = Some((this.key, value));
+        &mut node.entry.as_mut().expect("inserted entry is missing from the map").1
+    }
+}
+
+#[test]
+fn test_trie_map() {
+    use super::super::map::{Base, PrefixMap};
+
+    let keys = |map: &TrieMap<String, usize>| -> Vec<String> {
+        map.iter().map(|(key, _)| key.clone()).collect()
+    };
+
+    let mut map = TrieMap::new();
+    for word in &["tea", "ten", "to", "in", "inn", "i", "tent"] {
+        map.insert(word.to_string(), word.len());
+    }
+
+    *map.entry("t".to_string()).or_insert(0) += 10;
+    *map.entry("tea".to_string()).or_insert(0) += 10;
+    assert_eq!(map.len(), 8);
+    assert_eq!(keys(&map), ["i", "in", "inn", "t", "tea", "ten", "tent", "to"]);
+
+    assert_eq!(map.get(&b"inn"[..]), Some(&3));
+    assert_eq!(map.longest_prefix_match("tense").map(|(_, &v)| v), Some(3));
+    assert_eq!(map.longest_prefix_match("x"), None);
+    assert!(map.iter_prefix("te").map(|(_, &v)| v).eq(vec![13, 3, 4]));
+    assert_eq!(map.iter_prefix("").count(), 8);
+
+    assert_eq!(map.remove("te"), None);
+    assert_eq!(map.remove("t"), Some(10));
+    assert!(map.remove_prefix("te").map(|(k, _)| k).eq(vec!["tea", "ten", "tent"]));
+    assert_eq!(keys(&map), ["i", "in", "inn", "to"]);
+    assert!(map.root.find("t").map_or(false, |node| node.children.len() == 1));
+
+    assert_eq!(map.remove_prefix("").count(), 4);
+    assert!(map.is_empty() && map.root.is_empty());
+}
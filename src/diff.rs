@@ -0,0 +1,283 @@
+//! Differences between collections.
+//!
+//! The functions in this module compute the changes that transform one collection into another,
+//! and apply such changes to a collection. The changes are plain values, so they can be stored,
+//! inspected, or replayed onto any collection that supports insertion and removal, regardless of
+//! the types of the collections from which they were computed.
+//!
+//! # Examples
+//!
+//! ```
+//! use eclectic::diff::{self, MapChange};
+//! use std::collections::{BTreeMap, HashMap};
+//!
+//! let old: BTreeMap<_, _> = vec![("port", 80), ("workers", 4)].into_iter().collect();
+//! let new: BTreeMap<_, _> = vec![("port", 8080), ("timeout", 30)].into_iter().collect();
+//!
+//! let changes = diff::diff_maps(&old, &new);
+//! assert_eq!(changes, [
+//!     MapChange::Changed("port", 8080),
+//!     MapChange::Removed("workers"),
+//!     MapChange::Added("timeout", 30),
+//! ]);
+//!
+//! let mut live: HashMap<_, _> = old.into_iter().collect();
+//! diff::patch_map(&mut live, changes);
+//! assert_eq!(live, new.into_iter().collect());
+//! ```
+
+use super::*;
+use super::algo::map::{self, EitherOrBoth};
+
+/// A change to a map.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MapChange<K, V> {
+    /// An entry with the given key and value was added.
+    Added(K, V),
+    /// The entry with the given key was removed.
+    Removed(K),
+    /// The value of the entry with the given key was changed to the given value.
+    Changed(K, V),
+}
+
+/// A change to a set.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SetChange<T> {
+    /// The given item was added.
+    Added(T),
+    /// The given item was removed.
+    Removed(T),
+}
+
+/// A change to a list.
+///
+/// The index of each change in an edit script refers to the list as it is after all preceding
+/// changes in the script have been applied.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ListChange<T> {
+    /// The given item was inserted at the given index.
+    Inserted(usize, T),
+    /// The item at the given index was removed.
+    Removed(usize),
+}
+
+/// Returns the changes that transform the first map into the second.
+///
+/// The changes are ordered as the entries of the first map, followed by the entries that are
+/// present only in the second map.
+pub fn diff_maps<A, B>(old: &A, new: &B) -> Vec<MapChange<A::Key, B::Value>>
+    where A: ?Sized + Map,
+          B: ?Sized + Map<A::Key, Key = A::Key>,
+          A::Key: Clone,
+          A::Value: PartialEq<B::Value>,
+          B::Value: Clone
+{
+    map::diff(old, new).map(|(key, entry)| match entry {
+        EitherOrBoth::Left(_) => MapChange::Removed(key.clone()),
+        EitherOrBoth::Right(value) => MapChange::Added(key.clone(), value.clone()),
+        EitherOrBoth::Both(_, value) => MapChange::Changed(key.clone(), value.clone()),
+    }).collect()
+}
+
+/// Applies the given changes to the map.
+pub fn patch_map<M, I>(map: &mut M, changes: I)
+    where M: ?Sized + Map + AddRemove, I: IntoIterator<Item = MapChange<M::Key, M::Value>>
+{
+    for change in changes {
+        match change {
+            MapChange::Added(key, value) | MapChange::Changed(key, value) => {
+                map.insert(key, value);
+            }
+            MapChange::Removed(key) => {
+                map.remove(&key);
+            }
+        }
+    }
+}
+
+/// Returns the changes that transform the first set into the second.
+///
+/// The removals are ordered as the items of the first set, and precede the additions, which are
+/// ordered as the items of the second set.
+pub fn diff_sets<A, B>(old: &A, new: &B) -> Vec<SetChange<A::Item>>
+    where A: ?Sized + Set, B: ?Sized + Set<A::Item, Item = A::Item>, A::Item: Clone
+{
+    let removed = old.iter().filter(|item| !new.contains(item)).cloned().map(SetChange::Removed);
+    let added = new.iter().filter(|item| !old.contains(item)).cloned().map(SetChange::Added);
+    removed.chain(added).collect()
+}
+
+/// Applies the given changes to the set.
+pub fn patch_set<S, I>(set: &mut S, changes: I)
+    where S: ?Sized + Set + AddRemove, I: IntoIterator<Item = SetChange<S::Item>>
+{
+    for change in changes {
+        match change {
+            SetChange::Added(item) => {
+                set.insert(item);
+            }
+            SetChange::Removed(item) => {
+                set.remove(&item);
+            }
+        }
+    }
+}
+
+/// Returns a shortest edit script that transforms the first list into the second.
+///
+/// The script is computed with Myers' algorithm, which takes `O((n + m) d)` time, where `n` and
+/// `m` are the lengths of the lists and `d` is the length of the script.
+pub fn diff_lists<A, B>(old: &A, new: &B) -> Vec<ListChange<B::Item>>
+    where A: ?Sized + List, B: ?Sized + List, A::Item: PartialEq<B::Item>, B::Item: Clone
+{
+    let old: Vec<_> = old.iter().collect();
+    let new: Vec<_> = new.iter().collect();
+
+    let mut changes = vec![];
+    let (mut index, mut i, mut j) = (0, 0, 0);
+
+    for edit in shortest_edit(&old, &new) {
+        match edit {
+            Edit::Keep => {
+                index += 1;
+                i += 1;
+                j += 1;
+            }
+            Edit::Remove => {
+                changes.push(ListChange::Removed(index));
+                i += 1;
+            }
+            Edit::Insert => {
+                changes.push(ListChange::Inserted(index, new[j].clone()));
+                index += 1;
+                j += 1;
+            }
+        }
+    }
+
+    debug_assert_eq!((i, j), (old.len(), new.len()));
+    changes
+}
+
+/// Applies the given edit script to the list.
+///
+/// # Panics
+///
+/// Panics if a change's index is out of bounds.
+pub fn patch_list<L, I>(list: &mut L, changes: I)
+    where L: ?Sized + List + AddRemove, I: IntoIterator<Item = ListChange<L::Item>>
+{
+    for change in changes {
+        match change {
+            ListChange::Inserted(index, item) => list.insert(index, item),
+            ListChange::Removed(index) => {
+                list.remove(index).expect("index out of bounds");
+            }
+        }
+    }
+}
+
+/// A step in an edit script between two sequences.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Edit {
+    Keep,
+    Remove,
+    Insert,
+}
+
+/// Returns a shortest edit script between the given sequences using Myers' algorithm.
+fn shortest_edit<T: PartialEq<U>, U>(a: &[&T], b: &[&U]) -> Vec<Edit> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = n + m;
+
+    // `v[k + max]` is the furthest `x` reached on diagonal `k = x - y`. `trace[d]` is the state of
+    // `v` before round `d`, which is used to recover the path.
+    let mut v = vec![0; 2 * max as usize + 2];
+    let mut trace = vec![];
+    let at = |k: isize| (k + max) as usize;
+
+    'search: for d in 0..max + 1 {
+        trace.push(v.clone());
+
+        for k in (-d..d + 1).step_by(2) {
+            let mut x = if k == -d || (k != d && v[at(k - 1)] < v[at(k + 1)]) {
+                v[at(k + 1)]
+            } else {
+                v[at(k - 1)] + 1
+            };
+            let mut y = x - k;
+
+            while x < n && y < m && *a[x as usize] == *b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+
+            v[at(k)] = x;
+
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut edits = vec![];
+    let (mut x, mut y) = (n, m);
+
+    for (d, v) in trace[..].iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+
+        let prev_k = if k == -d || (k != d && v[at(k - 1)] < v[at(k + 1)]) { k + 1 } else { k - 1 };
+        let prev_x = v[at(prev_k)];
+        let prev_y = prev_x - prev_k;
+
+        while x > prev_x && y > prev_y {
+            edits.push(Edit::Keep);
+            x -= 1;
+            y -= 1;
+        }
+
+        if d > 0 {
+            edits.push(if x == prev_x { Edit::Insert } else { Edit::Remove });
+        }
+
+        x = prev_x;
+        y = prev_y;
+    }
+
+    edits.reverse();
+    edits
+}
+
+#[test]
+fn test_diff_lists() {
+    use std::collections::LinkedList;
+
+    let old = vec!['a', 'b', 'c', 'a', 'b', 'b', 'a'];
+    let new: LinkedList<_> = vec!['c', 'b', 'a', 'b', 'a', 'c'].into_iter().collect();
+
+    let changes = diff_lists(&old, &new);
+    assert_eq!(changes.len(), 5);
+
+    let mut list = old.clone();
+    patch_list(&mut list, changes);
+    assert!(list.iter().eq(&new));
+
+    assert_eq!(diff_lists(&old, &old), []);
+    assert_eq!(diff_lists(&Vec::<char>::new(), &vec!['a']), [ListChange::Inserted(0, 'a')]);
+}
+
+#[test]
+fn test_diff_sets() {
+    use std::collections::{BTreeSet, HashSet};
+
+    let old: BTreeSet<_> = vec![1, 2, 3].into_iter().collect();
+    let new: HashSet<_> = vec![2, 3, 4].into_iter().collect();
+
+    let changes = diff_sets(&old, &new);
+    assert_eq!(changes, [SetChange::Removed(1), SetChange::Added(4)]);
+
+    let mut set = old;
+    patch_set(&mut set, changes);
+    assert!(set.into_iter().eq(vec![2, 3, 4]));
+}
@@ -0,0 +1,931 @@
+//! Forwarding implementations for pointers to collections.
+//!
+//! `Box<C>` and `&mut C` implement each trait that `C` does, including [`AddRemove`], provided
+//! that `C` implements `AddRemove`. They do not implement [`Mutate`], because a forwarding
+//! implementation cannot call a method that requires `C: Mutate` from one that only requires
+//! `Box<C>: Mutate`, and a type cannot implement a trait in more than one way, so a second set of
+//! implementations for `C: Mutate` would conflict with the first. The methods that require
+//! `Self: Mutate` can therefore never be called on these pointers, and are marked unreachable.
+//! Code that mutates items should take a `&mut L` with an `L: ?Sized` bound, which a `&mut Vec<T>`
+//! or a reborrowed `Box<Vec<T>>` (`&mut *boxed`) satisfies directly.
+//!
+//! `&C`, `Rc<C>` and `Arc<C>` implement each trait that `C` does, but implement neither marker,
+//! so they provide read-only access. Texts (which implement [`AddRemoveChars`] instead of
+//! `AddRemove`) are forwarded only by these read-only pointers.
+//!
+//! Traits with methods that require `Self: Sized` (e.g. [`set::Base`]) are forwarded only for
+//! collections that are `Sized`. `Box<C>` requires `C: Sized` throughout, so that it can forward
+//! `Collection::with_capacity` and `List::split_off`. `&mut C` forwards `Collection::into_vec` by
+//! draining the collection, which the crate documentation warns about.
+//!
+//! [`AddRemove`]: ../trait.AddRemove.html
+//! [`AddRemoveChars`]: ../trait.AddRemoveChars.html
+//! [`Mutate`]: ../trait.Mutate.html
+//! [`set::Base`]: ../set/trait.Base.html
+
+use std::ops::Range;
+use std::rc::Rc;
+use std::sync::Arc;
+use super::*;
+
+macro_rules! forward_add_remove {
+    ($([$($params:tt)*] $ty:ty { $($collection:tt)* } { $($list:tt)* }),*) => {$(
+        impl<$($params)*> AddRemove for $ty where C: AddRemove {}
+
+        impl<$($params)*> Collection for $ty where C: Collection + AddRemove {
+            type Item = C::Item;
+
+            fn len(&self) -> usize {
+                Collection::len(&**self)
+            }
+
+            fn is_empty(&self) -> bool {
+                Collection::is_empty(&**self)
+            }
+
+            fn capacity(&self) -> usize {
+                Collection::capacity(&**self)
+            }
+
+            fn extend_object(&mut self, items: &mut Iterator<Item = C::Item>) {
+                Collection::extend_object(&mut **self, items);
+            }
+
+            fn clear(&mut self) {
+                Collection::clear(&mut **self);
+            }
+
+            fn retain(&mut self, f: &mut FnMut(&C::Item) -> bool) {
+                Collection::retain(&mut **self, f);
+            }
+
+            fn drain<'b>(&'b mut self) -> Box<Iterator<Item = C::Item> + 'b> {
+                Collection::drain(&mut **self)
+            }
+
+            fn extract_if<'b>(&'b mut self, pred: &'b mut FnMut(&C::Item) -> bool)
+                -> Box<Iterator<Item = C::Item> + 'b>
+            {
+                Collection::extract_if(&mut **self, pred)
+            }
+
+            fn reserve(&mut self, additional: usize) {
+                Collection::reserve(&mut **self, additional);
+            }
+
+            fn shrink_to_fit(&mut self) {
+                Collection::shrink_to_fit(&mut **self);
+            }
+
+            $($collection)*
+        }
+
+        impl<$($params)*> Iter for $ty where C: Iter + AddRemove {
+            fn iter<'b>(&'b self) -> Box<Iterator<Item = &'b C::Item> + 'b> {
+                Iter::iter(&**self)
+            }
+
+            fn iter_mut<'b>(&'b mut self) -> Box<Iterator<Item = &'b mut C::Item> + 'b>
+                where Self: Mutate
+            {
+                unreachable!("pointers to collections do not implement `Mutate`")
+            }
+        }
+
+        impl<$($params)*> DrainRange<Range<usize>> for $ty
+            where C: DrainRange<Range<usize>> + AddRemove
+        {
+            fn drain_range<'b>(&'b mut self, range: Range<usize>)
+                -> Box<Iterator<Item = C::Item> + 'b>
+            {
+                DrainRange::drain_range(&mut **self, range)
+            }
+        }
+
+        impl<$($params)*> List for $ty where C: List + AddRemove {
+            fn get(&self, index: usize) -> Option<&C::Item> {
+                List::get(&**self, index)
+            }
+
+            fn get_mut(&mut self, _index: usize) -> Option<&mut C::Item> where Self: Mutate {
+                unreachable!("pointers to collections do not implement `Mutate`")
+            }
+
+            fn swap(&mut self, _i: usize, _j: usize) where Self: Mutate {
+                unreachable!("pointers to collections do not implement `Mutate`")
+            }
+
+            fn first(&self) -> Option<&C::Item> {
+                List::first(&**self)
+            }
+
+            fn last(&self) -> Option<&C::Item> {
+                List::last(&**self)
+            }
+
+            fn push(&mut self, item: C::Item) {
+                List::push(&mut **self, item);
+            }
+
+            fn insert(&mut self, index: usize, item: C::Item) {
+                List::insert(&mut **self, index, item);
+            }
+
+            fn insert_many(&mut self, index: usize, items: &mut Iterator<Item = C::Item>) {
+                List::insert_many(&mut **self, index, items);
+            }
+
+            fn splice<'b>(&'b mut self, range: Range<usize>,
+                          items: &'b mut Iterator<Item = C::Item>)
+                -> Box<Iterator<Item = C::Item> + 'b>
+            {
+                List::splice(&mut **self, range, items)
+            }
+
+            fn pop(&mut self) -> Option<C::Item> {
+                List::pop(&mut **self)
+            }
+
+            fn remove(&mut self, index: usize) -> Option<C::Item> {
+                List::remove(&mut **self, index)
+            }
+
+            fn swap_remove(&mut self, index: usize) -> Option<C::Item> {
+                List::swap_remove(&mut **self, index)
+            }
+
+            fn truncate(&mut self, len: usize) {
+                List::truncate(&mut **self, len);
+            }
+
+            fn resize(&mut self, len: usize, item: C::Item) where C::Item: Clone {
+                List::resize(&mut **self, len, item);
+            }
+
+            fn resize_with(&mut self, len: usize, f: &mut FnMut() -> C::Item) {
+                List::resize_with(&mut **self, len, f);
+            }
+
+            fn dedup(&mut self) where C::Item: PartialEq {
+                List::dedup(&mut **self);
+            }
+
+            fn dedup_by(&mut self, same_bucket: &mut FnMut(&mut C::Item, &mut C::Item) -> bool) {
+                List::dedup_by(&mut **self, same_bucket);
+            }
+
+            fn contiguous(&self) -> Option<&[C::Item]> {
+                List::contiguous(&**self)
+            }
+
+            fn cursor_front<'b>(&'b self) -> Box<list::Cursor<Item = C::Item> + 'b> {
+                List::cursor_front(&**self)
+            }
+
+            fn cursor_back<'b>(&'b self) -> Box<list::Cursor<Item = C::Item> + 'b> {
+                List::cursor_back(&**self)
+            }
+
+            $($list)*
+        }
+
+        impl<$($params)*> Segmented for $ty where C: Segmented + AddRemove {
+            fn as_slices(&self) -> (&[C::Item], &[C::Item]) {
+                Segmented::as_slices(&**self)
+            }
+
+            fn as_mut_slices(&mut self) -> (&mut [C::Item], &mut [C::Item]) where Self: Mutate {
+                unreachable!("pointers to collections do not implement `Mutate`")
+            }
+
+            fn make_contiguous(&mut self) -> &mut [C::Item] where Self: Mutate {
+                unreachable!("pointers to collections do not implement `Mutate`")
+            }
+        }
+
+        impl<$($params)*> Contiguous for $ty where C: Contiguous + AddRemove {
+            fn as_slice(&self) -> &[C::Item] {
+                Contiguous::as_slice(&**self)
+            }
+
+            fn as_mut_slice(&mut self) -> &mut [C::Item] where Self: Mutate {
+                unreachable!("pointers to collections do not implement `Mutate`")
+            }
+        }
+
+        impl<$($params)*> map::Base for $ty where C: map::Base + AddRemove {
+            type Key = C::Key;
+            type Value = C::Value;
+
+            fn iter<'b>(&'b self) -> Box<Iterator<Item = (&'b C::Key, &'b C::Value)> + 'b> {
+                map::Base::iter(&**self)
+            }
+
+            fn iter_mut<'b>(&'b mut self)
+                -> Box<Iterator<Item = (&'b C::Key, &'b mut C::Value)> + 'b> where Self: Mutate
+            {
+                unreachable!("pointers to collections do not implement `Mutate`")
+            }
+
+            fn insert(&mut self, key: C::Key, value: C::Value) -> Option<C::Value> {
+                map::Base::insert(&mut **self, key, value)
+            }
+
+            fn entry<'b>(&'b mut self, key: C::Key) -> map::Entry<'b, C::Key, C::Value> {
+                map::Base::entry(&mut **self, key)
+            }
+
+            fn replace(&mut self, key: C::Key, value: C::Value) -> Option<(C::Key, C::Value)>
+                where C::Key: Eq
+            {
+                map::Base::replace(&mut **self, key, value)
+            }
+
+            fn retain_mut(&mut self, f: &mut FnMut(&C::Key, &mut C::Value) -> bool) {
+                map::Base::retain_mut(&mut **self, f);
+            }
+
+            fn extract_if_mut<'b>(&'b mut self, f: &'b mut FnMut(&C::Key, &mut C::Value) -> bool)
+                -> Box<Iterator<Item = (C::Key, C::Value)> + 'b>
+            {
+                map::Base::extract_if_mut(&mut **self, f)
+            }
+        }
+
+        impl<$($params)*, Q: ?Sized> Map<Q> for $ty where C: Map<Q> + AddRemove {
+            fn contains_key(&self, key: &Q) -> bool {
+                Map::contains_key(&**self, key)
+            }
+
+            fn get(&self, key: &Q) -> Option<&C::Value> {
+                Map::get(&**self, key)
+            }
+
+            fn get_key_value(&self, key: &Q) -> Option<(&C::Key, &C::Value)> {
+                Map::get_key_value(&**self, key)
+            }
+
+            fn get_mut(&mut self, _key: &Q) -> Option<&mut C::Value> where Self: Mutate {
+                unreachable!("pointers to collections do not implement `Mutate`")
+            }
+
+            fn remove(&mut self, key: &Q) -> Option<C::Value> {
+                Map::remove(&mut **self, key)
+            }
+
+            fn remove_entry(&mut self, key: &Q) -> Option<(C::Key, C::Value)> {
+                Map::remove_entry(&mut **self, key)
+            }
+        }
+
+        impl<$($params)*> map::Ordered for $ty where C: map::Ordered + AddRemove {
+            fn cursor_front<'b>(&'b self)
+                -> Box<map::Cursor<Key = C::Key, Value = C::Value> + 'b>
+            {
+                map::Ordered::cursor_front(&**self)
+            }
+
+            fn lower_bound<'b>(&'b self, key: &C::Key)
+                -> Box<map::Cursor<Key = C::Key, Value = C::Value> + 'b>
+            {
+                map::Ordered::lower_bound(&**self, key)
+            }
+
+            fn cursor_front_mut<'b>(&'b mut self)
+                -> Box<map::CursorMut<Key = C::Key, Value = C::Value> + 'b>
+                where Self: Mutate + AddRemove, C::Key: Clone
+            {
+                unreachable!("pointers to collections do not implement `Mutate`")
+            }
+
+            fn lower_bound_mut<'b>(&'b mut self, _key: &C::Key)
+                -> Box<map::CursorMut<Key = C::Key, Value = C::Value> + 'b>
+                where Self: Mutate + AddRemove, C::Key: Clone
+            {
+                unreachable!("pointers to collections do not implement `Mutate`")
+            }
+        }
+
+        impl<$($params)*, Q: ?Sized> map::PrefixMap<Q> for $ty
+            where C: map::PrefixMap<Q> + AddRemove
+        {
+            fn iter_prefix<'b>(&'b self, prefix: &Q)
+                -> Box<Iterator<Item = (&'b C::Key, &'b C::Value)> + 'b>
+            {
+                map::PrefixMap::iter_prefix(&**self, prefix)
+            }
+
+            fn longest_prefix_match(&self, key: &Q) -> Option<(&C::Key, &C::Value)> {
+                map::PrefixMap::longest_prefix_match(&**self, key)
+            }
+
+            fn remove_prefix<'b>(&'b mut self, prefix: &Q)
+                -> Box<Iterator<Item = (C::Key, C::Value)> + 'b>
+            {
+                map::PrefixMap::remove_prefix(&mut **self, prefix)
+            }
+        }
+
+        impl<$($params)*> set::Base for $ty where C: Sized + set::Base + AddRemove {
+            fn is_disjoint(&self, other: &Self) -> bool {
+                set::Base::is_disjoint(&**self, &**other)
+            }
+
+            fn is_subset(&self, other: &Self) -> bool {
+                set::Base::is_subset(&**self, &**other)
+            }
+
+            fn is_superset(&self, other: &Self) -> bool {
+                set::Base::is_superset(&**self, &**other)
+            }
+
+            fn insert(&mut self, item: C::Item) -> bool {
+                set::Base::insert(&mut **self, item)
+            }
+
+            fn replace(&mut self, item: C::Item) -> Option<C::Item> {
+                set::Base::replace(&mut **self, item)
+            }
+
+            fn entry<'b>(&'b mut self, item: C::Item) -> set::Entry<'b, C::Item>
+                where C::Item: Eq
+            {
+                set::Base::entry(&mut **self, item)
+            }
+        }
+
+        impl<$($params)*, Q: ?Sized> Set<Q> for $ty where C: Sized + Set<Q> + AddRemove {
+            fn contains(&self, item: &Q) -> bool {
+                Set::contains(&**self, item)
+            }
+
+            fn get(&self, item: &Q) -> Option<&C::Item> {
+                Set::get(&**self, item)
+            }
+
+            fn remove(&mut self, item: &Q) -> bool {
+                Set::remove(&mut **self, item)
+            }
+
+            fn take(&mut self, item: &Q) -> Option<C::Item> {
+                Set::take(&mut **self, item)
+            }
+        }
+
+        impl<$($params)*> Queue for $ty where C: Queue + AddRemove {
+            fn push(&mut self, item: C::Item) {
+                Queue::push(&mut **self, item);
+            }
+
+            fn front(&self) -> Option<&C::Item> {
+                Queue::front(&**self)
+            }
+
+            fn pop_front(&mut self) -> Option<C::Item> {
+                Queue::pop_front(&mut **self)
+            }
+        }
+
+        impl<$($params)*> FifoQueue for $ty where C: FifoQueue + AddRemove {
+            fn front_mut(&mut self) -> Option<&mut C::Item> where Self: Mutate {
+                unreachable!("pointers to collections do not implement `Mutate`")
+            }
+        }
+
+        impl<$($params)*> PrioQueue for $ty where C: PrioQueue + AddRemove {
+            fn push_pop_front(&mut self, item: C::Item) -> C::Item {
+                PrioQueue::push_pop_front(&mut **self, item)
+            }
+
+            fn replace_front(&mut self, item: C::Item) -> Option<C::Item> {
+                PrioQueue::replace_front(&mut **self, item)
+            }
+        }
+
+        impl<$($params)*> Deque for $ty where C: Deque + AddRemove {
+            fn back(&self) -> Option<&C::Item> {
+                Deque::back(&**self)
+            }
+
+            fn pop_back(&mut self) -> Option<C::Item> {
+                Deque::pop_back(&mut **self)
+            }
+        }
+
+        impl<$($params)*> FifoDeque for $ty where C: FifoDeque + AddRemove {
+            fn push_front(&mut self, item: C::Item) {
+                FifoDeque::push_front(&mut **self, item);
+            }
+
+            fn back_mut(&mut self) -> Option<&mut C::Item> where Self: Mutate {
+                unreachable!("pointers to collections do not implement `Mutate`")
+            }
+        }
+
+        impl<$($params)*> RandomAccessDeque for $ty where C: RandomAccessDeque + AddRemove {
+            fn range<'b>(&'b self, range: Range<usize>) -> Box<Iterator<Item = &'b C::Item> + 'b> {
+                RandomAccessDeque::range(&**self, range)
+            }
+
+            fn range_mut<'b>(&'b mut self, _range: Range<usize>)
+                -> Box<Iterator<Item = &'b mut C::Item> + 'b> where Self: Mutate
+            {
+                unreachable!("pointers to collections do not implement `Mutate`")
+            }
+        }
+
+        impl<$($params)*> PrioDeque for $ty where C: PrioDeque + AddRemove {
+            fn push_pop_back(&mut self, item: C::Item) -> C::Item {
+                PrioDeque::push_pop_back(&mut **self, item)
+            }
+
+            fn replace_back(&mut self, item: C::Item) -> Option<C::Item> {
+                PrioDeque::replace_back(&mut **self, item)
+            }
+        }
+
+        impl<$($params)*> RangeMapping for $ty where C: RangeMapping + AddRemove {
+            type Key = C::Key;
+            type Value = C::Value;
+
+            fn insert_range(&mut self, range: Range<C::Key>, value: C::Value) {
+                RangeMapping::insert_range(&mut **self, range, value);
+            }
+
+            fn remove_range(&mut self, range: Range<C::Key>) {
+                RangeMapping::remove_range(&mut **self, range);
+            }
+
+            fn get_containing(&self, key: &C::Key) -> Option<(&Range<C::Key>, &C::Value)> {
+                RangeMapping::get_containing(&**self, key)
+            }
+
+            fn overlapping<'b>(&'b self, range: Range<C::Key>)
+                -> Box<Iterator<Item = (&'b Range<C::Key>, &'b C::Value)> + 'b>
+            {
+                RangeMapping::overlapping(&**self, range)
+            }
+
+            fn gaps<'b>(&'b self, range: Range<C::Key>)
+                -> Box<Iterator<Item = Range<C::Key>> + 'b>
+            {
+                RangeMapping::gaps(&**self, range)
+            }
+        }
+    )*}
+}
+
+macro_rules! forward_read_only {
+    ($([$($params:tt)*] $ty:ty),*) => {$(
+        impl<$($params)*> Collection for $ty where C: Collection {
+            type Item = C::Item;
+
+            fn len(&self) -> usize {
+                Collection::len(&**self)
+            }
+
+            fn is_empty(&self) -> bool {
+                Collection::is_empty(&**self)
+            }
+
+            fn capacity(&self) -> usize {
+                Collection::capacity(&**self)
+            }
+
+            fn extend_object(&mut self, _items: &mut Iterator<Item = C::Item>)
+                where Self: AddRemove
+            {
+                unimplemented!()
+            }
+
+            fn drain<'b>(&'b mut self) -> Box<Iterator<Item = C::Item> + 'b>
+                where Self: AddRemove
+            {
+                unimplemented!()
+            }
+
+            fn reserve(&mut self, _additional: usize) where Self: AddRemove {
+                unimplemented!()
+            }
+
+            fn shrink_to_fit(&mut self) where Self: AddRemove {
+                unimplemented!()
+            }
+        }
+
+        impl<$($params)*> Iter for $ty where C: Iter {
+            fn iter<'b>(&'b self) -> Box<Iterator<Item = &'b C::Item> + 'b> {
+                Iter::iter(&**self)
+            }
+
+            fn iter_mut<'b>(&'b mut self) -> Box<Iterator<Item = &'b mut C::Item> + 'b>
+                where Self: Mutate
+            {
+                unimplemented!()
+            }
+        }
+
+        impl<$($params)*> DrainRange<Range<usize>> for $ty where C: DrainRange<Range<usize>> {
+            fn drain_range<'b>(&'b mut self, _range: Range<usize>)
+                -> Box<Iterator<Item = C::Item> + 'b> where Self: AddRemove
+            {
+                unimplemented!()
+            }
+        }
+
+        impl<$($params)*> List for $ty where C: List {
+            fn get(&self, index: usize) -> Option<&C::Item> {
+                List::get(&**self, index)
+            }
+
+            fn get_mut(&mut self, _index: usize) -> Option<&mut C::Item> where Self: Mutate {
+                unimplemented!()
+            }
+
+            fn swap(&mut self, _i: usize, _j: usize) where Self: Mutate {
+                unimplemented!()
+            }
+
+            fn first(&self) -> Option<&C::Item> {
+                List::first(&**self)
+            }
+
+            fn last(&self) -> Option<&C::Item> {
+                List::last(&**self)
+            }
+
+            fn insert(&mut self, _index: usize, _item: C::Item) where Self: AddRemove {
+                unimplemented!()
+            }
+
+            fn remove(&mut self, _index: usize) -> Option<C::Item> where Self: AddRemove {
+                unimplemented!()
+            }
+
+            fn swap_remove(&mut self, _index: usize) -> Option<C::Item> where Self: AddRemove {
+                unimplemented!()
+            }
+
+            fn contiguous(&self) -> Option<&[C::Item]> {
+                List::contiguous(&**self)
+            }
+
+            fn cursor_front<'b>(&'b self) -> Box<list::Cursor<Item = C::Item> + 'b> {
+                List::cursor_front(&**self)
+            }
+
+            fn cursor_back<'b>(&'b self) -> Box<list::Cursor<Item = C::Item> + 'b> {
+                List::cursor_back(&**self)
+            }
+        }
+
+        impl<$($params)*> Segmented for $ty where C: Segmented {
+            fn as_slices(&self) -> (&[C::Item], &[C::Item]) {
+                Segmented::as_slices(&**self)
+            }
+
+            fn as_mut_slices(&mut self) -> (&mut [C::Item], &mut [C::Item]) where Self: Mutate {
+                unimplemented!()
+            }
+
+            fn make_contiguous(&mut self) -> &mut [C::Item] where Self: Mutate {
+                unimplemented!()
+            }
+        }
+
+        impl<$($params)*> Contiguous for $ty where C: Contiguous {
+            fn as_slice(&self) -> &[C::Item] {
+                Contiguous::as_slice(&**self)
+            }
+
+            fn as_mut_slice(&mut self) -> &mut [C::Item] where Self: Mutate {
+                unimplemented!()
+            }
+        }
+
+        impl<$($params)*> Text for $ty where C: Text {
+            fn as_str(&self) -> &str {
+                Text::as_str(&**self)
+            }
+
+            fn insert_str(&mut self, _index: usize, _s: &str) -> Result<(), CharBoundaryError>
+                where Self: AddRemoveChars
+            {
+                unimplemented!()
+            }
+
+            fn remove_char(&mut self, _index: usize) -> Result<char, CharBoundaryError>
+                where Self: AddRemoveChars
+            {
+                unimplemented!()
+            }
+
+            fn drain_chars<'b>(&'b mut self, _range: Range<usize>)
+                -> Result<Box<Iterator<Item = char> + 'b>, CharBoundaryError>
+                where Self: AddRemoveChars
+            {
+                unimplemented!()
+            }
+        }
+
+        impl<$($params)*> map::Base for $ty where C: map::Base {
+            type Key = C::Key;
+            type Value = C::Value;
+
+            fn iter<'b>(&'b self) -> Box<Iterator<Item = (&'b C::Key, &'b C::Value)> + 'b> {
+                map::Base::iter(&**self)
+            }
+
+            fn iter_mut<'b>(&'b mut self)
+                -> Box<Iterator<Item = (&'b C::Key, &'b mut C::Value)> + 'b> where Self: Mutate
+            {
+                unimplemented!()
+            }
+
+            fn insert(&mut self, _key: C::Key, _value: C::Value) -> Option<C::Value>
+                where Self: AddRemove
+            {
+                unimplemented!()
+            }
+
+            fn entry<'b>(&'b mut self, _key: C::Key) -> map::Entry<'b, C::Key, C::Value>
+                where Self: AddRemove
+            {
+                unimplemented!()
+            }
+        }
+
+        impl<$($params)*, Q: ?Sized> Map<Q> for $ty where C: Map<Q> {
+            fn contains_key(&self, key: &Q) -> bool {
+                Map::contains_key(&**self, key)
+            }
+
+            fn get(&self, key: &Q) -> Option<&C::Value> {
+                Map::get(&**self, key)
+            }
+
+            fn get_key_value(&self, key: &Q) -> Option<(&C::Key, &C::Value)> {
+                Map::get_key_value(&**self, key)
+            }
+
+            fn get_mut(&mut self, _key: &Q) -> Option<&mut C::Value> where Self: Mutate {
+                unimplemented!()
+            }
+
+            fn remove(&mut self, _key: &Q) -> Option<C::Value> where Self: AddRemove {
+                unimplemented!()
+            }
+
+            fn remove_entry(&mut self, _key: &Q) -> Option<(C::Key, C::Value)>
+                where Self: AddRemove
+            {
+                unimplemented!()
+            }
+        }
+
+        impl<$($params)*> map::Ordered for $ty where C: map::Ordered {
+            fn cursor_front<'b>(&'b self)
+                -> Box<map::Cursor<Key = C::Key, Value = C::Value> + 'b>
+            {
+                map::Ordered::cursor_front(&**self)
+            }
+
+            fn lower_bound<'b>(&'b self, key: &C::Key)
+                -> Box<map::Cursor<Key = C::Key, Value = C::Value> + 'b>
+            {
+                map::Ordered::lower_bound(&**self, key)
+            }
+
+            fn cursor_front_mut<'b>(&'b mut self)
+                -> Box<map::CursorMut<Key = C::Key, Value = C::Value> + 'b>
+                where Self: Mutate + AddRemove, C::Key: Clone
+            {
+                unimplemented!()
+            }
+
+            fn lower_bound_mut<'b>(&'b mut self, _key: &C::Key)
+                -> Box<map::CursorMut<Key = C::Key, Value = C::Value> + 'b>
+                where Self: Mutate + AddRemove, C::Key: Clone
+            {
+                unimplemented!()
+            }
+        }
+
+        impl<$($params)*, Q: ?Sized> map::PrefixMap<Q> for $ty where C: map::PrefixMap<Q> {
+            fn iter_prefix<'b>(&'b self, prefix: &Q)
+                -> Box<Iterator<Item = (&'b C::Key, &'b C::Value)> + 'b>
+            {
+                map::PrefixMap::iter_prefix(&**self, prefix)
+            }
+
+            fn longest_prefix_match(&self, key: &Q) -> Option<(&C::Key, &C::Value)> {
+                map::PrefixMap::longest_prefix_match(&**self, key)
+            }
+
+            fn remove_prefix<'b>(&'b mut self, _prefix: &Q)
+                -> Box<Iterator<Item = (C::Key, C::Value)> + 'b> where Self: AddRemove
+            {
+                unimplemented!()
+            }
+        }
+
+        impl<$($params)*> set::Base for $ty where C: Sized + set::Base {
+            fn is_disjoint(&self, other: &Self) -> bool {
+                set::Base::is_disjoint(&**self, &**other)
+            }
+
+            fn is_subset(&self, other: &Self) -> bool {
+                set::Base::is_subset(&**self, &**other)
+            }
+
+            fn is_superset(&self, other: &Self) -> bool {
+                set::Base::is_superset(&**self, &**other)
+            }
+
+            fn insert(&mut self, _item: C::Item) -> bool where Self: AddRemove {
+                unimplemented!()
+            }
+
+            fn replace(&mut self, _item: C::Item) -> Option<C::Item> where Self: AddRemove {
+                unimplemented!()
+            }
+        }
+
+        impl<$($params)*, Q: ?Sized> Set<Q> for $ty where C: Sized + Set<Q> {
+            fn contains(&self, item: &Q) -> bool {
+                Set::contains(&**self, item)
+            }
+
+            fn get(&self, item: &Q) -> Option<&C::Item> {
+                Set::get(&**self, item)
+            }
+
+            fn take(&mut self, _item: &Q) -> Option<C::Item> where Self: AddRemove {
+                unimplemented!()
+            }
+        }
+
+        impl<$($params)*> Queue for $ty where C: Queue {
+            fn push(&mut self, _item: C::Item) where Self: AddRemove {
+                unimplemented!()
+            }
+
+            fn front(&self) -> Option<&C::Item> {
+                Queue::front(&**self)
+            }
+
+            fn pop_front(&mut self) -> Option<C::Item> where Self: AddRemove {
+                unimplemented!()
+            }
+        }
+
+        impl<$($params)*> FifoQueue for $ty where C: FifoQueue {
+            fn front_mut(&mut self) -> Option<&mut C::Item> where Self: Mutate {
+                unimplemented!()
+            }
+        }
+
+        impl<$($params)*> PrioQueue for $ty where C: PrioQueue {}
+
+        impl<$($params)*> Deque for $ty where C: Deque {
+            fn back(&self) -> Option<&C::Item> {
+                Deque::back(&**self)
+            }
+
+            fn pop_back(&mut self) -> Option<C::Item> where Self: AddRemove {
+                unimplemented!()
+            }
+        }
+
+        impl<$($params)*> FifoDeque for $ty where C: FifoDeque {
+            fn push_front(&mut self, _item: C::Item) where Self: AddRemove {
+                unimplemented!()
+            }
+
+            fn back_mut(&mut self) -> Option<&mut C::Item> where Self: Mutate {
+                unimplemented!()
+            }
+        }
+
+        impl<$($params)*> RandomAccessDeque for $ty where C: RandomAccessDeque {
+            fn range<'b>(&'b self, range: Range<usize>) -> Box<Iterator<Item = &'b C::Item> + 'b> {
+                RandomAccessDeque::range(&**self, range)
+            }
+
+            fn range_mut<'b>(&'b mut self, _range: Range<usize>)
+                -> Box<Iterator<Item = &'b mut C::Item> + 'b> where Self: Mutate
+            {
+                unimplemented!()
+            }
+        }
+
+        impl<$($params)*> PrioDeque for $ty where C: PrioDeque {}
+
+        impl<$($params)*> RangeMapping for $ty where C: RangeMapping {
+            type Key = C::Key;
+            type Value = C::Value;
+
+            fn insert_range(&mut self, _range: Range<C::Key>, _value: C::Value)
+                where Self: AddRemove
+            {
+                unimplemented!()
+            }
+
+            fn remove_range(&mut self, _range: Range<C::Key>) where Self: AddRemove {
+                unimplemented!()
+            }
+
+            fn get_containing(&self, key: &C::Key) -> Option<(&Range<C::Key>, &C::Value)> {
+                RangeMapping::get_containing(&**self, key)
+            }
+
+            fn overlapping<'b>(&'b self, range: Range<C::Key>)
+                -> Box<Iterator<Item = (&'b Range<C::Key>, &'b C::Value)> + 'b>
+            {
+                RangeMapping::overlapping(&**self, range)
+            }
+
+            fn gaps<'b>(&'b self, range: Range<C::Key>)
+                -> Box<Iterator<Item = Range<C::Key>> + 'b>
+            {
+                RangeMapping::gaps(&**self, range)
+            }
+        }
+    )*}
+}
+
+forward_add_remove! {
+    [C] Box<C> {
+        fn with_capacity(capacity: usize) -> Self where Self: Default {
+            let mut collection = Self::default();
+            Collection::reserve(&mut *collection, capacity);
+            collection
+        }
+
+        fn into_vec(self) -> Vec<C::Item> {
+            Collection::into_vec(*self)
+        }
+    } {
+        fn split_off(&mut self, index: usize) -> Self {
+            Box::new(List::split_off(&mut **self, index))
+        }
+    },
+    ['a, C: ?Sized] &'a mut C {
+        // this empties the referenced collection (see the module documentation)
+        fn into_vec(self) -> Vec<C::Item> {
+            Collection::drain(self).collect()
+        }
+    } {}
+}
+
+forward_read_only!(['a, C: ?Sized] &'a C, [C: ?Sized] Rc<C>, [C: ?Sized] Arc<C>);
+
+#[test]
+fn test_forward() {
+    use std::collections::{BinaryHeap, HashSet, VecDeque};
+    use super::collections::SortedList;
+
+    fn push_all<L: List<Item = i32> + AddRemove>(mut list: L, items: &[i32]) -> usize {
+        for &item in items {
+            list.push(item);
+        }
+
+        list.len()
+    }
+
+    let mut list = vec![1];
+    assert_eq!(push_all(&mut list, &[2, 3]), 3);
+
+    let boxed: Box<VecDeque<_>> = Box::new(list.iter().cloned().collect());
+    assert_eq!(push_all(boxed, &[4]), 4);
+
+    let mut set = SortedList::from_list(&mut list);
+    assert!(!set::Base::insert(&mut set, 2));
+    assert!(set::Base::insert(&mut set, 0));
+    assert_eq!(list, [0, 1, 2, 3]);
+
+    let mut heap = Box::new(BinaryHeap::new());
+    Queue::push(&mut heap, 2);
+    assert_eq!(PrioQueue::push_pop_front(&mut heap, 1), 2);
+
+    let set: Rc<HashSet<_>> = Rc::new(list.iter().cloned().collect());
+    assert!(Set::contains(&set, &3));
+    assert_eq!(List::get(&Arc::new(list), 1), Some(&1));
+
+    fn swap_ends<L: List<Item = i32> + Mutate>(mut list: L) {
+        let len = list.len();
+        list.swap(0, len - 1);
+    }
+
+    let mut boxed = Box::new(vec![3, 1, 2]);
+    assert_eq!(Collection::into_vec(List::split_off(&mut boxed, 2)), [2]);
+    swap_ends(&mut boxed[..]);
+    assert_eq!(*boxed, [1, 3]);
+
+    let boxed: Box<Vec<i32>> = Collection::with_capacity(4);
+    assert!(boxed.is_empty() && boxed.capacity() >= 4);
+
+    let mut list = vec![1, 2];
+    assert_eq!(Collection::into_vec(&mut list), [1, 2]);
+    assert!(list.is_empty());
+}
@@ -2,7 +2,8 @@ use std::borrow::Borrow;
 use std::collections::*;
 use std::hash::Hash;
 use std::mem;
-use std::ops::Range;
+use std::ops::{Bound, Range};
+use std::str;
 use super::*;
 
 impl<T> Mutate for [T] {}
@@ -70,24 +71,1098 @@ impl<T> List for [T] {
         self.reverse();
     }
 
+    fn rotate_left(&mut self, mid: usize) {
+        self.rotate_left(mid);
+    }
+
+    fn rotate_right(&mut self, k: usize) {
+        self.rotate_right(k);
+    }
+
+    fn contiguous(&self) -> Option<&[T]> {
+        Some(self)
+    }
+
+    fn contiguous_mut(&mut self) -> Option<&mut [T]> {
+        Some(self)
+    }
+
+    fn insert(&mut self, _index: usize, _item: T) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn remove(&mut self, _index: usize) -> Option<T> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn swap_remove(&mut self, _index: usize) -> Option<T> where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+impl<T> Segmented for [T] {
+    fn as_slices(&self) -> (&[T], &[T]) {
+        (self, &[])
+    }
+
+    fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        (self, &mut [])
+    }
+
+    fn make_contiguous(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T> Contiguous for [T] {
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T> Mutate for Box<[T]> {}
+
+impl<T> Collection for Box<[T]> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn capacity(&self) -> usize {
+        (**self).len()
+    }
+
+    fn extend_object(&mut self, _items: &mut Iterator<Item = T>) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = T> + 'a> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn reserve(&mut self, _additional: usize) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn shrink_to_fit(&mut self) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn into_vec(self) -> Vec<T> {
+        <[T]>::into_vec(self)
+    }
+}
+
+impl<T> Iter for Box<[T]> {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a T> + 'a> {
+        Box::new((**self).iter())
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut T> + 'a> {
+        Box::new((**self).iter_mut())
+    }
+}
+
+impl<T> DrainRange<Range<usize>> for Box<[T]> {
+    fn drain_range<'a>(&'a mut self, _range: Range<usize>)
+        -> Box<Iterator<Item = T> + 'a> where Self: AddRemove
+    {
+        unimplemented!()
+    }
+}
+
+impl<T> List for Box<[T]> {
+    fn get(&self, index: usize) -> Option<&T> {
+        (**self).get(index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        (**self).get_mut(index)
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        (**self).swap(i, j);
+    }
+
+    fn reverse(&mut self) {
+        (**self).reverse();
+    }
+
+    fn rotate_left(&mut self, mid: usize) {
+        (**self).rotate_left(mid);
+    }
+
+    fn rotate_right(&mut self, k: usize) {
+        (**self).rotate_right(k);
+    }
+
+    fn contiguous(&self) -> Option<&[T]> {
+        Some(self)
+    }
+
+    fn contiguous_mut(&mut self) -> Option<&mut [T]> {
+        Some(self)
+    }
+
+    fn insert(&mut self, _index: usize, _item: T) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn remove(&mut self, _index: usize) -> Option<T> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn swap_remove(&mut self, _index: usize) -> Option<T> where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+impl<T> Segmented for Box<[T]> {
+    fn as_slices(&self) -> (&[T], &[T]) {
+        (self, &[])
+    }
+
+    fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        (self, &mut [])
+    }
+
+    fn make_contiguous(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T> Contiguous for Box<[T]> {
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<'a, T> Mutate for &'a mut [T] {}
+
+impl<'a, T> Collection for &'a mut [T] {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn capacity(&self) -> usize {
+        (**self).len()
+    }
+
+    fn extend_object(&mut self, _items: &mut Iterator<Item = T>) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn drain<'b>(&'b mut self) -> Box<Iterator<Item = T> + 'b> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn reserve(&mut self, _additional: usize) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn shrink_to_fit(&mut self) where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+impl<'a, T> Iter for &'a mut [T] {
+    fn iter<'b>(&'b self) -> Box<Iterator<Item = &'b T> + 'b> {
+        Box::new((**self).iter())
+    }
+
+    fn iter_mut<'b>(&'b mut self) -> Box<Iterator<Item = &'b mut T> + 'b> {
+        Box::new((**self).iter_mut())
+    }
+}
+
+impl<'a, T> DrainRange<Range<usize>> for &'a mut [T] {
+    fn drain_range<'b>(&'b mut self, _range: Range<usize>)
+        -> Box<Iterator<Item = T> + 'b> where Self: AddRemove
+    {
+        unimplemented!()
+    }
+}
+
+impl<'a, T> List for &'a mut [T] {
+    fn get(&self, index: usize) -> Option<&T> {
+        (**self).get(index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        (**self).get_mut(index)
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        (**self).swap(i, j);
+    }
+
+    fn reverse(&mut self) {
+        (**self).reverse();
+    }
+
+    fn rotate_left(&mut self, mid: usize) {
+        (**self).rotate_left(mid);
+    }
+
+    fn rotate_right(&mut self, k: usize) {
+        (**self).rotate_right(k);
+    }
+
+    fn contiguous(&self) -> Option<&[T]> {
+        Some(self)
+    }
+
+    fn contiguous_mut(&mut self) -> Option<&mut [T]> {
+        Some(self)
+    }
+
     fn insert(&mut self, _index: usize, _item: T) where Self: AddRemove {
         unimplemented!()
     }
 
-    fn remove(&mut self, _index: usize) -> Option<T> where Self: AddRemove {
-        unimplemented!()
+    fn remove(&mut self, _index: usize) -> Option<T> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn swap_remove(&mut self, _index: usize) -> Option<T> where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+impl<'a, T> Segmented for &'a mut [T] {
+    fn as_slices(&self) -> (&[T], &[T]) {
+        (self, &[])
+    }
+
+    fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        (self, &mut [])
+    }
+
+    fn make_contiguous(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<'a, T> Contiguous for &'a mut [T] {
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T, const N: usize> Mutate for [T; N] {}
+
+impl<T, const N: usize> Collection for [T; N] {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn extend_object(&mut self, _items: &mut Iterator<Item = T>) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = T> + 'a> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn reserve(&mut self, _additional: usize) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn shrink_to_fit(&mut self) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn into_vec(self) -> Vec<T> {
+        Vec::from(self)
+    }
+}
+
+impl<T, const N: usize> Iter for [T; N] {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a T> + 'a> {
+        Box::new(self[..].iter())
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut T> + 'a> {
+        Box::new(self[..].iter_mut())
+    }
+}
+
+impl<T, const N: usize> DrainRange<Range<usize>> for [T; N] {
+    fn drain_range<'a>(&'a mut self, _range: Range<usize>)
+        -> Box<Iterator<Item = T> + 'a> where Self: AddRemove
+    {
+        unimplemented!()
+    }
+}
+
+impl<T, const N: usize> List for [T; N] {
+    fn get(&self, index: usize) -> Option<&T> {
+        self[..].get(index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self[..].get_mut(index)
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self[..].swap(i, j);
+    }
+
+    fn reverse(&mut self) {
+        self[..].reverse();
+    }
+
+    fn rotate_left(&mut self, mid: usize) {
+        self[..].rotate_left(mid);
+    }
+
+    fn rotate_right(&mut self, k: usize) {
+        self[..].rotate_right(k);
+    }
+
+    fn contiguous(&self) -> Option<&[T]> {
+        Some(self)
+    }
+
+    fn contiguous_mut(&mut self) -> Option<&mut [T]> {
+        Some(self)
+    }
+
+    fn insert(&mut self, _index: usize, _item: T) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn remove(&mut self, _index: usize) -> Option<T> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn swap_remove(&mut self, _index: usize) -> Option<T> where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+impl<T, const N: usize> Segmented for [T; N] {
+    fn as_slices(&self) -> (&[T], &[T]) {
+        (self, &[])
+    }
+
+    fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        (self, &mut [])
+    }
+
+    fn make_contiguous(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T, const N: usize> Contiguous for [T; N] {
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl Collection for str {
+    type Item = u8;
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+
+    fn extend_object(&mut self, _items: &mut Iterator<Item = u8>) {
+        unimplemented!()
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = u8> + 'a> {
+        unimplemented!()
+    }
+
+    fn reserve(&mut self, _additional: usize) {
+        unimplemented!()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        unimplemented!()
+    }
+}
+
+impl Iter for str {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a u8> + 'a> {
+        Box::new(self.as_bytes().iter())
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut u8> + 'a> {
+        unimplemented!()
+    }
+}
+
+impl DrainRange<Range<usize>> for str {
+    fn drain_range<'a>(&'a mut self, _range: Range<usize>) -> Box<Iterator<Item = u8> + 'a> {
+        unimplemented!()
+    }
+}
+
+impl List for str {
+    fn get(&self, index: usize) -> Option<&u8> {
+        self.as_bytes().get(index)
+    }
+
+    fn get_mut(&mut self, _index: usize) -> Option<&mut u8> {
+        unimplemented!()
+    }
+
+    fn swap(&mut self, _i: usize, _j: usize) {
+        unimplemented!()
+    }
+
+    fn contiguous(&self) -> Option<&[u8]> {
+        Some(self.as_bytes())
+    }
+
+    fn insert(&mut self, _index: usize, _item: u8) {
+        unimplemented!()
+    }
+
+    fn remove(&mut self, _index: usize) -> Option<u8> {
+        unimplemented!()
+    }
+
+    fn swap_remove(&mut self, _index: usize) -> Option<u8> {
+        unimplemented!()
+    }
+}
+
+impl Text for str {
+    fn as_str(&self) -> &str {
+        self
+    }
+
+    fn insert_str(&mut self, _index: usize, _s: &str) -> Result<(), CharBoundaryError> {
+        unimplemented!()
+    }
+
+    fn remove_char(&mut self, _index: usize) -> Result<char, CharBoundaryError> {
+        unimplemented!()
+    }
+
+    fn drain_chars<'a>(&'a mut self, _range: Range<usize>)
+        -> Result<Box<Iterator<Item = char> + 'a>, CharBoundaryError>
+    {
+        unimplemented!()
+    }
+}
+
+impl<K: Ord, V> Mutate for BTreeMap<K, V> {}
+
+impl<K: Ord, V> AddRemove for BTreeMap<K, V> {}
+
+impl<K: Ord, V> Collection for BTreeMap<K, V> {
+    type Item = (K, V);
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        self.extend(mem::replace(other, Self::new()));
+    }
+
+    fn extend_object(&mut self, items: &mut Iterator<Item = (K, V)>) {
+        self.extend(items);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = (K, V)> + 'a> {
+        Box::new(mem::replace(self, Self::new()).into_iter())
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn shrink_to_fit(&mut self) {}
+
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::new()
+    }
+
+    fn into_vec(self) -> Vec<(K, V)> {
+        self.into_iter().collect()
+    }
+}
+
+impl<K: Ord, V> map::Base for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = (&'a K, &'a V)> + 'a> {
+        Box::new(self.iter())
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = (&'a K, &'a mut V)> + 'a> {
+        Box::new(self.iter_mut())
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.insert(key, value)
+    }
+
+    fn entry<'a>(&'a mut self, key: K) -> map::Entry<'a, K, V> {
+        let map: *mut Self = self;
+
+        // the entry borrows the map through the raw pointer, so that `replace_key` can use the
+        // pointer once the entry has been consumed
+        match unsafe { (*map).entry(key) } {
+            btree_map::Entry::Occupied(e) => {
+                map::Entry::Occupied(Box::new(BTreeMapOccupiedEntry { entry: e, map: map }))
+            }
+            btree_map::Entry::Vacant(e) => map::Entry::Vacant(Box::new(e)),
+        }
+    }
+
+    // the standard library cannot replace a stored key, and its entries drop the key that was used
+    // to look them up, so the equivalent key has to be removed before the given one is inserted
+    // see `BTreeMap`'s `replace`
+    fn replace(&mut self, key: K, value: V) -> Option<(K, V)> {
+        let entry = self.remove_entry(&key);
+        self.insert(key, value);
+        entry
+    }
+
+    fn retain_mut(&mut self, f: &mut FnMut(&K, &mut V) -> bool) {
+        self.retain(f);
+    }
+
+    fn extract_if_mut<'a>(&'a mut self, f: &'a mut FnMut(&K, &mut V) -> bool)
+        -> Box<Iterator<Item = (K, V)> + 'a>
+    {
+        Box::new(self.extract_if(.., f))
+    }
+}
+
+impl<K: Ord + Borrow<Q>, V, Q: ?Sized + Ord> Map<Q> for BTreeMap<K, V> {
+    fn contains_key(&self, key: &Q) -> bool {
+        self.contains_key(key)
+    }
+
+    fn get(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn get_key_value(&self, key: &Q) -> Option<(&K, &V)> {
+        self.get_key_value(key)
+    }
+
+    fn get_mut(&mut self, key: &Q) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+
+    fn remove(&mut self, key: &Q) -> Option<V> {
+        self.remove(key)
+    }
+
+    fn remove_entry(&mut self, key: &Q) -> Option<(K, V)> {
+        self.remove_entry(key)
+    }
+}
+
+
+impl<K: Ord, V> map::Ordered for BTreeMap<K, V> {
+    fn cursor_front<'a>(&'a self) -> Box<map::Cursor<Key = K, Value = V> + 'a> {
+        Box::new(BTreeMapCursor { map: self, current: self.iter().next() })
+    }
+
+    fn lower_bound<'a>(&'a self, key: &K) -> Box<map::Cursor<Key = K, Value = V> + 'a> {
+        Box::new(BTreeMapCursor { map: self, current: self.range(key..).next() })
+    }
+
+    fn cursor_front_mut<'a>(&'a mut self) -> Box<map::CursorMut<Key = K, Value = V> + 'a>
+        where K: Clone
+    {
+        let key = self.keys().next().cloned();
+        Box::new(BTreeMapCursorMut { map: self, key: key })
+    }
+
+    fn lower_bound_mut<'a>(&'a mut self, key: &K) -> Box<map::CursorMut<Key = K, Value = V> + 'a>
+        where K: Clone
+    {
+        let key = self.range(key..).next().map(|(key, _)| key.clone());
+        Box::new(BTreeMapCursorMut { map: self, key: key })
+    }
+}
+
+/// Returns the entry in the map with the next larger key than the given one, or the entry with
+/// the smallest key if no key is given.
+fn btree_map_next<'a, K: Ord, V>(map: &'a BTreeMap<K, V>, key: Option<&K>)
+    -> Option<(&'a K, &'a V)>
+{
+    match key {
+        Some(key) => map.range((Bound::Excluded(key), Bound::Unbounded)).next(),
+        None => map.iter().next(),
+    }
+}
+
+/// Returns the entry in the map with the next smaller key than the given one, or the entry with
+/// the largest key if no key is given.
+fn btree_map_prev<'a, K: Ord, V>(map: &'a BTreeMap<K, V>, key: Option<&K>)
+    -> Option<(&'a K, &'a V)>
+{
+    match key {
+        Some(key) => map.range(..key).next_back(),
+        None => map.iter().next_back(),
+    }
+}
+
+/// A cursor over a `BTreeMap`.
+struct BTreeMapCursor<'a, K: 'a, V: 'a> {
+    map: &'a BTreeMap<K, V>,
+    current: Option<(&'a K, &'a V)>,
+}
+
+impl<'a, K: Ord, V> map::Cursor for BTreeMapCursor<'a, K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn move_next(&mut self) {
+        self.current = btree_map_next(self.map, self.current.map(|(key, _)| key));
+    }
+
+    fn move_prev(&mut self) {
+        self.current = btree_map_prev(self.map, self.current.map(|(key, _)| key));
+    }
+
+    fn current(&self) -> Option<(&K, &V)> {
+        self.current
+    }
+
+    fn peek_next(&self) -> Option<(&K, &V)> {
+        btree_map_next(self.map, self.current.map(|(key, _)| key))
+    }
+
+    fn peek_prev(&self) -> Option<(&K, &V)> {
+        btree_map_prev(self.map, self.current.map(|(key, _)| key))
+    }
+}
+
+/// A cursor over a `BTreeMap` that supports editing the map.
+///
+/// The cursor holds a copy of the current key, because it cannot hold a reference into the map.
+struct BTreeMapCursorMut<'a, K: 'a, V: 'a> {
+    map: &'a mut BTreeMap<K, V>,
+    key: Option<K>,
+}
+
+impl<'a, K: Ord + Clone, V> map::Cursor for BTreeMapCursorMut<'a, K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn move_next(&mut self) {
+        self.key = btree_map_next(self.map, self.key.as_ref()).map(|(key, _)| key.clone());
+    }
+
+    fn move_prev(&mut self) {
+        self.key = btree_map_prev(self.map, self.key.as_ref()).map(|(key, _)| key.clone());
+    }
+
+    fn current(&self) -> Option<(&K, &V)> {
+        self.key.as_ref().and_then(|key| self.map.get_key_value(key))
+    }
+
+    fn peek_next(&self) -> Option<(&K, &V)> {
+        btree_map_next(self.map, self.key.as_ref())
+    }
+
+    fn peek_prev(&self) -> Option<(&K, &V)> {
+        btree_map_prev(self.map, self.key.as_ref())
+    }
+}
+
+impl<'a, K: Ord + Clone, V> map::CursorMut for BTreeMapCursorMut<'a, K, V> {
+    fn current_mut(&mut self) -> Option<(&K, &mut V)> {
+        match self.key {
+            Some(ref key) => self.map.get_mut(key).map(|value| (key, value)),
+            None => None,
+        }
+    }
+
+    fn remove_current(&mut self) -> Option<(K, V)> {
+        let key = match self.key.take() {
+            Some(key) => key,
+            None => return None,
+        };
+
+        self.key = btree_map_next(self.map, Some(&key)).map(|(key, _)| key.clone());
+        self.map.remove_entry(&key)
+    }
+}
+
+/// An occupied `BTreeMap` entry.
+///
+/// This wraps the standard library's entry, which cannot give the map back after it has been
+/// consumed, in order to reinsert the entry's value in [`replace_key`].
+///
+/// [`replace_key`]: ../map/trait.OccupiedEntry.html#tymethod.replace_key
+struct BTreeMapOccupiedEntry<'a, K: 'a, V: 'a> {
+    entry: btree_map::OccupiedEntry<'a, K, V>,
+    map: *mut BTreeMap<K, V>,
+}
+
+impl<'a, K: 'a + Ord, V: 'a> map::OccupiedEntry for BTreeMapOccupiedEntry<'a, K, V> {
+    type Key = K;
+    type Value = V;
+    type MutValue = &'a mut V;
+
+    fn key(&self) -> &K {
+        self.entry.key()
+    }
+
+    fn get(&self) -> &V {
+        self.entry.get()
+    }
+
+    fn get_mut(&mut self) -> &mut V {
+        self.entry.get_mut()
+    }
+
+    fn into_mut(self: Box<Self>) -> &'a mut V {
+        self.entry.into_mut()
+    }
+
+    fn insert(&mut self, value: V) -> V {
+        self.entry.insert(value)
+    }
+
+    fn remove(self: Box<Self>) -> V {
+        self.entry.remove()
+    }
+
+    fn remove_entry(self: Box<Self>) -> (K, V) {
+        self.entry.remove_entry()
+    }
+
+    fn replace_key(self: Box<Self>, key: K) -> K {
+        let map = self.map;
+        let (old_key, value) = self.entry.remove_entry();
+        // this is safe because the entry, which was the only borrow of the map, has been consumed
+        unsafe { (*map).insert(key, value); }
+        old_key
+    }
+}
+
+impl<'a, K: 'a + Ord, V: 'a> map::VacantEntry for btree_map::VacantEntry<'a, K, V> {
+    type Key = K;
+    type Value = V;
+    type MutValue = &'a mut V;
+
+    fn key(&self) -> &K {
+        self.key()
+    }
+
+    fn into_key(self: Box<Self>) -> K {
+        (*self).into_key()
+    }
+
+    fn insert(self: Box<Self>, value: V) -> &'a mut V {
+        (*self).insert(value)
+    }
+}
+
+impl<T: Ord> AddRemove for BTreeSet<T> {}
+
+impl<T: Ord> Collection for BTreeSet<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        self.extend(mem::replace(other, Self::new()));
+    }
+
+    fn extend_object(&mut self, items: &mut Iterator<Item = T>) {
+        self.extend(items);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain(&mut self, f: &mut FnMut(&T) -> bool) {
+        self.retain(f);
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = T> + 'a> {
+        Box::new(mem::replace(self, Self::new()).into_iter())
+    }
+
+    fn extract_if<'a>(&'a mut self, pred: &'a mut FnMut(&T) -> bool)
+        -> Box<Iterator<Item = T> + 'a>
+    {
+        Box::new(self.extract_if(.., pred))
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn shrink_to_fit(&mut self) {}
+
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::new()
+    }
+
+    fn into_vec(self) -> Vec<T> {
+        self.into_iter().collect()
+    }
+}
+
+impl<T: Ord> Iter for BTreeSet<T> {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a T> + 'a> {
+        Box::new(self.iter())
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut T> + 'a> where Self: Mutate {
+        unimplemented!()
+    }
+}
+
+impl<T: Ord> set::Base for BTreeSet<T> {
+    fn is_disjoint(&self, other: &Self) -> bool {
+        self.is_disjoint(other)
+    }
+
+    fn is_subset(&self, other: &Self) -> bool {
+        self.is_subset(other)
+    }
+
+    fn insert(&mut self, item: T) -> bool {
+        self.insert(item)
+    }
+
+    fn replace(&mut self, item: T) -> Option<T> {
+        self.replace(item)
+    }
+
+    fn entry<'a>(&'a mut self, item: T) -> set::Entry<'a, T> {
+        set_entry(self, item)
+    }
+}
+
+impl<T: Ord + Borrow<Q>, Q: ?Sized + Ord> Set<Q> for BTreeSet<T> {
+    fn contains(&self, item: &Q) -> bool {
+        self.contains(item)
+    }
+
+    fn get(&self, item: &Q) -> Option<&T> {
+        self.get(item)
+    }
+
+    fn remove(&mut self, item: &Q) -> bool {
+        self.remove(item)
+    }
+
+    fn take(&mut self, item: &Q) -> Option<T> {
+        self.take(item)
+    }
+}
+
+/// Returns the entry in the set for the given item.
+///
+/// The standard library's sets do not expose entries, so the entry looks up the item again when
+/// it is used.
+fn set_entry<'a, S: set::Set + AddRemove>(set: &'a mut S, item: S::Item)
+    -> set::Entry<'a, S::Item>
+{
+    if set.contains(&item) {
+        set::Entry::Occupied(Box::new(SetOccupiedEntry { set: set, item: item }))
+    } else {
+        set::Entry::Vacant(Box::new(SetVacantEntry { set: set, item: item }))
+    }
+}
+
+struct SetOccupiedEntry<'a, S: 'a + set::Set> {
+    set: &'a mut S,
+    item: S::Item,
+}
+
+impl<'a, S: set::Set + AddRemove> set::OccupiedEntry for SetOccupiedEntry<'a, S> {
+    type Item = S::Item;
+
+    fn get(&self) -> &S::Item {
+        self.set.get(&self.item).expect("occupied entry's item is missing from the set")
+    }
+
+    fn take(self: Box<Self>) -> S::Item {
+        self.set.take(&self.item).expect("occupied entry's item is missing from the set")
+    }
+}
+
+struct SetVacantEntry<'a, S: 'a + set::Set> {
+    set: &'a mut S,
+    item: S::Item,
+}
+
+impl<'a, S: set::Set + AddRemove> set::VacantEntry for SetVacantEntry<'a, S> {
+    type Item = S::Item;
+
+    fn get(&self) -> &S::Item {
+        &self.item
+    }
+
+    fn into_item(self: Box<Self>) -> S::Item {
+        self.item
+    }
+
+    fn insert(self: Box<Self>) {
+        let this = *self;
+        this.set.insert(this.item);
+    }
+}
+
+impl<T: Ord> AddRemove for BinaryHeap<T> {}
+
+impl<T: Ord> Collection for BinaryHeap<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        self.extend(other.drain());
+    }
+
+    fn extend_object(&mut self, items: &mut Iterator<Item = T>) {
+        self.extend(items);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
     }
 
-    fn swap_remove(&mut self, _index: usize) -> Option<T> where Self: AddRemove {
+    fn retain(&mut self, f: &mut FnMut(&T) -> bool) {
+        self.retain(f);
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = T> + 'a> {
+        Box::new(self.drain())
+    }
+
+    // the heap has to be rebuilt after items are removed from the middle of it, so they are
+    // removed eagerly, in place, and the remaining items are reordered once
+    fn extract_if<'a>(&'a mut self, pred: &'a mut FnMut(&T) -> bool)
+        -> Box<Iterator<Item = T> + 'a>
+    {
+        let mut items = mem::replace(self, Self::new()).into_vec();
+        let extracted: Vec<_> = items.extract_if(.., move |item| pred(item)).collect();
+        *self = Self::from(items);
+        Box::new(extracted.into_iter())
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
+    }
+
+    fn into_vec(self) -> Vec<T> {
+        self.into_vec()
+    }
+}
+
+impl<T: Ord> Iter for BinaryHeap<T> {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a T> + 'a> {
+        Box::new(self.iter())
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut T> + 'a> where Self: Mutate {
         unimplemented!()
     }
 }
 
-impl<K: Ord, V> Mutate for BTreeMap<K, V> {}
+impl<T: Ord> Queue for BinaryHeap<T> {
+    fn push(&mut self, item: T) {
+        self.push(item);
+    }
 
-impl<K: Ord, V> AddRemove for BTreeMap<K, V> {}
+    fn front(&self) -> Option<&T> {
+        self.peek()
+    }
 
-impl<K: Ord, V> Collection for BTreeMap<K, V> {
+    fn pop_front(&mut self) -> Option<T> {
+        self.pop()
+    }
+}
+
+impl<T: Ord> PrioQueue for BinaryHeap<T> {
+    fn push_pop_front(&mut self, item: T) -> T {
+        match self.peek_mut() {
+            Some(ref mut old) if item < **old => mem::replace(&mut *old, item),
+            _ => item,
+        }
+    }
+
+    fn replace_front(&mut self, item: T) -> Option<T> {
+        if let Some(mut old) = self.peek_mut() {
+            return Some(mem::replace(&mut *old, item));
+        };
+
+        self.push(item);
+        None
+    }
+}
+
+impl<K: Eq + Hash, V> Mutate for HashMap<K, V> {}
+
+impl<K: Eq + Hash, V> AddRemove for HashMap<K, V> {}
+
+impl<K: Eq + Hash, V> Collection for HashMap<K, V> {
     type Item = (K, V);
 
     fn len(&self) -> usize {
@@ -95,11 +1170,11 @@ impl<K: Ord, V> Collection for BTreeMap<K, V> {
     }
 
     fn capacity(&self) -> usize {
-        self.len()
+        self.capacity()
     }
 
     fn append(&mut self, other: &mut Self) {
-        self.extend(mem::replace(other, Self::new()));
+        self.extend(other.drain());
     }
 
     fn extend_object(&mut self, items: &mut Iterator<Item = (K, V)>) {
@@ -111,15 +1186,19 @@ impl<K: Ord, V> Collection for BTreeMap<K, V> {
     }
 
     fn drain<'a>(&'a mut self) -> Box<Iterator<Item = (K, V)> + 'a> {
-        Box::new(mem::replace(self, Self::new()).into_iter())
+        Box::new(self.drain())
     }
 
-    fn reserve(&mut self, _additional: usize) {}
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
 
-    fn shrink_to_fit(&mut self) {}
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
 
-    fn with_capacity(_capacity: usize) -> Self {
-        Self::new()
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
     }
 
     fn into_vec(self) -> Vec<(K, V)> {
@@ -127,7 +1206,7 @@ impl<K: Ord, V> Collection for BTreeMap<K, V> {
     }
 }
 
-impl<K: Ord, V> map::Base for BTreeMap<K, V> {
+impl<K: Eq + Hash, V> map::Base for HashMap<K, V> {
     type Key = K;
     type Value = V;
 
@@ -144,48 +1223,136 @@ impl<K: Ord, V> map::Base for BTreeMap<K, V> {
     }
 
     fn entry<'a>(&'a mut self, key: K) -> map::Entry<'a, K, V> {
-        match self.entry(key) {
-            btree_map::Entry::Occupied(e) => map::Entry::Occupied(Box::new(e)),
-            btree_map::Entry::Vacant(e) => map::Entry::Vacant(Box::new(e)),
+        let map: *mut Self = self;
+
+        // the entry borrows the map through the raw pointer, so that `replace_key` can use the
+        // pointer once the entry has been consumed
+        match unsafe { (*map).entry(key) } {
+            hash_map::Entry::Occupied(e) => {
+                map::Entry::Occupied(Box::new(HashMapOccupiedEntry { entry: e, map: map }))
+            }
+            hash_map::Entry::Vacant(e) => map::Entry::Vacant(Box::new(e)),
         }
     }
+
+    fn replace(&mut self, key: K, value: V) -> Option<(K, V)> {
+        let entry = self.remove_entry(&key);
+        self.insert(key, value);
+        entry
+    }
+
+    fn retain_mut(&mut self, f: &mut FnMut(&K, &mut V) -> bool) {
+        self.retain(f);
+    }
+
+    fn extract_if_mut<'a>(&'a mut self, f: &'a mut FnMut(&K, &mut V) -> bool)
+        -> Box<Iterator<Item = (K, V)> + 'a>
+    {
+        Box::new(self.extract_if(f))
+    }
+}
+
+impl<K: Eq + Hash + Borrow<Q>, V, Q: ?Sized + Eq + Hash> Map<Q> for HashMap<K, V> {
+    fn contains_key(&self, key: &Q) -> bool {
+        self.contains_key(key)
+    }
+
+    fn get(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn get_key_value(&self, key: &Q) -> Option<(&K, &V)> {
+        self.get_key_value(key)
+    }
+
+    fn get_mut(&mut self, key: &Q) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+
+    fn get_disjoint_mut<const N: usize>(&mut self, keys: [&Q; N]) -> [Option<&mut V>; N] {
+        self.get_disjoint_mut(keys)
+    }
+
+    fn remove(&mut self, key: &Q) -> Option<V> {
+        self.remove(key)
+    }
+
+    fn remove_entry(&mut self, key: &Q) -> Option<(K, V)> {
+        self.remove_entry(key)
+    }
 }
 
-impl<'a, K: 'a + Ord, V: 'a> map::OccupiedEntry for btree_map::OccupiedEntry<'a, K, V> {
+/// An occupied `HashMap` entry.
+///
+/// See `BTreeMapOccupiedEntry` for why this wraps the standard library's entry.
+struct HashMapOccupiedEntry<'a, K: 'a, V: 'a> {
+    entry: hash_map::OccupiedEntry<'a, K, V>,
+    map: *mut HashMap<K, V>,
+}
+
+impl<'a, K: 'a + Eq + Hash, V: 'a> map::OccupiedEntry for HashMapOccupiedEntry<'a, K, V> {
     type Key = K;
     type Value = V;
     type MutValue = &'a mut V;
 
+    fn key(&self) -> &K {
+        self.entry.key()
+    }
+
     fn get(&self) -> &V {
-        self.get()
+        self.entry.get()
     }
 
     fn get_mut(&mut self) -> &mut V {
-        self.get_mut()
+        self.entry.get_mut()
     }
 
     fn into_mut(self: Box<Self>) -> &'a mut V {
-        (*self).into_mut()
+        self.entry.into_mut()
+    }
+
+    fn insert(&mut self, value: V) -> V {
+        self.entry.insert(value)
     }
 
     fn remove(self: Box<Self>) -> V {
-        (*self).remove()
+        self.entry.remove()
+    }
+
+    fn remove_entry(self: Box<Self>) -> (K, V) {
+        self.entry.remove_entry()
+    }
+
+    fn replace_key(self: Box<Self>, key: K) -> K {
+        let map = self.map;
+        let (old_key, value) = self.entry.remove_entry();
+        // this is safe because the entry, which was the only borrow of the map, has been consumed
+        unsafe { (*map).insert(key, value); }
+        old_key
     }
 }
 
-impl<'a, K: 'a + Ord, V: 'a> map::VacantEntry for btree_map::VacantEntry<'a, K, V> {
+impl<'a, K: 'a + Eq + Hash, V: 'a> map::VacantEntry for hash_map::VacantEntry<'a, K, V> {
     type Key = K;
     type Value = V;
     type MutValue = &'a mut V;
 
+    fn key(&self) -> &K {
+        self.key()
+    }
+
+    fn into_key(self: Box<Self>) -> K {
+        (*self).into_key()
+    }
+
     fn insert(self: Box<Self>, value: V) -> &'a mut V {
         (*self).insert(value)
     }
 }
 
-impl<T: Ord> AddRemove for BTreeSet<T> {}
+impl<T: Eq + Hash> AddRemove for HashSet<T> {}
 
-impl<T: Ord> Collection for BTreeSet<T> {
+impl<T: Eq + Hash> Collection for HashSet<T> {
     type Item = T;
 
     fn len(&self) -> usize {
@@ -193,11 +1360,11 @@ impl<T: Ord> Collection for BTreeSet<T> {
     }
 
     fn capacity(&self) -> usize {
-        self.len()
+        self.capacity()
     }
 
     fn append(&mut self, other: &mut Self) {
-        self.extend(mem::replace(other, Self::new()));
+        self.extend(other.drain());
     }
 
     fn extend_object(&mut self, items: &mut Iterator<Item = T>) {
@@ -208,16 +1375,30 @@ impl<T: Ord> Collection for BTreeSet<T> {
         self.clear();
     }
 
+    fn retain(&mut self, f: &mut FnMut(&T) -> bool) {
+        self.retain(f);
+    }
+
     fn drain<'a>(&'a mut self) -> Box<Iterator<Item = T> + 'a> {
-        Box::new(mem::replace(self, Self::new()).into_iter())
+        Box::new(self.drain())
     }
 
-    fn reserve(&mut self, _additional: usize) {}
+    fn extract_if<'a>(&'a mut self, pred: &'a mut FnMut(&T) -> bool)
+        -> Box<Iterator<Item = T> + 'a>
+    {
+        Box::new(self.extract_if(pred))
+    }
 
-    fn shrink_to_fit(&mut self) {}
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
 
-    fn with_capacity(_capacity: usize) -> Self {
-        Self::new()
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
     }
 
     fn into_vec(self) -> Vec<T> {
@@ -225,7 +1406,7 @@ impl<T: Ord> Collection for BTreeSet<T> {
     }
 }
 
-impl<T: Ord> Iter for BTreeSet<T> {
+impl<T: Eq + Hash> Iter for HashSet<T> {
     fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a T> + 'a> {
         Box::new(self.iter())
     }
@@ -235,7 +1416,7 @@ impl<T: Ord> Iter for BTreeSet<T> {
     }
 }
 
-impl<T: Ord> set::Base for BTreeSet<T> {
+impl<T: Eq + Hash> set::Base for HashSet<T> {
     fn is_disjoint(&self, other: &Self) -> bool {
         self.is_disjoint(other)
     }
@@ -251,9 +1432,13 @@ impl<T: Ord> set::Base for BTreeSet<T> {
     fn replace(&mut self, item: T) -> Option<T> {
         self.replace(item)
     }
+
+    fn entry<'a>(&'a mut self, item: T) -> set::Entry<'a, T> {
+        set_entry(self, item)
+    }
 }
 
-impl<T: Ord + Borrow<Q>, Q: ?Sized + Ord> Set<Q> for BTreeSet<T> {
+impl<T: Eq + Hash + Borrow<Q>, Q: ?Sized + Eq + Hash> Set<Q> for HashSet<T> {
     fn contains(&self, item: &Q) -> bool {
         self.contains(item)
     }
@@ -266,242 +1451,390 @@ impl<T: Ord + Borrow<Q>, Q: ?Sized + Ord> Set<Q> for BTreeSet<T> {
         self.remove(item)
     }
 
-    fn take(&mut self, item: &Q) -> Option<T> {
-        self.take(item)
+    fn take(&mut self, item: &Q) -> Option<T> {
+        self.take(item)
+    }
+}
+
+impl<T> Mutate for LinkedList<T> {}
+
+impl<T> AddRemove for LinkedList<T> {}
+
+impl<T> Collection for LinkedList<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        self.append(other);
+    }
+
+    fn extend_object(&mut self, items: &mut Iterator<Item = T>) {
+        self.extend(items);
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain(&mut self, f: &mut FnMut(&T) -> bool) {
+        for item in mem::replace(self, Self::new()) {
+            if f(&item) { self.push_back(item); }
+        }
+    }
+
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = T> + 'a> {
+        Box::new(mem::replace(self, Self::new()).into_iter())
+    }
+
+    fn extract_if<'a>(&'a mut self, pred: &'a mut FnMut(&T) -> bool)
+        -> Box<Iterator<Item = T> + 'a>
+    {
+        let rest = mem::replace(self, Self::new());
+        Box::new(LinkedListExtractIf { list: self, rest: rest, pred: pred })
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn shrink_to_fit(&mut self) {}
+
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::new()
+    }
+
+    fn into_vec(self) -> Vec<T> {
+        self.into_iter().collect()
+    }
+}
+
+impl<T> Iter for LinkedList<T> {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a T> + 'a> {
+        Box::new(self.iter())
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut T> + 'a> {
+        Box::new(self.iter_mut())
+    }
+}
+
+impl<T> DrainRange<Range<usize>> for LinkedList<T> {
+    fn drain_range<'a>(&'a mut self, range: Range<usize>) -> Box<Iterator<Item = T> + 'a> {
+        assert!(range.start <= range.end, "range start exceeds range end");
+        let mut tail = self.split_off(range.end);
+        let drained = self.split_off(range.start);
+        self.append(&mut tail);
+        Box::new(drained.into_iter())
+    }
+}
+
+impl<T> List for LinkedList<T> {
+    fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.iter_mut().nth(index)
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        let len = self.len();
+        assert!(i < len && j < len, "index out of bounds");
+
+        if i != j {
+            let (i, j) = if i < j { (i, j) } else { (j, i) };
+            let mut it = self.iter_mut();
+            let a = it.nth(i).expect("index out of bounds");
+            let b = it.nth(j - i - 1).expect("index out of bounds");
+            mem::swap(a, b);
+        }
+    }
+
+    fn reverse(&mut self) {
+        let mut it = self.iter_mut();
+        while let (Some(a), Some(b)) = (it.next(), it.next_back()) { mem::swap(a, b); }
+    }
+
+    fn rotate_left(&mut self, mid: usize) {
+        let mut tail = self.split_off(mid);
+        tail.append(self);
+        *self = tail;
+    }
+
+    fn first(&self) -> Option<&T> {
+        self.front()
     }
-}
 
-impl<T: Ord> AddRemove for BinaryHeap<T> {}
+    fn first_mut(&mut self) -> Option<&mut T> {
+        self.front_mut()
+    }
 
-impl<T: Ord> Collection for BinaryHeap<T> {
-    type Item = T;
+    fn last(&self) -> Option<&T> {
+        self.back()
+    }
 
-    fn len(&self) -> usize {
-        self.len()
+    fn last_mut(&mut self) -> Option<&mut T> {
+        self.back_mut()
     }
 
-    fn capacity(&self) -> usize {
-        self.capacity()
+    fn push(&mut self, item: T) {
+        self.push_back(item);
     }
 
-    fn append(&mut self, other: &mut Self) {
-        self.extend(other.drain());
+    fn insert(&mut self, index: usize, item: T) {
+        let mut tail = self.split_off(index);
+        self.push_back(item);
+        self.append(&mut tail);
     }
 
-    fn extend_object(&mut self, items: &mut Iterator<Item = T>) {
+    fn insert_many(&mut self, index: usize, items: &mut Iterator<Item = T>) {
+        let mut tail = self.split_off(index);
         self.extend(items);
+        self.append(&mut tail);
     }
 
-    fn clear(&mut self) {
-        self.clear();
+    fn pop(&mut self) -> Option<T> {
+        self.pop_back()
     }
 
-    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = T> + 'a> {
-        Box::new(self.drain())
+    fn remove(&mut self, index: usize) -> Option<T> {
+        if index < self.len() {
+            let mut tail = self.split_off(index);
+            let item = tail.pop_front();
+            self.append(&mut tail);
+            item
+        } else {
+            None
+        }
     }
 
-    fn reserve(&mut self, additional: usize) {
-        self.reserve(additional);
-    }
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        let len = self.len();
 
-    fn shrink_to_fit(&mut self) {
-        self.shrink_to_fit();
+        if index < len {
+            self.swap(index, len - 1);
+            self.pop_back()
+        } else {
+            None
+        }
     }
 
-    fn with_capacity(capacity: usize) -> Self {
-        Self::with_capacity(capacity)
+    fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.split_off(len);
+        }
     }
 
-    fn into_vec(self) -> Vec<T> {
-        self.into_vec()
+    fn split_off(&mut self, index: usize) -> Self {
+        self.split_off(index)
     }
-}
 
-impl<T: Ord> Iter for BinaryHeap<T> {
-    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a T> + 'a> {
-        Box::new(self.iter())
+    fn cursor_front_mut<'a>(&'a mut self) -> Box<list::CursorMut<Item = T, List = Self> + 'a> {
+        let back = mem::replace(self, Self::new());
+        Box::new(LinkedListCursorMut { front: self, back: back })
     }
 
-    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut T> + 'a> where Self: Mutate {
-        unimplemented!()
+    fn cursor_back_mut<'a>(&'a mut self) -> Box<list::CursorMut<Item = T, List = Self> + 'a> {
+        let index = self.len().saturating_sub(1);
+        let back = self.split_off(index);
+        Box::new(LinkedListCursorMut { front: self, back: back })
     }
 }
 
-impl<T: Ord> Queue for BinaryHeap<T> {
+impl<T> Queue for LinkedList<T> {
     fn push(&mut self, item: T) {
-        self.push(item);
+        self.push_back(item);
     }
 
     fn front(&self) -> Option<&T> {
-        self.peek()
+        self.front()
     }
 
     fn pop_front(&mut self) -> Option<T> {
-        self.pop()
+        self.pop_front()
     }
 }
 
-impl<T: Ord> PrioQueue for BinaryHeap<T> {
-    fn push_pop_front(&mut self, item: T) -> T {
-        match self.peek_mut() {
-            Some(ref mut old) if item < **old => mem::replace(&mut *old, item),
-            _ => item,
-        }
+impl<T> Deque for LinkedList<T> {
+    fn back(&self) -> Option<&T> {
+        self.back()
     }
 
-    fn replace_front(&mut self, item: T) -> Option<T> {
-        if let Some(mut old) = self.peek_mut() {
-            return Some(mem::replace(&mut *old, item));
-        };
-
-        self.push(item);
-        None
+    fn pop_back(&mut self) -> Option<T> {
+        self.pop_back()
     }
 }
 
-impl<K: Eq + Hash, V> Mutate for HashMap<K, V> {}
-
-impl<K: Eq + Hash, V> AddRemove for HashMap<K, V> {}
-
-impl<K: Eq + Hash, V> Collection for HashMap<K, V> {
-    type Item = (K, V);
-
-    fn len(&self) -> usize {
-        self.len()
+impl<T> FifoQueue for LinkedList<T> {
+    fn front_mut(&mut self) -> Option<&mut T> {
+        self.front_mut()
     }
+}
 
-    fn capacity(&self) -> usize {
-        self.capacity()
+impl<T> FifoDeque for LinkedList<T> {
+    fn push_front(&mut self, item: T) {
+        self.push_front(item);
     }
 
-    fn append(&mut self, other: &mut Self) {
-        self.extend(other.drain());
+    fn back_mut(&mut self) -> Option<&mut T> {
+        self.back_mut()
     }
+}
 
-    fn extend_object(&mut self, items: &mut Iterator<Item = (K, V)>) {
-        self.extend(items);
-    }
+impl<T> RandomAccessDeque for LinkedList<T> {}
 
-    fn clear(&mut self) {
-        self.clear();
+/// A cursor over a linked list that supports editing the list.
+///
+/// The list is split at the cursor, so that the current item is at the front of `back`. Each
+/// operation splits or appends at the ends of the halves, which takes constant time.
+struct LinkedListCursorMut<'a, T: 'a> {
+    front: &'a mut LinkedList<T>,
+    back: LinkedList<T>,
+}
+
+impl<'a, T> list::Cursor for LinkedListCursorMut<'a, T> {
+    type Item = T;
+
+    fn index(&self) -> Option<usize> {
+        if self.back.is_empty() { None } else { Some(self.front.len()) }
     }
 
-    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = (K, V)> + 'a> {
-        Box::new(self.drain())
+    fn move_next(&mut self) {
+        if self.back.is_empty() {
+            mem::swap(self.front, &mut self.back);
+        } else {
+            let rest = self.back.split_off(1);
+            self.front.append(&mut mem::replace(&mut self.back, rest));
+        }
     }
 
-    fn reserve(&mut self, additional: usize) {
-        self.reserve(additional);
+    fn move_prev(&mut self) {
+        if self.front.is_empty() {
+            mem::swap(self.front, &mut self.back);
+        } else {
+            let index = self.front.len() - 1;
+            let mut back = self.front.split_off(index);
+            back.append(&mut self.back);
+            self.back = back;
+        }
     }
 
-    fn shrink_to_fit(&mut self) {
-        self.shrink_to_fit();
+    fn current(&self) -> Option<&T> {
+        self.back.front()
     }
 
-    fn with_capacity(capacity: usize) -> Self {
-        Self::with_capacity(capacity)
+    fn peek_next(&self) -> Option<&T> {
+        if self.back.is_empty() { self.front.front() } else { self.back.iter().nth(1) }
     }
 
-    fn into_vec(self) -> Vec<(K, V)> {
-        self.into_iter().collect()
+    fn peek_prev(&self) -> Option<&T> {
+        self.front.back()
     }
 }
 
-impl<K: Eq + Hash, V> map::Base for HashMap<K, V> {
-    type Key = K;
-    type Value = V;
-
-    fn iter<'a>(&'a self) -> Box<Iterator<Item = (&'a K, &'a V)> + 'a> {
-        Box::new(self.iter())
-    }
+impl<'a, T> list::CursorMut for LinkedListCursorMut<'a, T> {
+    type List = LinkedList<T>;
 
-    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = (&'a K, &'a mut V)> + 'a> {
-        Box::new(self.iter_mut())
+    fn current_mut(&mut self) -> Option<&mut T> {
+        self.back.front_mut()
     }
 
-    fn insert(&mut self, key: K, value: V) -> Option<V> {
-        self.insert(key, value)
+    fn insert_before(&mut self, item: T) {
+        self.front.push_back(item);
     }
 
-    fn entry<'a>(&'a mut self, key: K) -> map::Entry<'a, K, V> {
-        match self.entry(key) {
-            hash_map::Entry::Occupied(e) => map::Entry::Occupied(Box::new(e)),
-            hash_map::Entry::Vacant(e) => map::Entry::Vacant(Box::new(e)),
+    fn insert_after(&mut self, item: T) {
+        if self.back.is_empty() {
+            self.front.push_front(item);
+        } else {
+            let mut rest = self.back.split_off(1);
+            self.back.push_back(item);
+            self.back.append(&mut rest);
         }
     }
-}
-
-impl<'a, K: 'a + Eq + Hash, V: 'a> map::OccupiedEntry for hash_map::OccupiedEntry<'a, K, V> {
-    type Key = K;
-    type Value = V;
-    type MutValue = &'a mut V;
 
-    fn get(&self) -> &V {
-        self.get()
+    fn remove_current(&mut self) -> Option<T> {
+        self.back.pop_front()
     }
 
-    fn get_mut(&mut self) -> &mut V {
-        self.get_mut()
+    fn split_before(&mut self) -> LinkedList<T> {
+        mem::replace(self.front, LinkedList::new())
     }
+}
 
-    fn into_mut(self: Box<Self>) -> &'a mut V {
-        (*self).into_mut()
+impl<'a, T> Drop for LinkedListCursorMut<'a, T> {
+    fn drop(&mut self) {
+        self.front.append(&mut self.back);
     }
+}
 
-    fn remove(self: Box<Self>) -> V {
-        (*self).remove()
-    }
+/// An iterator that removes the items in a linked list that match a predicate.
+struct LinkedListExtractIf<'a, T: 'a> {
+    list: &'a mut LinkedList<T>,
+    rest: LinkedList<T>,
+    pred: &'a mut FnMut(&T) -> bool,
 }
 
-impl<'a, K: 'a + Eq + Hash, V: 'a> map::VacantEntry for hash_map::VacantEntry<'a, K, V> {
-    type Key = K;
-    type Value = V;
-    type MutValue = &'a mut V;
+impl<'a, T> Iterator for LinkedListExtractIf<'a, T> {
+    type Item = T;
 
-    fn insert(self: Box<Self>, value: V) -> &'a mut V {
-        (*self).insert(value)
+    fn next(&mut self) -> Option<T> {
+        while let Some(item) = self.rest.pop_front() {
+            if (self.pred)(&item) { return Some(item); }
+            self.list.push_back(item);
+        }
+
+        None
     }
 }
 
-impl<T: Eq + Hash> AddRemove for HashSet<T> {}
+impl<'a, T> Drop for LinkedListExtractIf<'a, T> {
+    fn drop(&mut self) {
+        self.list.append(&mut self.rest);
+    }
+}
 
-impl<T: Eq + Hash> Collection for HashSet<T> {
+// `Option` is treated as a list with a capacity of one, so inserting into a full one panics.
+impl<T> Mutate for Option<T> {}
+
+impl<T> AddRemove for Option<T> {}
+
+impl<T> Collection for Option<T> {
     type Item = T;
 
     fn len(&self) -> usize {
-        self.len()
+        if self.is_some() { 1 } else { 0 }
     }
 
     fn capacity(&self) -> usize {
-        self.capacity()
-    }
-
-    fn append(&mut self, other: &mut Self) {
-        self.extend(other.drain());
+        1
     }
 
     fn extend_object(&mut self, items: &mut Iterator<Item = T>) {
-        self.extend(items);
+        for item in items {
+            Queue::push(self, item);
+        }
     }
 
     fn clear(&mut self) {
-        self.clear();
+        *self = None;
     }
 
     fn drain<'a>(&'a mut self) -> Box<Iterator<Item = T> + 'a> {
-        Box::new(self.drain())
+        Box::new(self.take().into_iter())
     }
 
-    fn reserve(&mut self, additional: usize) {
-        self.reserve(additional);
-    }
+    fn reserve(&mut self, _additional: usize) {}
 
-    fn shrink_to_fit(&mut self) {
-        self.shrink_to_fit();
-    }
+    fn shrink_to_fit(&mut self) {}
 
-    fn with_capacity(capacity: usize) -> Self {
-        Self::with_capacity(capacity)
+    fn with_capacity(_capacity: usize) -> Self {
+        None
     }
 
     fn into_vec(self) -> Vec<T> {
@@ -509,143 +1842,206 @@ impl<T: Eq + Hash> Collection for HashSet<T> {
     }
 }
 
-impl<T: Eq + Hash> Iter for HashSet<T> {
+impl<T> Iter for Option<T> {
     fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a T> + 'a> {
         Box::new(self.iter())
     }
 
-    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut T> + 'a> where Self: Mutate {
-        unimplemented!()
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut T> + 'a> {
+        Box::new(self.iter_mut())
     }
 }
 
-impl<T: Eq + Hash> set::Base for HashSet<T> {
-    fn is_disjoint(&self, other: &Self) -> bool {
-        self.is_disjoint(other)
+impl<T> DrainRange<Range<usize>> for Option<T> {
+    fn drain_range<'a>(&'a mut self, range: Range<usize>) -> Box<Iterator<Item = T> + 'a> {
+        assert!(range.start <= range.end, "range start is greater than range end");
+        assert!(range.end <= Collection::len(self), "range end out of bounds");
+
+        if range.start < range.end {
+            self.drain()
+        } else {
+            Box::new(None.into_iter())
+        }
     }
+}
 
-    fn is_subset(&self, other: &Self) -> bool {
-        self.is_subset(other)
+impl<T> List for Option<T> {
+    fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
     }
 
-    fn insert(&mut self, item: T) -> bool {
-        self.insert(item)
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
     }
 
-    fn replace(&mut self, item: T) -> Option<T> {
-        self.replace(item)
+    fn swap(&mut self, i: usize, j: usize) {
+        self.as_mut_slice().swap(i, j);
     }
-}
 
-impl<T: Eq + Hash + Borrow<Q>, Q: ?Sized + Eq + Hash> Set<Q> for HashSet<T> {
-    fn contains(&self, item: &Q) -> bool {
-        self.contains(item)
+    fn insert(&mut self, index: usize, item: T) {
+        assert!(index <= Collection::len(self), "index out of bounds");
+        assert!(self.is_none(), "capacity exceeded");
+        *self = Some(item);
     }
 
-    fn get(&self, item: &Q) -> Option<&T> {
-        self.get(item)
+    fn remove(&mut self, index: usize) -> Option<T> {
+        if index == 0 { self.take() } else { None }
     }
 
-    fn remove(&mut self, item: &Q) -> bool {
-        self.remove(item)
+    fn swap_remove(&mut self, index: usize) -> Option<T> {
+        List::remove(self, index)
     }
 
-    fn take(&mut self, item: &Q) -> Option<T> {
-        self.take(item)
+    fn split_off(&mut self, index: usize) -> Self {
+        assert!(index <= Collection::len(self), "index out of bounds");
+        if index == 0 { self.take() } else { None }
+    }
+
+    fn contiguous(&self) -> Option<&[T]> {
+        Some(self.as_slice())
+    }
+
+    fn contiguous_mut(&mut self) -> Option<&mut [T]> {
+        Some(self.as_mut_slice())
     }
 }
 
-impl<T> Mutate for LinkedList<T> {}
+impl<T> Queue for Option<T> {
+    fn push(&mut self, item: T) {
+        List::insert(self, 0, item);
+    }
 
-impl<T> AddRemove for LinkedList<T> {}
+    fn front(&self) -> Option<&T> {
+        self.as_ref()
+    }
 
-impl<T> Collection for LinkedList<T> {
-    type Item = T;
+    fn pop_front(&mut self) -> Option<T> {
+        self.take()
+    }
+}
+
+impl<T> FifoQueue for Option<T> {
+    fn front_mut(&mut self) -> Option<&mut T> {
+        self.as_mut()
+    }
+}
+
+impl AddRemoveChars for String {}
+
+impl Collection for String {
+    type Item = u8;
 
     fn len(&self) -> usize {
         self.len()
     }
 
     fn capacity(&self) -> usize {
-        self.len()
+        self.capacity()
+    }
+
+    fn extend_object(&mut self, _items: &mut Iterator<Item = u8>) {
+        unimplemented!()
     }
 
-    fn append(&mut self, other: &mut Self) {
-        self.append(other);
+    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = u8> + 'a> {
+        unimplemented!()
     }
 
-    fn extend_object(&mut self, items: &mut Iterator<Item = T>) {
-        self.extend(items);
+    fn reserve(&mut self, _additional: usize) {
+        unimplemented!()
     }
 
-    fn clear(&mut self) {
-        self.clear();
+    fn shrink_to_fit(&mut self) {
+        unimplemented!()
     }
 
-    fn drain<'a>(&'a mut self) -> Box<Iterator<Item = T> + 'a> {
-        Box::new(mem::replace(self, Self::new()).into_iter())
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
     }
 
-    fn reserve(&mut self, _additional: usize) {}
+    fn into_vec(self) -> Vec<u8> {
+        self.into_bytes()
+    }
+}
 
-    fn shrink_to_fit(&mut self) {}
+impl Iter for String {
+    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a u8> + 'a> {
+        Box::new(self.as_bytes().iter())
+    }
 
-    fn with_capacity(_capacity: usize) -> Self {
-        Self::new()
+    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut u8> + 'a> {
+        unimplemented!()
     }
+}
 
-    fn into_vec(self) -> Vec<T> {
-        self.into_iter().collect()
+impl DrainRange<Range<usize>> for String {
+    fn drain_range<'a>(&'a mut self, _range: Range<usize>) -> Box<Iterator<Item = u8> + 'a> {
+        unimplemented!()
     }
 }
 
-impl<T> Iter for LinkedList<T> {
-    fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a T> + 'a> {
-        Box::new(self.iter())
+impl List for String {
+    fn get(&self, index: usize) -> Option<&u8> {
+        self.as_bytes().get(index)
     }
 
-    fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut T> + 'a> {
-        Box::new(self.iter_mut())
+    fn get_mut(&mut self, _index: usize) -> Option<&mut u8> {
+        unimplemented!()
     }
-}
 
-impl<T> Queue for LinkedList<T> {
-    fn push(&mut self, item: T) {
-        self.push_back(item);
+    fn swap(&mut self, _i: usize, _j: usize) {
+        unimplemented!()
     }
 
-    fn front(&self) -> Option<&T> {
-        self.front()
+    fn insert(&mut self, _index: usize, _item: u8) {
+        unimplemented!()
     }
 
-    fn pop_front(&mut self) -> Option<T> {
-        self.pop_front()
+    fn remove(&mut self, _index: usize) -> Option<u8> {
+        unimplemented!()
     }
-}
 
-impl<T> Deque for LinkedList<T> {
-    fn back(&self) -> Option<&T> {
-        self.back()
+    fn swap_remove(&mut self, _index: usize) -> Option<u8> {
+        unimplemented!()
     }
 
-    fn pop_back(&mut self) -> Option<T> {
-        self.pop_back()
+    fn contiguous(&self) -> Option<&[u8]> {
+        Some(self.as_bytes())
     }
 }
 
-impl<T> FifoQueue for LinkedList<T> {
-    fn front_mut(&mut self) -> Option<&mut T> {
-        self.front_mut()
+impl Text for String {
+    fn as_str(&self) -> &str {
+        self
     }
-}
 
-impl<T> FifoDeque for LinkedList<T> {
-    fn push_front(&mut self, item: T) {
-        self.push_front(item);
+    fn insert_str(&mut self, index: usize, s: &str) -> Result<(), CharBoundaryError> {
+        if !str::is_char_boundary(self, index) {
+            return Err(CharBoundaryError::new(index));
+        }
+
+        String::insert_str(self, index, s);
+        Ok(())
     }
 
-    fn back_mut(&mut self) -> Option<&mut T> {
-        self.back_mut()
+    fn remove_char(&mut self, index: usize) -> Result<char, CharBoundaryError> {
+        if index == self.len() || !str::is_char_boundary(self, index) {
+            return Err(CharBoundaryError::new(index));
+        }
+
+        Ok(String::remove(self, index))
+    }
+
+    fn drain_chars<'a>(&'a mut self, range: Range<usize>)
+        -> Result<Box<Iterator<Item = char> + 'a>, CharBoundaryError>
+    {
+        for &index in &[range.start, range.end] {
+            if !str::is_char_boundary(self, index) {
+                return Err(CharBoundaryError::new(index));
+            }
+        }
+
+        Ok(Box::new(self.drain(range)))
     }
 }
 
@@ -676,10 +2072,20 @@ impl<T> Collection for Vec<T> {
         self.clear();
     }
 
+    fn retain(&mut self, f: &mut FnMut(&T) -> bool) {
+        self.retain(f);
+    }
+
     fn drain<'a>(&'a mut self) -> Box<Iterator<Item = T> + 'a> {
         Box::new(self.drain(..))
     }
 
+    fn extract_if<'a>(&'a mut self, pred: &'a mut FnMut(&T) -> bool)
+        -> Box<Iterator<Item = T> + 'a>
+    {
+        Box::new(self.extract_if(.., move |item| pred(item)))
+    }
+
     fn reserve(&mut self, additional: usize) {
         self.reserve(additional);
     }
@@ -730,6 +2136,14 @@ impl<T> List for Vec<T> {
         (**self).reverse();
     }
 
+    fn rotate_left(&mut self, mid: usize) {
+        (**self).rotate_left(mid);
+    }
+
+    fn rotate_right(&mut self, k: usize) {
+        (**self).rotate_right(k);
+    }
+
     fn push(&mut self, item: T) {
         self.push(item);
     }
@@ -738,6 +2152,16 @@ impl<T> List for Vec<T> {
         self.insert(index, item);
     }
 
+    fn insert_many(&mut self, index: usize, items: &mut Iterator<Item = T>) {
+        self.splice(index..index, items);
+    }
+
+    fn splice<'a>(&'a mut self, range: Range<usize>, items: &'a mut Iterator<Item = T>)
+        -> Box<Iterator<Item = T> + 'a>
+    {
+        Box::new(self.splice(range, items))
+    }
+
     fn pop(&mut self) -> Option<T> {
         self.pop()
     }
@@ -762,9 +2186,53 @@ impl<T> List for Vec<T> {
         self.truncate(len);
     }
 
+    fn resize(&mut self, len: usize, item: T) where T: Clone {
+        self.resize(len, item);
+    }
+
+    fn resize_with(&mut self, len: usize, f: &mut FnMut() -> T) {
+        self.resize_with(len, f);
+    }
+
     fn split_off(&mut self, index: usize) -> Self {
         self.split_off(index)
     }
+
+    fn dedup_by(&mut self, same_bucket: &mut FnMut(&mut T, &mut T) -> bool) {
+        self.dedup_by(|a, b| same_bucket(a, b));
+    }
+
+    fn contiguous(&self) -> Option<&[T]> {
+        Some(self)
+    }
+
+    fn contiguous_mut(&mut self) -> Option<&mut [T]> {
+        Some(self)
+    }
+}
+
+impl<T> Segmented for Vec<T> {
+    fn as_slices(&self) -> (&[T], &[T]) {
+        (self, &[])
+    }
+
+    fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        (self, &mut [])
+    }
+
+    fn make_contiguous(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T> Contiguous for Vec<T> {
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
 }
 
 impl<T> Mutate for VecDeque<T> {}
@@ -786,6 +2254,10 @@ impl<T> Collection for VecDeque<T> {
         self.clear();
     }
 
+    fn retain(&mut self, f: &mut FnMut(&T) -> bool) {
+        self.retain(f);
+    }
+
     fn append(&mut self, other: &mut Self) {
         self.append(other);
     }
@@ -798,6 +2270,13 @@ impl<T> Collection for VecDeque<T> {
         Box::new(self.drain(..))
     }
 
+    fn extract_if<'a>(&'a mut self, pred: &'a mut FnMut(&T) -> bool)
+        -> Box<Iterator<Item = T> + 'a>
+    {
+        let remaining = self.len();
+        Box::new(VecDequeExtractIf { deque: self, remaining: remaining, pred: pred })
+    }
+
     fn reserve(&mut self, additional: usize) {
         self.reserve(additional);
     }
@@ -849,6 +2328,14 @@ impl<T> List for VecDeque<T> {
         while let (Some(a), Some(b)) = (it.next(), it.next_back()) { mem::swap(a, b); }
     }
 
+    fn rotate_left(&mut self, mid: usize) {
+        self.rotate_left(mid);
+    }
+
+    fn rotate_right(&mut self, k: usize) {
+        self.rotate_right(k);
+    }
+
     fn push(&mut self, item: T) {
         self.push_back(item);
     }
@@ -857,6 +2344,12 @@ impl<T> List for VecDeque<T> {
         self.insert(index, item);
     }
 
+    fn insert_many(&mut self, index: usize, items: &mut Iterator<Item = T>) {
+        let mut tail = self.split_off(index);
+        self.extend(items);
+        self.append(&mut tail);
+    }
+
     fn pop(&mut self) -> Option<T> {
         self.pop_back()
     }
@@ -873,9 +2366,42 @@ impl<T> List for VecDeque<T> {
         self.truncate(len);
     }
 
+    fn resize(&mut self, len: usize, item: T) where T: Clone {
+        self.resize(len, item);
+    }
+
+    fn resize_with(&mut self, len: usize, f: &mut FnMut() -> T) {
+        self.resize_with(len, f);
+    }
+
     fn split_off(&mut self, index: usize) -> Self {
         self.split_off(index)
     }
+
+    fn contiguous(&self) -> Option<&[T]> {
+        match self.as_slices() {
+            (front, &[]) => Some(front),
+            _ => None,
+        }
+    }
+
+    fn contiguous_mut(&mut self) -> Option<&mut [T]> {
+        Some(self.make_contiguous())
+    }
+}
+
+impl<T> Segmented for VecDeque<T> {
+    fn as_slices(&self) -> (&[T], &[T]) {
+        self.as_slices()
+    }
+
+    fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        self.as_mut_slices()
+    }
+
+    fn make_contiguous(&mut self) -> &mut [T] {
+        self.make_contiguous()
+    }
 }
 
 impl<T> Queue for VecDeque<T> {
@@ -918,6 +2444,53 @@ impl<T> FifoDeque for VecDeque<T> {
     }
 }
 
+impl<T> RandomAccessDeque for VecDeque<T> {
+    fn range<'a>(&'a self, range: Range<usize>) -> Box<Iterator<Item = &'a T> + 'a> {
+        Box::new(self.range(range))
+    }
+
+    fn range_mut<'a>(&'a mut self, range: Range<usize>) -> Box<Iterator<Item = &'a mut T> + 'a> {
+        Box::new(self.range_mut(range))
+    }
+}
+
+/// An iterator that extracts items from a `VecDeque` in place.
+///
+/// The unvisited items are at the front of the deque. Each visited item is either yielded or moved
+/// to the back, after the items that were retained before it, so the deque only needs to be
+/// rotated once the iterator is dropped.
+struct VecDequeExtractIf<'a, T: 'a> {
+    deque: &'a mut VecDeque<T>,
+    remaining: usize,
+    pred: &'a mut FnMut(&T) -> bool,
+}
+
+impl<'a, T> Iterator for VecDequeExtractIf<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while self.remaining > 0 {
+            let extract = (self.pred)(&self.deque[0]);
+            self.remaining -= 1;
+            let item = self.deque.pop_front().expect("deque has unvisited items");
+            if extract { return Some(item); }
+            self.deque.push_back(item);
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+impl<'a, T> Drop for VecDequeExtractIf<'a, T> {
+    fn drop(&mut self) {
+        self.deque.rotate_left(self.remaining);
+    }
+}
+
 #[test]
 fn test_binary_heap_push_pop_front() {
     let mut h = BinaryHeap::new();
@@ -940,3 +2513,359 @@ fn test_binary_heap_replace_front() {
     assert_eq!(h.replace_front(6), Some(4));
     assert!(h.iter().eq(&[6]));
 }
+
+#[test]
+fn test_retain_extract_if() {
+    fn check<C: Collection<Item = i32> + AddRemove>(mut c: C) -> C {
+        c.retain(&mut |&item| item != 3);
+        assert_eq!(c.extract_if(&mut |&item| item % 2 == 0).count(), 2);
+        c
+    }
+
+    assert_eq!(check(vec![1, 2, 3, 4, 5]), [1, 5]);
+    assert!(check(vec![5, 4, 3, 2, 1].into_iter().collect::<LinkedList<_>>()).iter().eq(&[5, 1]));
+    assert!(check(vec![1, 2, 3, 4, 5].into_iter().collect::<VecDeque<_>>()).iter().eq(&[1, 5]));
+    assert_eq!(check((1..6).collect::<BTreeSet<_>>()).into_vec(), [1, 5]);
+    assert_eq!(check((1..6).collect::<BinaryHeap<_>>()).into_sorted_vec(), [1, 5]);
+
+    let mut l: LinkedList<_> = (0..6).collect();
+    assert_eq!(Collection::extract_if(&mut l, &mut |&item| item > 1).next(), Some(2));
+    assert!(l.iter().eq(&[0, 1, 3, 4, 5]));
+
+    let mut q: VecDeque<_> = (0..6).collect();
+    assert_eq!(Collection::extract_if(&mut q, &mut |&item| item % 2 == 1).next(), Some(1));
+    assert!(q.iter().eq(&[0, 2, 3, 4, 5]));
+
+    let mut m: HashMap<_, _> = (0..4).map(|i| (i, i)).collect();
+    map::Base::retain_mut(&mut m, &mut |&key, value| { *value *= 10; key % 2 == 1 });
+    assert_eq!(m.into_vec().into_iter().collect::<BTreeMap<_, _>>().into_vec(), [(1, 10), (3, 30)]);
+
+    let mut m: BTreeMap<_, _> = (0..4).map(|i| (i, i)).collect();
+    let odd: Vec<_> = map::Base::extract_if_mut(&mut m, &mut |&key, value| {
+        *value *= 10;
+        key % 2 == 1
+    }).collect();
+    assert_eq!(odd, [(1, 10), (3, 30)]);
+    assert_eq!(m.into_vec(), [(0, 0), (2, 20)]);
+
+    let mut m: HashMap<_, _> = (0..4).map(|i| (i, i)).collect();
+    assert!(map::Base::extract_if_mut(&mut m, &mut |&key, _| key == 2).eq(vec![(2, 2)]));
+    assert_eq!(m.len(), 3);
+
+    let mut d: VecDeque<_> = vec![1, 1, 2, 3, 3, 3, 1].into_iter().collect();
+    List::dedup(&mut d);
+    assert!(d.iter().eq(&[1, 2, 3, 1]));
+}
+
+#[test]
+fn test_bulk_edits() {
+    fn check<L: List<Item = i32> + Mutate + AddRemove>(mut l: L) -> Vec<i32> {
+        l.rotate_left(2);
+        l.rotate_right(1);
+        assert_eq!(l.splice(1..3, &mut vec![7, 8, 9].into_iter()).collect::<Vec<_>>(), [2, 3]);
+        l.insert_many(0, &mut (5..7));
+        l.extend_from_list(&[0, 0][..]);
+        l.resize(l.len() + 1, 4);
+        l.resize_with(9, &mut || unreachable!());
+        l.into_vec()
+    }
+
+    let expected = [5, 6, 1, 7, 8, 9, 4, 0, 0];
+    assert_eq!(check(vec![0, 1, 2, 3, 4]), expected);
+    assert_eq!(check((0..5).collect::<VecDeque<_>>()), expected);
+    assert_eq!(check((0..5).collect::<LinkedList<_>>()), expected);
+
+    let mut items = vec![4, 3, 2, 1, 0];
+    let mut l = list::RevMut::new(&mut items);
+    l.rotate_left(2);
+    assert!(l.iter().eq(&[2, 3, 4, 0, 1]));
+}
+
+#[test]
+fn test_btree_map_cursor() {
+    use map::Ordered;
+
+    let mut map: BTreeMap<_, _> = (0..5).map(|i| (i * 2, i)).collect();
+
+    {
+        let mut cursor = map.lower_bound(&3);
+        assert_eq!(cursor.current(), Some((&4, &2)));
+        assert_eq!(cursor.peek_prev(), Some((&2, &1)));
+        cursor.move_next();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), Some((&0, &0)));
+    }
+
+    {
+        let mut cursor = map.cursor_front_mut();
+        cursor.move_prev();
+        cursor.move_prev();
+        *cursor.current_mut().unwrap().1 *= 10;
+        assert_eq!(cursor.remove_current(), Some((8, 40)));
+        assert_eq!(cursor.current(), None);
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some((0, 0)));
+        assert_eq!(cursor.current(), Some((&2, &1)));
+    }
+
+    assert_eq!(map.into_vec(), [(2, 1), (4, 2), (6, 3)]);
+}
+
+#[test]
+fn test_random_access_deque() {
+    fn check<D: RandomAccessDeque<Item = i32> + Mutate + AddRemove>(mut deque: D) -> Vec<i32> {
+        deque.push_front(-1);
+        deque.rotate_right(2);
+        for item in deque.range_mut(1..3) { *item *= 10; }
+        assert!(deque.range(2..4).eq(&[-10, 0]));
+        deque.into_vec()
+    }
+
+    assert_eq!(check((0..3).collect::<VecDeque<_>>()), [1, 20, -10, 0]);
+    assert_eq!(check((0..3).collect::<LinkedList<_>>()), [1, 20, -10, 0]);
+}
+
+#[test]
+fn test_entry() {
+    use std::rc::Rc;
+
+    fn check<M: map::Base<Key = Rc<str>, Value = i32> + AddRemove>(mut map: M) {
+        *map.entry("a".into()).and_modify(|value| *value += 1).or_default() += 1;
+        map.entry("a".into()).and_modify(|value| *value += 1).or_insert_with_key(|_| 5);
+        map.entry("b".into()).or_insert_with_key(|key| key.len() as i32);
+
+        let key: Rc<str> = "a".into();
+
+        match map.entry(key.clone()) {
+            map::Entry::Occupied(mut e) => {
+                assert_eq!(e.insert(10), 2);
+                assert!(!Rc::ptr_eq(e.key(), &key));
+                assert!(!Rc::ptr_eq(&e.replace_key(key.clone()), &key));
+            }
+            map::Entry::Vacant(_) => panic!("expected an occupied entry"),
+        }
+
+        match map.entry(key.clone()) {
+            map::Entry::Occupied(e) => {
+                assert!(Rc::ptr_eq(e.key(), &key));
+                assert_eq!(e.remove_entry().1, 10);
+            }
+            map::Entry::Vacant(_) => panic!("expected an occupied entry"),
+        }
+
+        match map.entry("c".into()) {
+            map::Entry::Occupied(_) => panic!("expected a vacant entry"),
+            map::Entry::Vacant(e) => assert_eq!(&*e.into_key(), "c"),
+        }
+
+        assert_eq!(map.into_vec(), [("b".into(), 1)]);
+    }
+
+    check(HashMap::new());
+    check(BTreeMap::new());
+}
+
+#[test]
+fn test_set_entry() {
+    use collections::SortedList;
+
+    fn check<S: set::Base<Item = i32> + AddRemove>(mut set: S) -> Vec<i32> {
+        set.entry(1).or_insert();
+        set.entry(3).or_insert();
+        assert_eq!(*set.entry(2).get(), 2);
+
+        match set.entry(1) {
+            set::Entry::Occupied(e) => assert_eq!(e.take(), 1),
+            set::Entry::Vacant(_) => panic!("expected an occupied entry"),
+        }
+
+        match set.entry(2) {
+            set::Entry::Occupied(_) => panic!("expected a vacant entry"),
+            set::Entry::Vacant(e) => e.insert(),
+        }
+
+        let mut items = set.into_vec();
+        items.sort();
+        items
+    }
+
+    // a set that uses the default `entry`
+    struct VecSet(Vec<i32>);
+
+    impl AddRemove for VecSet {}
+
+    impl Collection for VecSet {
+        type Item = i32;
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn capacity(&self) -> usize {
+            self.0.capacity()
+        }
+
+        fn extend_object(&mut self, items: &mut Iterator<Item = i32>) {
+            for item in items {
+                set::Base::insert(self, item);
+            }
+        }
+
+        fn drain<'a>(&'a mut self) -> Box<Iterator<Item = i32> + 'a> {
+            Box::new(self.0.drain(..))
+        }
+
+        fn reserve(&mut self, additional: usize) {
+            self.0.reserve(additional);
+        }
+
+        fn shrink_to_fit(&mut self) {
+            self.0.shrink_to_fit();
+        }
+
+        fn into_vec(self) -> Vec<i32> {
+            self.0
+        }
+    }
+
+    impl Iter for VecSet {
+        fn iter<'a>(&'a self) -> Box<Iterator<Item = &'a i32> + 'a> {
+            Box::new(self.0.iter())
+        }
+
+        fn iter_mut<'a>(&'a mut self) -> Box<Iterator<Item = &'a mut i32> + 'a> {
+            unreachable!()
+        }
+    }
+
+    impl set::Base for VecSet {
+        fn is_disjoint(&self, other: &Self) -> bool {
+            !self.0.iter().any(|item| other.0.contains(item))
+        }
+
+        fn is_subset(&self, other: &Self) -> bool {
+            self.0.iter().all(|item| other.0.contains(item))
+        }
+
+        fn insert(&mut self, item: i32) -> bool {
+            if self.0.contains(&item) { return false; }
+            self.0.push(item);
+            true
+        }
+
+        fn replace(&mut self, item: i32) -> Option<i32> {
+            let index = self.0.iter().position(|&stored| stored == item);
+
+            match index {
+                Some(index) => Some(mem::replace(&mut self.0[index], item)),
+                None => { self.0.push(item); None }
+            }
+        }
+    }
+
+    assert_eq!(check(HashSet::new()), [2, 3]);
+    assert_eq!(check(BTreeSet::new()), [2, 3]);
+    assert_eq!(check(SortedList::<VecDeque<_>>::default()), [2, 3]);
+    assert_eq!(check(VecSet(vec![])), [2, 3]);
+}
+
+#[test]
+fn test_map_lookups() {
+    use std::rc::Rc;
+
+    fn check<M: Map<str, Key = Rc<str>, Value = i32> + Mutate + AddRemove>(mut map: M) {
+        let a: Rc<str> = "a".into();
+        map.insert(a.clone(), 1);
+        map.insert("b".into(), 2);
+        assert!(Rc::ptr_eq(map.get_key_value("a").unwrap().0, &a));
+
+        {
+            let [a, b, c] = map.get_disjoint_mut(["a", "b", "c"]);
+            mem::swap(a.unwrap(), b.unwrap());
+            assert_eq!(c, None);
+        }
+
+        let b: Rc<str> = "b".into();
+        assert_eq!(map.replace(b.clone(), 3), Some(("b".into(), 1)));
+        assert!(Rc::ptr_eq(map.get_key_value("b").unwrap().0, &b));
+        assert_eq!(map.remove_entry("a"), Some((a, 2)));
+        assert_eq!(map.remove_entry("a"), None);
+    }
+
+    check(HashMap::new());
+    check(BTreeMap::new());
+}
+
+#[test]
+#[should_panic]
+fn test_map_get_disjoint_mut_duplicate() {
+    let mut map: BTreeMap<_, _> = vec![(1, 1)].into_iter().collect();
+    Map::get_disjoint_mut(&mut map, [&1, &1]);
+}
+
+#[test]
+fn test_text() {
+    fn check<T: ?Sized + Text + AddRemoveChars>(text: &mut T) {
+        text.push_str("!");
+        text.insert_char(0, '¡').unwrap();
+        assert_eq!(text.as_str(), "¡héllo!");
+
+        assert_eq!(text.insert_char(4, '?').err(), Some(CharBoundaryError::new(4)));
+        assert_eq!(text.drain_chars(2..4).err(), Some(CharBoundaryError::new(4)));
+        assert!(text.drain_chars(2..6).unwrap().eq("hél".chars()));
+        assert_eq!(text.remove_char(text.len() - 1), Ok('!'));
+        assert_eq!(text.remove_char(text.len()).err(), Some(CharBoundaryError::new(4)));
+        assert!(text.chars().eq("¡lo".chars()));
+    }
+
+    let mut text = String::from("héllo");
+    check(&mut text);
+    assert_eq!(text.into_vec(), "¡lo".as_bytes());
+    assert_eq!(algo::search::binary_search(&*"abc", &b'c'), Ok(2));
+}
+
+#[test]
+fn test_array() {
+    fn check<L: ?Sized + Contiguous + Mutate>(list: &mut L) where L::Item: Ord {
+        algo::sort::sort(list);
+        list.rotate_left(1);
+    }
+
+    let mut array = [3, 1, 2];
+    check(&mut array);
+    assert_eq!(array, [2, 3, 1]);
+    assert_eq!(array.into_vec(), [2, 3, 1]);
+
+    let mut boxed: Box<[_]> = Box::new([2, 1]);
+    check(&mut boxed);
+    assert_eq!(boxed.into_vec(), [2, 1]);
+}
+
+#[test]
+fn test_option() {
+    fn check<C: List<Item = i32> + Queue + AddRemove>(mut queue: C) -> Vec<i32> {
+        queue.extend_object(&mut vec![1].into_iter());
+        assert_eq!((queue.len(), queue.capacity()), (1, 1));
+        assert_eq!(queue.front(), Some(&1));
+        assert_eq!(queue.pop_front(), Some(1));
+        assert_eq!(queue.remove(0), None);
+        Queue::push(&mut queue, 2);
+        queue.into_vec()
+    }
+
+    assert_eq!(check(None), [2]);
+    assert_eq!(check(Box::new(None)), [2]);
+
+    let mut option = Some(1);
+    assert_eq!(List::split_off(&mut option, 1), None);
+    assert_eq!(List::split_off(&mut option, 0), Some(1));
+    assert_eq!(option, None);
+}
+
+#[test]
+#[should_panic]
+fn test_option_overflow() {
+    Queue::push(&mut Some(1), 2);
+}
@@ -4,6 +4,9 @@
 //!
 //! - [`Collection`]
 //!     - [`List`]
+//!         - [`Segmented`]
+//!             - [`Contiguous`]
+//!         - [`Text`]
 //!     - [`Map`]
 //!     - [`Set`]
 //!     - [`Queue`]
@@ -11,7 +14,9 @@
 //!         - [`PrioQueue`]
 //!     - [`Deque`]
 //!         - [`FifoDeque`]
+//!             - [`RandomAccessDeque`]
 //!         - [`PrioDeque`]
+//!     - [`RangeMapping`]
 //!
 //! When combined with these traits, two marker traits enable the use of additional operations:
 //!
@@ -21,6 +26,8 @@
 //! | [`Mutate`]    | Write access to a collection's items           | `&mut [T]`     |
 //! | [`AddRemove`] | Insertion and removal of a collection's items  | `&mut Vec<T>`  |
 //!
+//! Texts use a third marker, [`AddRemoveChars`], for the insertion and removal of `char`s.
+//!
 //! Generic code should specify only those bounds that are needed for its operation, but may
 //! specify additional bounds for future compatibility. Generic code should also use the collection
 //! traits with a `?Sized` bound in order to support slices and trait objects whenever possible.
@@ -56,7 +63,10 @@
 //! ```
 //!
 //! [`AddRemove`]: trait.AddRemove.html
+//! [`AddRemoveChars`]: trait.AddRemoveChars.html
 //! [`Collection`]: trait.Collection.html
+//! [`Collection::into_vec`]: trait.Collection.html#method.into_vec
+//! [`Contiguous`]: trait.Contiguous.html
 //! [`Deque`]: trait.Deque.html
 //! [`FifoDeque`]: trait.FifoDeque.html
 //! [`FifoQueue`]: trait.FifoQueue.html
@@ -66,7 +76,38 @@
 //! [`PrioDeque`]: trait.PrioDeque.html
 //! [`PrioQueue`]: trait.PrioQueue.html
 //! [`Queue`]: trait.Queue.html
+//! [`RandomAccessDeque`]: trait.RandomAccessDeque.html
+//! [`RangeMapping`]: trait.RangeMapping.html
+//! [`Segmented`]: trait.Segmented.html
 //! [`Set`]: set/trait.Set.html
+//! [`Text`]: trait.Text.html
+//!
+//! # Pointers to Collections
+//!
+//! `Box<C>` and `&mut C` implement the traits that `C` implements, including [`AddRemove`], when
+//! `C` implements `AddRemove`, so a `&mut Vec<T>` can be passed by value to code that takes an
+//! `L: List + AddRemove`. They do not implement [`Mutate`], because its methods cannot be
+//! forwarded without knowing that `C` implements it, and Rust does not allow a second forwarding
+//! implementation for collections that do. Code that mutates items should take a `&mut L` with an
+//! `L: ?Sized` bound instead, and be given the collection itself (e.g. `&mut *boxed`):
+//!
+//! ```
+//! use eclectic::algo::sort::sort;
+//!
+//! let mut boxed = Box::new(vec![3, 1, 2]);
+//! sort(&mut *boxed);
+//! assert_eq!(*boxed, [1, 2, 3]);
+//! ```
+//!
+//! `&mut [T]` and `Box<[T]>` are the exception, and implement `Mutate` like `[T]` does. `&C`,
+//! `Rc<C>` and `Arc<C>` implement the traits that `C` implements, but neither marker.
+//!
+//! Most of the collection traits have methods that require one of the markers, so they cannot be
+//! used as trait objects, and a `Box<List<Item = T>>` cannot be written. Generic code should take
+//! the collection by reference instead.
+//!
+//! **[`Collection::into_vec`] on a `&mut C` empties the collection that it refers to**, because
+//! the reference cannot take ownership of the collection's items; it drains them instead.
 //!
 //! # A Note on Trait Objects
 //!
@@ -88,11 +129,20 @@
 
 #![deny(missing_docs)]
 
+mod forward;
 mod impls;
 
+pub mod adapters;
+pub mod algo;
+pub mod collections;
+pub mod diff;
+pub mod list;
+
 pub use map::Map;
 pub use set::Set;
 
+use std::error::Error;
+use std::fmt;
 use std::ops::{Range, RangeFrom, RangeFull, RangeTo};
 
 /// A marker that indicates that a collection supports the mutation of its items.
@@ -102,6 +152,15 @@ pub trait Mutate {}
 /// of existing items.
 pub trait AddRemove {}
 
+/// A marker that indicates that a text supports the insertion of new `char`s and the removal of
+/// existing `char`s.
+///
+/// Texts implement this marker instead of [`AddRemove`], because inserting or removing
+/// individual bytes could produce invalid UTF-8.
+///
+/// [`AddRemove`]: trait.AddRemove.html
+pub trait AddRemoveChars {}
+
 /// A collection.
 ///
 /// A collection maintains a finite number of items.
@@ -149,6 +208,18 @@ pub trait Collection {
         self.drain();
     }
 
+    /// Removes all items from the collection for which the given predicate returns `false`.
+    ///
+    /// The predicate is called exactly once for each item. The order in which it is called is
+    /// unspecified, but subtraits may place a requirement on it.
+    ///
+    /// The default implementation drains the collection and reinserts the retained items, so
+    /// collections that can remove items in place should override it.
+    fn retain(&mut self, f: &mut FnMut(&Self::Item) -> bool) where Self: AddRemove {
+        let items: Vec<_> = self.drain().filter(|item| f(item)).collect();
+        self.extend_object(&mut items.into_iter());
+    }
+
     /// Removes all items from the collection and returns an iterator that yields them.
     ///
     /// All items are removed even if the iterator is not exhausted. However, the behavior of
@@ -161,6 +232,30 @@ pub trait Collection {
     /// [`mem::forget`]: https://doc.rust-lang.org/stable/std/mem/fn.forget.html
     fn drain<'a>(&'a mut self) -> Box<Iterator<Item = Self::Item> + 'a> where Self: AddRemove;
 
+    /// Removes all items from the collection for which the given predicate returns `true` and
+    /// returns an iterator that yields them.
+    ///
+    /// The items may be removed lazily, as the iterator is advanced. If the iterator is dropped
+    /// before it is exhausted, all items for which the predicate has not returned `true` are
+    /// retained, but it is unspecified whether the remaining matching items are removed. The
+    /// behavior of this method is unspecified if the iterator is leaked (e.g. via
+    /// [`mem::forget`]).
+    ///
+    /// The iteration order is unspecified, but subtraits may place a requirement on it.
+    ///
+    /// Like [`retain`], the default implementation drains the collection and reinserts the
+    /// retained items.
+    ///
+    /// [`mem::forget`]: https://doc.rust-lang.org/stable/std/mem/fn.forget.html
+    /// [`retain`]: #method.retain
+    fn extract_if<'a>(&'a mut self, pred: &'a mut FnMut(&Self::Item) -> bool)
+        -> Box<Iterator<Item = Self::Item> + 'a> where Self: AddRemove
+    {
+        let (extracted, retained): (Vec<_>, Vec<_>) = self.drain().partition(|item| pred(item));
+        self.extend_object(&mut retained.into_iter());
+        Box::new(extracted.into_iter())
+    }
+
     /// Reserves capacity for the given number of additional items to be inserted into the
     /// collection.
     ///
@@ -259,6 +354,38 @@ pub trait List:
         }
     }
 
+    /// Rotates the list in place such that the item at index `mid` becomes the first item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > self.len()`.
+    fn rotate_left(&mut self, mid: usize) where Self: Mutate {
+        let len = self.len();
+        assert!(mid <= len, "rotation amount out of bounds");
+
+        // reversing both sides of `mid` and then the whole list performs the rotation
+        for i in 0..mid / 2 {
+            self.swap(i, mid - i - 1);
+        }
+
+        for i in 0..(len - mid) / 2 {
+            self.swap(mid + i, len - i - 1);
+        }
+
+        self.reverse();
+    }
+
+    /// Rotates the list in place such that the last `k` items become the first items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k > self.len()`.
+    fn rotate_right(&mut self, k: usize) where Self: Mutate {
+        let len = self.len();
+        assert!(k <= len, "rotation amount out of bounds");
+        self.rotate_left(len - k);
+    }
+
     /// Returns a reference to the first item in the list.
     ///
     /// Returns `None` if the list is empty.
@@ -303,6 +430,50 @@ pub trait List:
     /// Panics if `index > self.len()`.
     fn insert(&mut self, index: usize, item: Self::Item) where Self: AddRemove;
 
+    /// Inserts the items yielded by the given iterator into the list at the given index, in
+    /// order.
+    ///
+    /// All items after the given index are shifted to the right by the number of inserted items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > self.len()`.
+    fn insert_many(&mut self, index: usize, items: &mut Iterator<Item = Self::Item>)
+        where Self: AddRemove
+    {
+        for (i, item) in items.enumerate() {
+            self.insert(index + i, item);
+        }
+    }
+
+    /// Replaces the items in the given range of the list with the items yielded by the given
+    /// iterator, and returns an iterator that yields the removed items.
+    ///
+    /// The range is replaced even if the returned iterator is not exhausted. However, the behavior
+    /// of this method is unspecified if the iterator is leaked (e.g. via [`mem::forget`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.start > range.end || range.end > self.len()`.
+    ///
+    /// [`mem::forget`]: https://doc.rust-lang.org/stable/std/mem/fn.forget.html
+    fn splice<'a>(&'a mut self, range: Range<usize>, items: &'a mut Iterator<Item = Self::Item>)
+        -> Box<Iterator<Item = Self::Item> + 'a> where Self: AddRemove
+    {
+        let index = range.start;
+        let removed: Vec<_> = self.drain_range(range).collect();
+        self.insert_many(index, items);
+        Box::new(removed.into_iter())
+    }
+
+    /// Pushes clones of the items in the given list onto the back of the list, in order.
+    fn extend_from_list<L: ?Sized + List<Item = Self::Item>>(&mut self, other: &L)
+        where Self: Sized + AddRemove, Self::Item: Clone
+    {
+        let len = self.len();
+        self.insert_many(len, &mut other.iter().cloned());
+    }
+
     /// Removes the last item in the list and returns it.
     ///
     /// Returns `None` if the list was empty.
@@ -332,7 +503,29 @@ pub trait List:
         if len == 0 {
             self.clear();
         } else {
-            self.drain_range(len..);
+            drop(self.drain_range(len..));
+        }
+    }
+
+    /// Resizes the list to the given length.
+    ///
+    /// If `len > self.len()`, clones of the given item are pushed onto the back of the list.
+    /// Otherwise, the list is truncated.
+    fn resize(&mut self, len: usize, item: Self::Item) where Self: AddRemove, Self::Item: Clone {
+        self.resize_with(len, &mut || item.clone());
+    }
+
+    /// Resizes the list to the given length.
+    ///
+    /// If `len > self.len()`, items returned by the given function are pushed onto the back of
+    /// the list. Otherwise, the list is truncated.
+    fn resize_with(&mut self, len: usize, f: &mut FnMut() -> Self::Item) where Self: AddRemove {
+        if len > self.len() {
+            for _ in self.len()..len {
+                self.push(f());
+            }
+        } else {
+            self.truncate(len);
         }
     }
 
@@ -351,6 +544,116 @@ pub trait List:
         let _ = index;
         unimplemented!()
     }
+
+    /// Removes consecutive repeated items from the list.
+    ///
+    /// If the list is sorted, this removes all duplicates.
+    fn dedup(&mut self) where Self: AddRemove, Self::Item: PartialEq {
+        self.dedup_by(&mut |a, b| a == b);
+    }
+
+    /// Removes consecutive items from the list that are equivalent according to the given
+    /// function.
+    ///
+    /// The function is called with each item and the last retained item before it, in that order,
+    /// and the former is removed if the function returns `true`.
+    fn dedup_by(&mut self, same_bucket: &mut FnMut(&mut Self::Item, &mut Self::Item) -> bool)
+        where Self: AddRemove
+    {
+        let mut items: Vec<_> = self.drain_range(..).collect();
+        items.dedup_by(|a, b| same_bucket(a, b));
+        self.extend_object(&mut items.into_iter());
+    }
+
+    /// Removes consecutive items from the list that map to equal keys.
+    fn dedup_by_key<K: PartialEq, F>(&mut self, mut key: F)
+        where Self: Sized + AddRemove, F: FnMut(&mut Self::Item) -> K
+    {
+        self.dedup_by(&mut |a, b| key(a) == key(b));
+    }
+
+    /// Returns a view of the items in the given range of the list.
+    ///
+    /// Unsized lists can be viewed with [`SubList::new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.start > range.end || range.end > self.len()`.
+    ///
+    /// [`SubList::new`]: list/struct.SubList.html#method.new
+    fn slice<'a>(&'a self, range: Range<usize>) -> list::SubList<'a, Self> where Self: Sized {
+        list::SubList::new(self, range)
+    }
+
+    /// Returns a mutable view of the items in the given range of the list.
+    ///
+    /// Unsized lists can be viewed with [`SubListMut::new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.start > range.end || range.end > self.len()`.
+    ///
+    /// [`SubListMut::new`]: list/struct.SubListMut.html#method.new
+    fn slice_mut<'a>(&'a mut self, range: Range<usize>) -> list::SubListMut<'a, Self>
+        where Self: Sized + Mutate
+    {
+        list::SubListMut::new(self, range)
+    }
+
+    /// Returns a slice that contains the list's items, if they are stored contiguously.
+    ///
+    /// Generic algorithms may use this method to delegate to slice routines. Returns `None` by
+    /// default, but lists that implement [`Contiguous`] should override it.
+    ///
+    /// [`Contiguous`]: trait.Contiguous.html
+    fn contiguous(&self) -> Option<&[Self::Item]> {
+        None
+    }
+
+    /// Returns a mutable slice that contains the list's items, if they are stored contiguously.
+    ///
+    /// Generic algorithms may use this method to delegate to slice routines. Implementations may
+    /// rearrange the list's storage in order to make it contiguous, but must not change the order
+    /// of its items. Returns `None` by default.
+    fn contiguous_mut(&mut self) -> Option<&mut [Self::Item]> where Self: Mutate {
+        None
+    }
+
+    /// Returns a cursor that points at the first item in the list.
+    ///
+    /// If the list is empty, the cursor is at the ghost position.
+    fn cursor_front<'a>(&'a self) -> Box<list::Cursor<Item = Self::Item> + 'a> {
+        Box::new(list::IndexCursor::new(self, 0))
+    }
+
+    /// Returns a cursor that points at the last item in the list.
+    ///
+    /// If the list is empty, the cursor is at the ghost position.
+    fn cursor_back<'a>(&'a self) -> Box<list::Cursor<Item = Self::Item> + 'a> {
+        let index = self.len().saturating_sub(1);
+        Box::new(list::IndexCursor::new(self, index))
+    }
+
+    /// Returns a cursor that points at the first item in the list and supports editing the list.
+    ///
+    /// If the list is empty, the cursor is at the ghost position.
+    fn cursor_front_mut<'a>(&'a mut self)
+        -> Box<list::CursorMut<Item = Self::Item, List = Self> + 'a>
+        where Self: Sized + Mutate + AddRemove
+    {
+        Box::new(list::IndexCursorMut::new(self, 0))
+    }
+
+    /// Returns a cursor that points at the last item in the list and supports editing the list.
+    ///
+    /// If the list is empty, the cursor is at the ghost position.
+    fn cursor_back_mut<'a>(&'a mut self)
+        -> Box<list::CursorMut<Item = Self::Item, List = Self> + 'a>
+        where Self: Sized + Mutate + AddRemove
+    {
+        let index = self.len().saturating_sub(1);
+        Box::new(list::IndexCursorMut::new(self, index))
+    }
 }
 
 impl<L: ?Sized + List> DrainRange<RangeFrom<usize>> for L {
@@ -378,9 +681,158 @@ impl<L: ?Sized + List> DrainRange<RangeFull> for L {
     }
 }
 
+/// A list whose items are stored in at most two contiguous slices.
+///
+/// Algorithms can use this interface to operate on the underlying storage of lists like ring
+/// buffers directly.
+pub trait Segmented: List {
+    /// Returns a pair of slices that contain the list's items, in order.
+    ///
+    /// The first slice contains the items at the front of the list and the second slice contains
+    /// the remaining items. Either slice may be empty.
+    fn as_slices(&self) -> (&[Self::Item], &[Self::Item]);
+
+    /// Returns a pair of mutable slices that contain the list's items, in order.
+    ///
+    /// The first slice contains the items at the front of the list and the second slice contains
+    /// the remaining items. Either slice may be empty.
+    fn as_mut_slices(&mut self) -> (&mut [Self::Item], &mut [Self::Item]) where Self: Mutate;
+
+    /// Rearranges the list's items so that they are stored in a single contiguous slice, and
+    /// returns that slice.
+    ///
+    /// After this method returns, the first slice returned by [`as_slices`] contains all of the
+    /// list's items.
+    ///
+    /// [`as_slices`]: #tymethod.as_slices
+    fn make_contiguous(&mut self) -> &mut [Self::Item] where Self: Mutate;
+}
+
+/// A list whose items are stored contiguously.
+///
+/// Algorithms can use this interface to delegate to slice routines.
+pub trait Contiguous: Segmented {
+    /// Returns a slice that contains the list's items.
+    fn as_slice(&self) -> &[Self::Item];
+
+    /// Returns a mutable slice that contains the list's items.
+    fn as_mut_slice(&mut self) -> &mut [Self::Item] where Self: Mutate;
+}
+
+/// A list of bytes that form a valid UTF-8 string.
+///
+/// Text implements the collection traits with `u8` items, so that generic code can borrow the
+/// bytes by reference (which is not possible for `char`s, as they are not stored directly). The
+/// methods of this trait operate on `char`s instead, and check that the given indices lie on
+/// `char` boundaries.
+///
+/// Text implements neither [`Mutate`] nor [`AddRemove`], because writing, inserting or removing
+/// individual bytes could produce invalid UTF-8. A text that can grow and shrink implements
+/// [`AddRemoveChars`] instead, which enables the methods of this trait that insert and remove
+/// `char`s.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::{List, Text};
+///
+/// let mut text = String::from("naïve");
+/// assert_eq!(text.get(0), Some(&b'n'));
+///
+/// assert!(text.insert_char(3, 'é').is_err());
+/// assert_eq!(text.remove_char(2), Ok('ï'));
+///
+/// Text::insert_str(&mut text, 2, "i").unwrap();
+/// assert_eq!(text, "naive");
+/// ```
+///
+/// [`AddRemove`]: trait.AddRemove.html
+/// [`AddRemoveChars`]: trait.AddRemoveChars.html
+/// [`Mutate`]: trait.Mutate.html
+pub trait Text: List<Item = u8> {
+    /// Returns a string slice that contains the text.
+    fn as_str(&self) -> &str;
+
+    /// Checks if the given index is the first byte of a `char` or the end of the text.
+    fn is_char_boundary(&self, index: usize) -> bool {
+        self.as_str().is_char_boundary(index)
+    }
+
+    /// Returns an iterator that yields the text's `char`s.
+    fn chars<'a>(&'a self) -> Box<Iterator<Item = char> + 'a> {
+        Box::new(self.as_str().chars())
+    }
+
+    /// Inserts the given `char` into the text at the given byte index.
+    ///
+    /// Returns an error if `index` is not a `char` boundary.
+    fn insert_char(&mut self, index: usize, ch: char) -> Result<(), CharBoundaryError>
+        where Self: AddRemoveChars
+    {
+        self.insert_str(index, ch.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Inserts the given string into the text at the given byte index.
+    ///
+    /// Returns an error if `index` is not a `char` boundary.
+    fn insert_str(&mut self, index: usize, s: &str) -> Result<(), CharBoundaryError>
+        where Self: AddRemoveChars;
+
+    /// Pushes the given string onto the back of the text.
+    fn push_str(&mut self, s: &str) where Self: AddRemoveChars {
+        let len = self.len();
+        self.insert_str(len, s).expect("end of text is not a char boundary");
+    }
+
+    /// Removes the `char` that starts at the given byte index and returns it.
+    ///
+    /// Returns an error if `index` is not a `char` boundary or if `index == self.len()`.
+    fn remove_char(&mut self, index: usize) -> Result<char, CharBoundaryError>
+        where Self: AddRemoveChars;
+
+    /// Removes the `char`s in the given byte range from the text and returns an iterator that
+    /// yields them.
+    ///
+    /// Returns an error if either end of the range is not a `char` boundary. The range is removed
+    /// even if the iterator is not exhausted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.start > range.end`.
+    fn drain_chars<'a>(&'a mut self, range: Range<usize>)
+        -> Result<Box<Iterator<Item = char> + 'a>, CharBoundaryError> where Self: AddRemoveChars;
+}
+
+/// An error indicating that a byte index does not lie on a `char` boundary.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CharBoundaryError {
+    index: usize,
+}
+
+impl CharBoundaryError {
+    /// Returns a new error for the given byte index.
+    pub fn new(index: usize) -> Self {
+        CharBoundaryError { index: index }
+    }
+
+    /// Returns the byte index that does not lie on a `char` boundary.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for CharBoundaryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "byte index {} is not a char boundary", self.index)
+    }
+}
+
+impl Error for CharBoundaryError {}
+
 pub mod map {
     //! Maps.
 
+    use std::mem;
     use super::*;
 
     /// Map functionality that is independent of an additional type parameter.
@@ -424,6 +876,73 @@ pub mod map {
         /// Returns the entry in the map for the given key.
         fn entry<'a>(&'a mut self, key: Self::Key) -> Entry<'a, Self::Key, Self::Value>
             where Self: AddRemove;
+
+        /// Inserts the given key and value into the map, replacing an equivalent key.
+        ///
+        /// Unlike [`insert`], this replaces both the key and the value if the map contains a key
+        /// that is equivalent to the given key.
+        ///
+        /// Returns the equivalent key and its value if the map contained one, `None` otherwise.
+        ///
+        /// The default implementation removes the equivalent key by comparing the given key with
+        /// each of the map's keys and then inserts the given key, so maps should override it with
+        /// one that uses their own lookup.
+        ///
+        /// [`insert`]: #tymethod.insert
+        fn replace(&mut self, key: Self::Key, value: Self::Value)
+            -> Option<(Self::Key, Self::Value)> where Self: AddRemove, Self::Key: Eq
+        {
+            let entry = self.extract_if_mut(&mut |stored, _| *stored == key).next();
+            self.insert(key, value);
+            entry
+        }
+
+        /// Removes all entries from the map for which the given predicate returns `false`.
+        ///
+        /// Unlike [`Collection::retain`], the predicate is given mutable access to each value.
+        /// Maps should prefer this method, because a map that stores its keys and values
+        /// separately can only give [`Collection::retain`]'s predicate a reference to an entry by
+        /// removing the entry from the map first.
+        ///
+        /// [`Collection::retain`]: ../trait.Collection.html#method.retain
+        fn retain_mut(&mut self, f: &mut FnMut(&Self::Key, &mut Self::Value) -> bool)
+            where Self: AddRemove
+        {
+            let mut entries = vec![];
+
+            for (key, mut value) in self.drain() {
+                if f(&key, &mut value) { entries.push((key, value)); }
+            }
+
+            self.extend_object(&mut entries.into_iter());
+        }
+
+        /// Removes all entries from the map for which the given predicate returns `true` and
+        /// returns an iterator that yields them.
+        ///
+        /// This method behaves like [`Collection::extract_if`], except that the predicate is
+        /// given mutable access to each value. Maps should prefer it for the same reason as
+        /// [`retain_mut`].
+        ///
+        /// [`Collection::extract_if`]: ../trait.Collection.html#method.extract_if
+        /// [`retain_mut`]: #method.retain_mut
+        fn extract_if_mut<'a>(&'a mut self, f: &'a mut FnMut(&Self::Key, &mut Self::Value) -> bool)
+            -> Box<Iterator<Item = (Self::Key, Self::Value)> + 'a> where Self: AddRemove
+        {
+            let mut extracted = vec![];
+            let mut retained = vec![];
+
+            for (key, mut value) in self.drain() {
+                if f(&key, &mut value) {
+                    extracted.push((key, value));
+                } else {
+                    retained.push((key, value));
+                }
+            }
+
+            self.extend_object(&mut retained.into_iter());
+            Box::new(extracted.into_iter())
+        }
     }
 
     /// A map.
@@ -445,16 +964,203 @@ pub mod map {
         /// Returns `None` if the map contains no such key.
         fn get(&self, key: &Q) -> Option<&Self::Value>;
 
+        /// Returns references to the key in the map that is equivalent to the given key and to its
+        /// value.
+        ///
+        /// Returns `None` if the map contains no such key.
+        fn get_key_value(&self, key: &Q) -> Option<(&Self::Key, &Self::Value)>;
+
         /// Returns a mutable reference to the value of the key in the map that is equivalent to
         /// the given key.
         ///
         /// Returns `None` if the map contains no such key.
         fn get_mut(&mut self, key: &Q) -> Option<&mut Self::Value> where Self: Mutate;
 
+        /// Returns mutable references to the values of the keys in the map that are equivalent to
+        /// the given keys.
+        ///
+        /// Each reference is `None` if the map contains no key that is equivalent to the
+        /// corresponding given key.
+        ///
+        /// The default implementation looks up each key, then collects the values by iterating
+        /// over the map with [`Base::iter_mut`] until it has found all of them, so it can take time
+        /// proportional to the map's length. Maps that can hand out several mutable references
+        /// from their lookups (e.g. `HashMap`) should override it.
+        ///
+        /// # Panics
+        ///
+        /// Panics if two of the given keys are equivalent to the same key in the map.
+        ///
+        /// [`Base::iter_mut`]: trait.Base.html#tymethod.iter_mut
+        fn get_disjoint_mut<const N: usize>(&mut self, keys: [&Q; N])
+            -> [Option<&mut Self::Value>; N] where Self: Sized + Mutate
+        {
+            let ptrs = keys.map(|key| self.get_key_value(key).map(|(key, _)| key as *const _));
+
+            for i in 0..N {
+                for j in 0..i {
+                    assert!(ptrs[i].is_none() || ptrs[i] != ptrs[j], "duplicate keys");
+                }
+            }
+
+            // the keys' addresses identify the entries, so the mutable references can be obtained
+            // without any unsafe code
+            let mut values = [(); N].map(|_| None);
+            let mut missing = ptrs.iter().filter(|ptr| ptr.is_some()).count();
+
+            for (key, value) in Base::iter_mut(self) {
+                if missing == 0 { break; }
+
+                let ptr = Some(key as *const _);
+
+                if let Some(i) = ptrs.iter().position(|&p| p == ptr) {
+                    values[i] = Some(value);
+                    missing -= 1;
+                }
+            }
+
+            values
+        }
+
         /// Removes the key in the map that is equivalent to the given key and returns its value.
         ///
         /// Returns `None` if the map contained no such key.
         fn remove(&mut self, key: &Q) -> Option<Self::Value> where Self: AddRemove;
+
+        /// Removes the key in the map that is equivalent to the given key and returns it and its
+        /// value.
+        ///
+        /// Returns `None` if the map contained no such key.
+        fn remove_entry(&mut self, key: &Q) -> Option<(Self::Key, Self::Value)>
+            where Self: AddRemove;
+    }
+
+    /// A map whose keys are ordered.
+    ///
+    /// The iteration methods of an ordered map (e.g. [`Base::iter`]) yield its entries in
+    /// ascending order of their keys.
+    ///
+    /// [`Base::iter`]: trait.Base.html#tymethod.iter
+    pub trait Ordered: Base {
+        /// Returns a cursor that points at the entry with the smallest key in the map.
+        ///
+        /// If the map is empty, the cursor is at the ghost position.
+        fn cursor_front<'a>(&'a self) -> Box<Cursor<Key = Self::Key, Value = Self::Value> + 'a>;
+
+        /// Returns a cursor that points at the entry with the smallest key in the map that is not
+        /// less than the given key.
+        ///
+        /// If the map contains no such entry, the cursor is at the ghost position.
+        fn lower_bound<'a>(&'a self, key: &Self::Key)
+            -> Box<Cursor<Key = Self::Key, Value = Self::Value> + 'a>;
+
+        /// Returns a cursor that points at the entry with the smallest key in the map and
+        /// supports editing the map.
+        ///
+        /// If the map is empty, the cursor is at the ghost position.
+        ///
+        /// Mutable cursors may need to copy keys in order to locate entries after the map has been
+        /// modified, so they require the keys to implement `Clone`.
+        fn cursor_front_mut<'a>(&'a mut self)
+            -> Box<CursorMut<Key = Self::Key, Value = Self::Value> + 'a>
+            where Self: Mutate + AddRemove, Self::Key: Clone;
+
+        /// Returns a cursor that points at the entry with the smallest key in the map that is not
+        /// less than the given key and supports editing the map.
+        ///
+        /// If the map contains no such entry, the cursor is at the ghost position.
+        ///
+        /// Mutable cursors may need to copy keys in order to locate entries after the map has been
+        /// modified, so they require the keys to implement `Clone`.
+        fn lower_bound_mut<'a>(&'a mut self, key: &Self::Key)
+            -> Box<CursorMut<Key = Self::Key, Value = Self::Value> + 'a>
+            where Self: Mutate + AddRemove, Self::Key: Clone;
+    }
+
+    /// A map whose keys are sequences and that supports queries by prefix.
+    ///
+    /// A key is a prefix of another key if the other key starts with its items. Every key is a
+    /// prefix of itself, and the empty key is a prefix of every key.
+    ///
+    /// The type parameter `Q` has the same meaning as in [`Map`].
+    ///
+    /// [`Map`]: trait.Map.html
+    pub trait PrefixMap<Q: ?Sized = <Self as Base>::Key>: Map<Q> {
+        /// Returns an iterator that yields references to the keys in the map that start with the
+        /// given prefix and references to their values.
+        ///
+        /// The iteration order is unspecified, but subtraits may place a requirement on it.
+        fn iter_prefix<'a>(&'a self, prefix: &Q)
+            -> Box<Iterator<Item = (&'a Self::Key, &'a Self::Value)> + 'a>;
+
+        /// Returns references to the longest key in the map that is a prefix of the given key and
+        /// to its value.
+        ///
+        /// Returns `None` if the map contains no such key.
+        fn longest_prefix_match(&self, key: &Q) -> Option<(&Self::Key, &Self::Value)>;
+
+        /// Removes all keys from the map that start with the given prefix and returns an iterator
+        /// that yields them and their values.
+        ///
+        /// All such keys are removed even if the iterator is not exhausted. However, the behavior
+        /// of this method is unspecified if the iterator is leaked (e.g. via [`mem::forget`]).
+        ///
+        /// The iteration order is unspecified, but subtraits may place a requirement on it.
+        ///
+        /// [`mem::forget`]: https://doc.rust-lang.org/stable/std/mem/fn.forget.html
+        fn remove_prefix<'a>(&'a mut self, prefix: &Q)
+            -> Box<Iterator<Item = (Self::Key, Self::Value)> + 'a> where Self: AddRemove;
+    }
+
+    /// A cursor over an ordered map.
+    ///
+    /// A cursor points at an entry in a map, or at a "ghost" position between the entries with
+    /// the largest and smallest keys. Moving past either end of the map moves the cursor to the
+    /// ghost position, and moving from the ghost position wraps around to the other end.
+    pub trait Cursor {
+        /// The type of the map's keys.
+        type Key;
+
+        /// The type of the map's values.
+        type Value;
+
+        /// Moves the cursor to the entry with the next larger key.
+        fn move_next(&mut self);
+
+        /// Moves the cursor to the entry with the next smaller key.
+        fn move_prev(&mut self);
+
+        /// Returns references to the key and value of the entry that the cursor points at.
+        ///
+        /// Returns `None` if the cursor is at the ghost position.
+        fn current(&self) -> Option<(&Self::Key, &Self::Value)>;
+
+        /// Returns references to the key and value of the entry after the one that the cursor
+        /// points at.
+        ///
+        /// Returns `None` if there is no such entry.
+        fn peek_next(&self) -> Option<(&Self::Key, &Self::Value)>;
+
+        /// Returns references to the key and value of the entry before the one that the cursor
+        /// points at.
+        ///
+        /// Returns `None` if there is no such entry.
+        fn peek_prev(&self) -> Option<(&Self::Key, &Self::Value)>;
+    }
+
+    /// A cursor over an ordered map that supports editing the map.
+    pub trait CursorMut: Cursor {
+        /// Returns a reference to the key and a mutable reference to the value of the entry that
+        /// the cursor points at.
+        ///
+        /// Returns `None` if the cursor is at the ghost position.
+        fn current_mut(&mut self) -> Option<(&Self::Key, &mut Self::Value)>;
+
+        /// Removes the entry that the cursor points at and returns its key and value.
+        ///
+        /// The cursor is moved to the next entry. Returns `None` if the cursor was at the ghost
+        /// position.
+        fn remove_current(&mut self) -> Option<(Self::Key, Self::Value)>;
     }
 
     /// A map entry.
@@ -487,6 +1193,53 @@ pub mod map {
                 Entry::Vacant(e) => e.insert(f()),
             }
         }
+
+        /// Ensures that the entry is occupied by inserting it into the map with the result of the
+        /// given function if it is vacant.
+        ///
+        /// The function is given a reference to the entry's key. Returns a mutable reference to
+        /// the entry's value with the same lifetime as the map.
+        pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, f: F) -> &'a mut V {
+            match self {
+                Entry::Occupied(e) => e.into_mut(),
+                Entry::Vacant(e) => {
+                    let value = f(e.key());
+                    e.insert(value)
+                }
+            }
+        }
+
+        /// Ensures that the entry is occupied by inserting it into the map with the default value
+        /// if it is vacant.
+        ///
+        /// Returns a mutable reference to the entry's value with the same lifetime as the map.
+        pub fn or_default(self) -> &'a mut V where V: Default {
+            self.or_insert_with(V::default)
+        }
+
+        /// Calls the given function with a mutable reference to the entry's value if it is
+        /// occupied.
+        ///
+        /// Returns the entry, so that this method can be chained with e.g. [`or_insert`].
+        ///
+        /// [`or_insert`]: #method.or_insert
+        pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+            match self {
+                Entry::Occupied(mut e) => {
+                    f(e.get_mut());
+                    Entry::Occupied(e)
+                }
+                Entry::Vacant(e) => Entry::Vacant(e),
+            }
+        }
+
+        /// Returns a reference to the entry's key.
+        pub fn key(&self) -> &K {
+            match *self {
+                Entry::Occupied(ref e) => e.key(),
+                Entry::Vacant(ref e) => e.key(),
+            }
+        }
     }
 
     /// An occupied map entry.
@@ -501,6 +1254,12 @@ pub mod map {
         /// map.
         type MutValue;
 
+        /// Returns a reference to the entry's key.
+        ///
+        /// This is the key that is stored in the map, not the key that was used to look up the
+        /// entry.
+        fn key(&self) -> &Self::Key;
+
         /// Returns a reference to the entry's value.
         fn get(&self) -> &Self::Value;
 
@@ -510,8 +1269,25 @@ pub mod map {
         /// Returns a mutable reference to the entry's value with the same lifetime as the map.
         fn into_mut(self: Box<Self>) -> Self::MutValue;
 
+        /// Replaces the entry's value with the given value and returns the old value.
+        fn insert(&mut self, value: Self::Value) -> Self::Value {
+            mem::replace(self.get_mut(), value)
+        }
+
         /// Removes the entry from the map and returns its value.
         fn remove(self: Box<Self>) -> Self::Value;
+
+        /// Removes the entry from the map and returns its key and value.
+        fn remove_entry(self: Box<Self>) -> (Self::Key, Self::Value);
+
+        /// Replaces the entry's key with the given key and returns the old key.
+        ///
+        /// The given key should be equivalent to the entry's key. This is useful for replacing a
+        /// key with one that shares storage with other data (e.g. when interning), because
+        /// [`Base::insert`] does not replace existing keys.
+        ///
+        /// [`Base::insert`]: trait.Base.html#tymethod.insert
+        fn replace_key(self: Box<Self>, key: Self::Key) -> Self::Key;
     }
 
     /// A vacant entry.
@@ -526,6 +1302,12 @@ pub mod map {
         /// map.
         type MutValue;
 
+        /// Returns a reference to the entry's key.
+        fn key(&self) -> &Self::Key;
+
+        /// Returns the entry's key without inserting the entry into the map.
+        fn into_key(self: Box<Self>) -> Self::Key;
+
         /// Inserts the entry into the map with the given value.
         ///
         /// Returns a mutable reference to the entry's value with the same lifetime as the map.
@@ -581,6 +1363,23 @@ pub mod set {
         /// Returns the item that was replaced, or `None` if the set did not contain an equivalent
         /// item.
         fn replace(&mut self, item: Self::Item) -> Option<Self::Item> where Self: AddRemove;
+
+        /// Returns the entry in the set for the given item.
+        ///
+        /// The entry is occupied if the set contains an item that is equivalent to the given item,
+        /// and vacant otherwise.
+        ///
+        /// The default implementation finds the item by comparing it with each of the set's items,
+        /// so sets should override it with one that uses their own lookup.
+        fn entry<'a>(&'a mut self, item: Self::Item) -> Entry<'a, Self::Item>
+            where Self: AddRemove, Self::Item: Eq
+        {
+            if self.iter().any(|stored| *stored == item) {
+                Entry::Occupied(Box::new(DefaultOccupiedEntry { set: self, item: item }))
+            } else {
+                Entry::Vacant(Box::new(DefaultVacantEntry { set: self, item: item }))
+            }
+        }
     }
 
     /// A set.
@@ -613,6 +1412,115 @@ pub mod set {
         /// Returns `None` if the set contained no such item.
         fn take(&mut self, item: &Q) -> Option<Self::Item> where Self: AddRemove;
     }
+
+    /// A set entry.
+    ///
+    /// An entry does not necessarily save a lookup. The standard library's sets, for example, look
+    /// the item up again when their entries are used.
+    pub enum Entry<'a, T: 'a> {
+        /// An occupied set entry.
+        Occupied(Box<OccupiedEntry<Item = T> + 'a>),
+        /// A vacant set entry.
+        Vacant(Box<VacantEntry<Item = T> + 'a>),
+    }
+
+    impl<'a, T: 'a> Entry<'a, T> {
+        /// Returns a reference to the entry's item.
+        ///
+        /// For an occupied entry, this is the item that is stored in the set. For a vacant entry,
+        /// this is the item that would be inserted into the set.
+        pub fn get(&self) -> &T {
+            match *self {
+                Entry::Occupied(ref e) => e.get(),
+                Entry::Vacant(ref e) => e.get(),
+            }
+        }
+
+        /// Ensures that the entry is occupied by inserting its item into the set if it is vacant.
+        pub fn or_insert(self) {
+            if let Entry::Vacant(e) = self {
+                e.insert();
+            }
+        }
+    }
+
+    /// An occupied set entry.
+    pub trait OccupiedEntry {
+        /// The type of the entry's item.
+        type Item;
+
+        /// Returns a reference to the item that is stored in the set.
+        fn get(&self) -> &Self::Item;
+
+        /// Removes the entry's item from the set and returns it.
+        fn take(self: Box<Self>) -> Self::Item;
+    }
+
+    /// A vacant set entry.
+    pub trait VacantEntry {
+        /// The type of the entry's item.
+        type Item;
+
+        /// Returns a reference to the item that would be inserted into the set.
+        fn get(&self) -> &Self::Item;
+
+        /// Returns the entry's item without inserting it into the set.
+        fn into_item(self: Box<Self>) -> Self::Item;
+
+        /// Inserts the entry's item into the set.
+        fn insert(self: Box<Self>);
+    }
+
+    /// The occupied entry that is returned by [`Base::entry`]'s default implementation.
+    ///
+    /// [`Base::entry`]: trait.Base.html#method.entry
+    struct DefaultOccupiedEntry<'a, S: 'a + ?Sized + Base> {
+        set: &'a mut S,
+        item: S::Item,
+    }
+
+    impl<'a, S: ?Sized + Base + AddRemove> OccupiedEntry for DefaultOccupiedEntry<'a, S>
+        where S::Item: Eq
+    {
+        type Item = S::Item;
+
+        fn get(&self) -> &S::Item {
+            self.set.iter().find(|stored| **stored == self.item)
+                .expect("occupied entry's item is missing from the set")
+        }
+
+        fn take(self: Box<Self>) -> S::Item {
+            let this = *self;
+            let item = this.item;
+            let taken = this.set.extract_if(&mut |stored| *stored == item).next();
+            taken.expect("occupied entry's item is missing from the set")
+        }
+    }
+
+    /// The vacant entry that is returned by [`Base::entry`]'s default implementation.
+    ///
+    /// [`Base::entry`]: trait.Base.html#method.entry
+    struct DefaultVacantEntry<'a, S: 'a + ?Sized + Base> {
+        set: &'a mut S,
+        item: S::Item,
+    }
+
+    impl<'a, S: ?Sized + Base + AddRemove> VacantEntry for DefaultVacantEntry<'a, S> {
+        type Item = S::Item;
+
+        fn get(&self) -> &S::Item {
+            &self.item
+        }
+
+        fn into_item(self: Box<Self>) -> S::Item {
+            self.item
+        }
+
+        fn insert(self: Box<Self>) {
+            let this = *self;
+            this.set.insert(this.item);
+        }
+    }
 }
 
 /// A queue.
@@ -689,6 +1597,40 @@ pub trait FifoDeque: FifoQueue + Deque {
     fn back_mut(&mut self) -> Option<&mut Self::Item> where Self: Mutate;
 }
 
+/// A double-ended first-in, first-out queue that supports access to the items between its ends.
+///
+/// The items are indexed from the front of the deque, and the operations of [`List`] (e.g.
+/// [`List::get`] and [`List::rotate_left`]) are available for them.
+///
+/// [`List`]: trait.List.html
+/// [`List::get`]: trait.List.html#tymethod.get
+/// [`List::rotate_left`]: trait.List.html#method.rotate_left
+pub trait RandomAccessDeque: FifoDeque + List {
+    /// Returns an iterator that yields references to the items in the given range of the deque,
+    /// from front to back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.start > range.end || range.end > self.len()`.
+    fn range<'a>(&'a self, range: Range<usize>) -> Box<Iterator<Item = &'a Self::Item> + 'a> {
+        assert!(range.start <= range.end && range.end <= self.len(), "range out of bounds");
+        Box::new(self.iter().skip(range.start).take(range.end - range.start))
+    }
+
+    /// Returns an iterator that yields mutable references to the items in the given range of the
+    /// deque, from front to back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.start > range.end || range.end > self.len()`.
+    fn range_mut<'a>(&'a mut self, range: Range<usize>)
+        -> Box<Iterator<Item = &'a mut Self::Item> + 'a> where Self: Mutate
+    {
+        assert!(range.start <= range.end && range.end <= self.len(), "range out of bounds");
+        Box::new(self.iter_mut().skip(range.start).take(range.end - range.start))
+    }
+}
+
 /// A double-ended priority queue.
 pub trait PrioDeque: PrioQueue + Deque {
     /// Pushes the given item onto the deque, then removes the item at the back of the deque and
@@ -712,27 +1654,59 @@ pub trait PrioDeque: PrioQueue + Deque {
     }
 }
 
+/// A collection of non-overlapping ranges, each of which is associated with a value.
+///
+/// The ranges are half-open, and empty ranges are never stored. Implementations may split and
+/// coalesce the stored ranges as they are inserted and removed, so the ranges that a collection
+/// yields need not be the ones that were inserted into it.
+pub trait RangeMapping: Collection {
+    /// The type of the ranges' bounds.
+    type Key;
+
+    /// The type of the values that are associated with the ranges.
+    type Value;
+
+    /// Associates the keys in the given range with the given value.
+    ///
+    /// The keys are first removed from any stored ranges that contain them. Does nothing if the
+    /// range is empty.
+    fn insert_range(&mut self, range: Range<Self::Key>, value: Self::Value) where Self: AddRemove;
+
+    /// Removes the keys in the given range from the stored ranges that contain them.
+    fn remove_range(&mut self, range: Range<Self::Key>) where Self: AddRemove;
+
+    /// Returns references to the stored range that contains the given key and to its value.
+    ///
+    /// Returns `None` if no stored range contains the key.
+    fn get_containing(&self, key: &Self::Key) -> Option<(&Range<Self::Key>, &Self::Value)>;
+
+    /// Returns an iterator that yields references to the stored ranges that overlap the given
+    /// range and to their values, in ascending order.
+    fn overlapping<'a>(&'a self, range: Range<Self::Key>)
+        -> Box<Iterator<Item = (&'a Range<Self::Key>, &'a Self::Value)> + 'a>;
+
+    /// Returns an iterator that yields the maximal subranges of the given range that overlap no
+    /// stored range, in ascending order.
+    fn gaps<'a>(&'a self, range: Range<Self::Key>) -> Box<Iterator<Item = Range<Self::Key>> + 'a>;
+}
+
+// The collection traits gate their methods with `where Self: AddRemove` and `where Self: Mutate`
+// clauses, which current compilers reject in dyn-compatible traits, so only the markers and the
+// cursor and entry traits are checked here.
 #[allow(dead_code)]
 fn assert_object_safe() {
     let _: &Mutate;
     let _: &AddRemove;
+    let _: &AddRemoveChars;
 
-    let _: &Collection<Item = String>;
-
-    let _: &List<Item = String>;
+    let _: &list::Cursor<Item = String>;
+    let _: &list::CursorMut<Item = String, List = Vec<String>>;
 
-    let _: &Map<str, Item = (String, i32), Key = String, Value = i32>;
+    let _: &map::Cursor<Key = String, Value = i32>;
+    let _: &map::CursorMut<Key = String, Value = i32>;
     let _: &map::OccupiedEntry<Key = String, Value = i32, MutValue = &mut i32>;
     let _: &map::VacantEntry<Key = String, Value = i32, MutValue = &mut i32>;
 
-    let _: &Set<str, Item = String>;
-
-    let _: &Queue<Item = String>;
-    let _: &Deque<Item = String>;
-
-    let _: &FifoQueue<Item = String>;
-    let _: &FifoDeque<Item = String>;
-
-    let _: &PrioQueue<Item = String>;
-    let _: &PrioDeque<Item = String>;
+    let _: &set::OccupiedEntry<Item = String>;
+    let _: &set::VacantEntry<Item = String>;
 }
@@ -0,0 +1,1303 @@
+//! List views and cursors.
+//!
+//! A view implements [`List`] in terms of one or more underlying lists, translating indices as
+//! necessary. Like [`SubList`] and [`SubListMut`], each of the composite views comes in two
+//! forms: an immutable view ([`Rev`], [`Chain`] and [`StepBy`]) that borrows any list, and a
+//! mutable view ([`RevMut`], [`ChainMut`] and [`StepByMut`]) that borrows a list that implements
+//! [`Mutate`], and implements it in turn. A single view could not do both, because it could not
+//! forward the methods that require `Self: Mutate` without knowing that the underlying list
+//! implements `Mutate`.
+//!
+//! A [`Cursor`] points at a position in a list and can be moved back and forth, while a
+//! [`CursorMut`] can also insert and remove items at that position. Lists that support efficient
+//! editing in the middle (e.g. `LinkedList`) provide mutable cursors that perform each operation
+//! in constant time, and other lists fall back to an [`IndexCursor`] or [`IndexCursorMut`].
+//!
+//! [`Chain`]: struct.Chain.html
+//! [`ChainMut`]: struct.ChainMut.html
+//! [`Cursor`]: trait.Cursor.html
+//! [`CursorMut`]: trait.CursorMut.html
+//! [`IndexCursor`]: struct.IndexCursor.html
+//! [`IndexCursorMut`]: struct.IndexCursorMut.html
+//! [`List`]: ../trait.List.html
+//! [`Mutate`]: ../trait.Mutate.html
+//! [`Rev`]: struct.Rev.html
+//! [`RevMut`]: struct.RevMut.html
+//! [`StepBy`]: struct.StepBy.html
+//! [`StepByMut`]: struct.StepByMut.html
+//! [`SubList`]: struct.SubList.html
+//! [`SubListMut`]: struct.SubListMut.html
+
+use std::mem;
+use std::ops::Range;
+use super::*;
+
+/// Returns the length of the given range after checking that it lies within a list of the given
+/// length.
+fn check_range(range: &Range<usize>, len: usize) -> usize {
+    assert!(range.start <= range.end, "range start {} exceeds range end {}", range.start,
+            range.end);
+    assert!(range.end <= len, "range end {} exceeds list length {}", range.end, len);
+    range.end - range.start
+}
+
+/// An immutable view of a range of a list's items.
+///
+/// Indices into the view are translated into indices into the underlying list, so a sub-list
+/// behaves like a list that contains only the items in the range.
+///
+/// This type is usually created by [`List::slice`], but can also be created by [`SubList::new`]
+/// for unsized lists.
+///
+/// [`List::slice`]: ../trait.List.html#method.slice
+/// [`SubList::new`]: #method.new
+pub struct SubList<'a, L: 'a + ?Sized + List> {
+    list: &'a L,
+    start: usize,
+    len: usize,
+}
+
+impl<'a, L: ?Sized + List> SubList<'a, L> {
+    /// Returns a view of the items in the given range of the given list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.start > range.end || range.end > list.len()`.
+    pub fn new(list: &'a L, range: Range<usize>) -> Self {
+        let len = check_range(&range, list.len());
+        SubList { list: list, start: range.start, len: len }
+    }
+}
+
+impl<'a, L: ?Sized + List> Clone for SubList<'a, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, L: ?Sized + List> Copy for SubList<'a, L> {}
+
+impl<'a, L: ?Sized + List> Collection for SubList<'a, L> {
+    type Item = L::Item;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn capacity(&self) -> usize {
+        self.len
+    }
+
+    fn extend_object(&mut self, _items: &mut Iterator<Item = L::Item>) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn drain<'b>(&'b mut self) -> Box<Iterator<Item = L::Item> + 'b> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn reserve(&mut self, _additional: usize) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn shrink_to_fit(&mut self) where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+impl<'a, L: ?Sized + List> Iter for SubList<'a, L> {
+    fn iter<'b>(&'b self) -> Box<Iterator<Item = &'b L::Item> + 'b> {
+        Box::new(self.list.iter().skip(self.start).take(self.len))
+    }
+
+    fn iter_mut<'b>(&'b mut self) -> Box<Iterator<Item = &'b mut L::Item> + 'b>
+        where Self: Mutate
+    {
+        unimplemented!()
+    }
+}
+
+impl<'a, L: ?Sized + List> DrainRange<Range<usize>> for SubList<'a, L> {
+    fn drain_range<'b>(&'b mut self, _range: Range<usize>)
+        -> Box<Iterator<Item = L::Item> + 'b> where Self: AddRemove
+    {
+        unimplemented!()
+    }
+}
+
+impl<'a, L: ?Sized + List> List for SubList<'a, L> {
+    fn get(&self, index: usize) -> Option<&L::Item> {
+        if index < self.len { self.list.get(self.start + index) } else { None }
+    }
+
+    fn get_mut(&mut self, _index: usize) -> Option<&mut L::Item> where Self: Mutate {
+        unimplemented!()
+    }
+
+    fn swap(&mut self, _i: usize, _j: usize) where Self: Mutate {
+        unimplemented!()
+    }
+
+    fn contiguous(&self) -> Option<&[L::Item]> {
+        self.list.contiguous().map(|slice| &slice[self.start..self.start + self.len])
+    }
+
+    fn insert(&mut self, _index: usize, _item: L::Item) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn remove(&mut self, _index: usize) -> Option<L::Item> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn swap_remove(&mut self, _index: usize) -> Option<L::Item> where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+/// A mutable view of a range of a list's items.
+///
+/// Indices into the view are translated into indices into the underlying list, so a sub-list
+/// behaves like a list that contains only the items in the range. The view supports the mutation
+/// of its items, but not the insertion or removal of items.
+///
+/// This type is usually created by [`List::slice_mut`], but can also be created by
+/// [`SubListMut::new`] for unsized lists.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::{List, Mutate};
+/// use std::collections::LinkedList;
+///
+/// fn insertion_sort<L: ?Sized + List + Mutate>(list: &mut L) where L::Item: Ord {
+///     for i in 1..list.len() {
+///         let mut j = i;
+///
+///         while j > 0 && list.get(j) < list.get(j - 1) {
+///             list.swap(j, j - 1);
+///             j -= 1;
+///         }
+///     }
+/// }
+///
+/// let mut list: LinkedList<_> = vec![5, 4, 3, 2, 1].into_iter().collect();
+/// insertion_sort(&mut list.slice_mut(1..4));
+/// assert!(list.iter().eq(&[5, 2, 3, 4, 1]));
+/// ```
+///
+/// [`List::slice_mut`]: ../trait.List.html#method.slice_mut
+/// [`SubListMut::new`]: #method.new
+pub struct SubListMut<'a, L: 'a + ?Sized + List + Mutate> {
+    list: &'a mut L,
+    start: usize,
+    len: usize,
+}
+
+impl<'a, L: ?Sized + List + Mutate> SubListMut<'a, L> {
+    /// Returns a mutable view of the items in the given range of the given list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.start > range.end || range.end > list.len()`.
+    pub fn new(list: &'a mut L, range: Range<usize>) -> Self {
+        let len = check_range(&range, list.len());
+        SubListMut { list: list, start: range.start, len: len }
+    }
+}
+
+impl<'a, L: ?Sized + List + Mutate> Mutate for SubListMut<'a, L> {}
+
+impl<'a, L: ?Sized + List + Mutate> Collection for SubListMut<'a, L> {
+    type Item = L::Item;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn capacity(&self) -> usize {
+        self.len
+    }
+
+    fn extend_object(&mut self, _items: &mut Iterator<Item = L::Item>) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn drain<'b>(&'b mut self) -> Box<Iterator<Item = L::Item> + 'b> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn reserve(&mut self, _additional: usize) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn shrink_to_fit(&mut self) where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+impl<'a, L: ?Sized + List + Mutate> Iter for SubListMut<'a, L> {
+    fn iter<'b>(&'b self) -> Box<Iterator<Item = &'b L::Item> + 'b> {
+        Box::new(self.list.iter().skip(self.start).take(self.len))
+    }
+
+    fn iter_mut<'b>(&'b mut self) -> Box<Iterator<Item = &'b mut L::Item> + 'b> {
+        Box::new(self.list.iter_mut().skip(self.start).take(self.len))
+    }
+}
+
+impl<'a, L: ?Sized + List + Mutate> DrainRange<Range<usize>> for SubListMut<'a, L> {
+    fn drain_range<'b>(&'b mut self, _range: Range<usize>)
+        -> Box<Iterator<Item = L::Item> + 'b> where Self: AddRemove
+    {
+        unimplemented!()
+    }
+}
+
+impl<'a, L: ?Sized + List + Mutate> List for SubListMut<'a, L> {
+    fn get(&self, index: usize) -> Option<&L::Item> {
+        if index < self.len { self.list.get(self.start + index) } else { None }
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut L::Item> {
+        if index < self.len { self.list.get_mut(self.start + index) } else { None }
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        assert!(i < self.len && j < self.len, "index out of bounds");
+        self.list.swap(self.start + i, self.start + j);
+    }
+
+    fn contiguous(&self) -> Option<&[L::Item]> {
+        self.list.contiguous().map(|slice| &slice[self.start..self.start + self.len])
+    }
+
+    fn contiguous_mut(&mut self) -> Option<&mut [L::Item]> {
+        let range = self.start..self.start + self.len;
+        self.list.contiguous_mut().map(|slice| &mut slice[range])
+    }
+
+    fn insert(&mut self, _index: usize, _item: L::Item) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn remove(&mut self, _index: usize) -> Option<L::Item> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn swap_remove(&mut self, _index: usize) -> Option<L::Item> where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+/// Returns an iterator that yields the given list's items in reverse order.
+///
+/// The collection traits only iterate forwards, so unless the list is contiguous, references to
+/// its items are collected before the first one is yielded.
+fn rev_iter<'a, L: ?Sized + List>(list: &'a L) -> Box<Iterator<Item = &'a L::Item> + 'a> {
+    match list.contiguous() {
+        Some(slice) => Box::new(slice.iter().rev()),
+        None => Box::new(list.iter().collect::<Vec<_>>().into_iter().rev()),
+    }
+}
+
+/// An immutable view of a list's items in reverse order.
+///
+/// The item at index `i` in the view is the item at index `len - 1 - i` in the underlying list.
+/// Iterating over the view of a list that is not contiguous collects references to the list's
+/// items first.
+pub struct Rev<'a, L: 'a + ?Sized + List> {
+    list: &'a L,
+}
+
+impl<'a, L: ?Sized + List> Rev<'a, L> {
+    /// Returns a view of the given list's items in reverse order.
+    pub fn new(list: &'a L) -> Self {
+        Rev { list: list }
+    }
+}
+
+impl<'a, L: ?Sized + List> Clone for Rev<'a, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, L: ?Sized + List> Copy for Rev<'a, L> {}
+
+impl<'a, L: ?Sized + List> Collection for Rev<'a, L> {
+    type Item = L::Item;
+
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.list.len()
+    }
+
+    fn extend_object(&mut self, _items: &mut Iterator<Item = L::Item>) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn drain<'b>(&'b mut self) -> Box<Iterator<Item = L::Item> + 'b> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn reserve(&mut self, _additional: usize) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn shrink_to_fit(&mut self) where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+impl<'a, L: ?Sized + List> Iter for Rev<'a, L> {
+    fn iter<'b>(&'b self) -> Box<Iterator<Item = &'b L::Item> + 'b> {
+        rev_iter(self.list)
+    }
+
+    fn iter_mut<'b>(&'b mut self) -> Box<Iterator<Item = &'b mut L::Item> + 'b>
+        where Self: Mutate
+    {
+        unimplemented!()
+    }
+}
+
+impl<'a, L: ?Sized + List> DrainRange<Range<usize>> for Rev<'a, L> {
+    fn drain_range<'b>(&'b mut self, _range: Range<usize>)
+        -> Box<Iterator<Item = L::Item> + 'b> where Self: AddRemove
+    {
+        unimplemented!()
+    }
+}
+
+impl<'a, L: ?Sized + List> List for Rev<'a, L> {
+    fn get(&self, index: usize) -> Option<&L::Item> {
+        let len = self.list.len();
+        if index < len { self.list.get(len - 1 - index) } else { None }
+    }
+
+    fn get_mut(&mut self, _index: usize) -> Option<&mut L::Item> where Self: Mutate {
+        unimplemented!()
+    }
+
+    fn swap(&mut self, _i: usize, _j: usize) where Self: Mutate {
+        unimplemented!()
+    }
+
+    fn first(&self) -> Option<&L::Item> {
+        self.list.last()
+    }
+
+    fn last(&self) -> Option<&L::Item> {
+        self.list.first()
+    }
+
+    fn insert(&mut self, _index: usize, _item: L::Item) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn remove(&mut self, _index: usize) -> Option<L::Item> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn swap_remove(&mut self, _index: usize) -> Option<L::Item> where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+/// A mutable view of a list's items in reverse order.
+///
+/// The item at index `i` in the view is the item at index `len - 1 - i` in the underlying list.
+/// Iterating over the view of a list that is not contiguous collects references to the list's
+/// items first.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::algo::sort::sort;
+/// use eclectic::list::RevMut;
+///
+/// let mut list = vec![2, 3, 1];
+/// sort(&mut RevMut::new(&mut list));
+/// assert_eq!(list, [3, 2, 1]);
+/// ```
+pub struct RevMut<'a, L: 'a + ?Sized + List + Mutate> {
+    list: &'a mut L,
+}
+
+impl<'a, L: ?Sized + List + Mutate> RevMut<'a, L> {
+    /// Returns a mutable view of the given list's items in reverse order.
+    pub fn new(list: &'a mut L) -> Self {
+        RevMut { list: list }
+    }
+}
+
+impl<'a, L: ?Sized + List + Mutate> Mutate for RevMut<'a, L> {}
+
+impl<'a, L: ?Sized + List + Mutate> Collection for RevMut<'a, L> {
+    type Item = L::Item;
+
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.list.len()
+    }
+
+    fn extend_object(&mut self, _items: &mut Iterator<Item = L::Item>) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn drain<'b>(&'b mut self) -> Box<Iterator<Item = L::Item> + 'b> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn reserve(&mut self, _additional: usize) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn shrink_to_fit(&mut self) where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+impl<'a, L: ?Sized + List + Mutate> Iter for RevMut<'a, L> {
+    fn iter<'b>(&'b self) -> Box<Iterator<Item = &'b L::Item> + 'b> {
+        rev_iter(&*self.list)
+    }
+
+    fn iter_mut<'b>(&'b mut self) -> Box<Iterator<Item = &'b mut L::Item> + 'b> {
+        // the slice is requested twice, because returning it from a `match` would keep the list
+        // borrowed in the fallback
+        if self.list.contiguous_mut().is_some() {
+            let slice = self.list.contiguous_mut().expect("list is not contiguous");
+            return Box::new(slice.iter_mut().rev());
+        }
+
+        Box::new(self.list.iter_mut().collect::<Vec<_>>().into_iter().rev())
+    }
+}
+
+impl<'a, L: ?Sized + List + Mutate> DrainRange<Range<usize>> for RevMut<'a, L> {
+    fn drain_range<'b>(&'b mut self, _range: Range<usize>)
+        -> Box<Iterator<Item = L::Item> + 'b> where Self: AddRemove
+    {
+        unimplemented!()
+    }
+}
+
+impl<'a, L: ?Sized + List + Mutate> List for RevMut<'a, L> {
+    fn get(&self, index: usize) -> Option<&L::Item> {
+        let len = self.list.len();
+        if index < len { self.list.get(len - 1 - index) } else { None }
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut L::Item> {
+        let len = self.list.len();
+        if index < len { self.list.get_mut(len - 1 - index) } else { None }
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        let len = self.list.len();
+        assert!(i < len && j < len, "index out of bounds");
+        self.list.swap(len - 1 - i, len - 1 - j);
+    }
+
+    fn reverse(&mut self) {
+        self.list.reverse();
+    }
+
+    fn first(&self) -> Option<&L::Item> {
+        self.list.last()
+    }
+
+    fn first_mut(&mut self) -> Option<&mut L::Item> {
+        self.list.last_mut()
+    }
+
+    fn last(&self) -> Option<&L::Item> {
+        self.list.first()
+    }
+
+    fn last_mut(&mut self) -> Option<&mut L::Item> {
+        self.list.first_mut()
+    }
+
+    fn insert(&mut self, _index: usize, _item: L::Item) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn remove(&mut self, _index: usize) -> Option<L::Item> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn swap_remove(&mut self, _index: usize) -> Option<L::Item> where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+/// An immutable view of the concatenation of two lists.
+///
+/// The view contains the items of the first list followed by the items of the second list.
+pub struct Chain<'a, A: 'a + ?Sized + List, B: 'a + ?Sized + List<Item = A::Item>> {
+    a: &'a A,
+    b: &'a B,
+}
+
+impl<'a, A: ?Sized + List, B: ?Sized + List<Item = A::Item>> Chain<'a, A, B> {
+    /// Returns a view of the concatenation of the given lists.
+    pub fn new(a: &'a A, b: &'a B) -> Self {
+        Chain { a: a, b: b }
+    }
+}
+
+impl<'a, A: ?Sized + List, B: ?Sized + List<Item = A::Item>> Clone for Chain<'a, A, B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, A: ?Sized + List, B: ?Sized + List<Item = A::Item>> Copy for Chain<'a, A, B> {}
+
+impl<'a, A: ?Sized + List, B: ?Sized + List<Item = A::Item>> Collection for Chain<'a, A, B> {
+    type Item = A::Item;
+
+    fn len(&self) -> usize {
+        self.a.len() + self.b.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+
+    fn extend_object(&mut self, _items: &mut Iterator<Item = A::Item>) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn drain<'b>(&'b mut self) -> Box<Iterator<Item = A::Item> + 'b> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn reserve(&mut self, _additional: usize) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn shrink_to_fit(&mut self) where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+impl<'a, A: ?Sized + List, B: ?Sized + List<Item = A::Item>> Iter for Chain<'a, A, B> {
+    fn iter<'b>(&'b self) -> Box<Iterator<Item = &'b A::Item> + 'b> {
+        Box::new(self.a.iter().chain(self.b.iter()))
+    }
+
+    fn iter_mut<'b>(&'b mut self) -> Box<Iterator<Item = &'b mut A::Item> + 'b>
+        where Self: Mutate
+    {
+        unimplemented!()
+    }
+}
+
+impl<'a, A: ?Sized + List, B: ?Sized + List<Item = A::Item>> DrainRange<Range<usize>>
+    for Chain<'a, A, B>
+{
+    fn drain_range<'b>(&'b mut self, _range: Range<usize>)
+        -> Box<Iterator<Item = A::Item> + 'b> where Self: AddRemove
+    {
+        unimplemented!()
+    }
+}
+
+impl<'a, A: ?Sized + List, B: ?Sized + List<Item = A::Item>> List for Chain<'a, A, B> {
+    fn get(&self, index: usize) -> Option<&A::Item> {
+        let len = self.a.len();
+        if index < len { self.a.get(index) } else { self.b.get(index - len) }
+    }
+
+    fn get_mut(&mut self, _index: usize) -> Option<&mut A::Item> where Self: Mutate {
+        unimplemented!()
+    }
+
+    fn swap(&mut self, _i: usize, _j: usize) where Self: Mutate {
+        unimplemented!()
+    }
+
+    fn insert(&mut self, _index: usize, _item: A::Item) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn remove(&mut self, _index: usize) -> Option<A::Item> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn swap_remove(&mut self, _index: usize) -> Option<A::Item> where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+/// A mutable view of the concatenation of two lists.
+///
+/// The view contains the items of the first list followed by the items of the second list.
+///
+/// # Examples
+///
+/// ```
+/// use eclectic::List;
+/// use eclectic::list::ChainMut;
+/// use std::collections::VecDeque;
+///
+/// let mut front = vec![3, 5, 1];
+/// let mut back: VecDeque<_> = vec![4, 2].into_iter().collect();
+///
+/// ChainMut::new(&mut front, &mut back).swap(0, 4);
+/// assert_eq!(front, [2, 5, 1]);
+/// assert!(back.iter().eq(&[4, 3]));
+/// ```
+pub struct ChainMut<'a, A, B>
+    where A: 'a + ?Sized + List + Mutate, B: 'a + ?Sized + List<Item = A::Item> + Mutate
+{
+    a: &'a mut A,
+    b: &'a mut B,
+}
+
+impl<'a, A, B> ChainMut<'a, A, B>
+    where A: ?Sized + List + Mutate, B: ?Sized + List<Item = A::Item> + Mutate
+{
+    /// Returns a mutable view of the concatenation of the given lists.
+    pub fn new(a: &'a mut A, b: &'a mut B) -> Self {
+        ChainMut { a: a, b: b }
+    }
+}
+
+impl<'a, A, B> Mutate for ChainMut<'a, A, B>
+    where A: ?Sized + List + Mutate, B: ?Sized + List<Item = A::Item> + Mutate {}
+
+impl<'a, A, B> Collection for ChainMut<'a, A, B>
+    where A: ?Sized + List + Mutate, B: ?Sized + List<Item = A::Item> + Mutate
+{
+    type Item = A::Item;
+
+    fn len(&self) -> usize {
+        self.a.len() + self.b.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+
+    fn extend_object(&mut self, _items: &mut Iterator<Item = A::Item>) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn drain<'b>(&'b mut self) -> Box<Iterator<Item = A::Item> + 'b> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn reserve(&mut self, _additional: usize) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn shrink_to_fit(&mut self) where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+impl<'a, A, B> Iter for ChainMut<'a, A, B>
+    where A: ?Sized + List + Mutate, B: ?Sized + List<Item = A::Item> + Mutate
+{
+    fn iter<'b>(&'b self) -> Box<Iterator<Item = &'b A::Item> + 'b> {
+        Box::new(self.a.iter().chain(self.b.iter()))
+    }
+
+    fn iter_mut<'b>(&'b mut self) -> Box<Iterator<Item = &'b mut A::Item> + 'b> {
+        Box::new(self.a.iter_mut().chain(self.b.iter_mut()))
+    }
+}
+
+impl<'a, A, B> DrainRange<Range<usize>> for ChainMut<'a, A, B>
+    where A: ?Sized + List + Mutate, B: ?Sized + List<Item = A::Item> + Mutate
+{
+    fn drain_range<'b>(&'b mut self, _range: Range<usize>)
+        -> Box<Iterator<Item = A::Item> + 'b> where Self: AddRemove
+    {
+        unimplemented!()
+    }
+}
+
+impl<'a, A, B> List for ChainMut<'a, A, B>
+    where A: ?Sized + List + Mutate, B: ?Sized + List<Item = A::Item> + Mutate
+{
+    fn get(&self, index: usize) -> Option<&A::Item> {
+        let len = self.a.len();
+        if index < len { self.a.get(index) } else { self.b.get(index - len) }
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut A::Item> {
+        let len = self.a.len();
+        if index < len { self.a.get_mut(index) } else { self.b.get_mut(index - len) }
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        assert!(i < self.len() && j < self.len(), "index out of bounds");
+
+        let len = self.a.len();
+
+        match (i < len, j < len) {
+            (true, true) => self.a.swap(i, j),
+            (false, false) => self.b.swap(i - len, j - len),
+            (true, false) => mem::swap(
+                self.a.get_mut(i).expect("index out of bounds"),
+                self.b.get_mut(j - len).expect("index out of bounds"),
+            ),
+            (false, true) => mem::swap(
+                self.a.get_mut(j).expect("index out of bounds"),
+                self.b.get_mut(i - len).expect("index out of bounds"),
+            ),
+        }
+    }
+
+    fn insert(&mut self, _index: usize, _item: A::Item) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn remove(&mut self, _index: usize) -> Option<A::Item> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn swap_remove(&mut self, _index: usize) -> Option<A::Item> where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+/// Returns the number of items in a view of every `step`-th item in a list of the given length.
+fn step_len(len: usize, step: usize) -> usize {
+    (len + step - 1) / step
+}
+
+/// An immutable view of every `step`-th item in a list, starting with the first.
+///
+/// The item at index `i` in the view is the item at index `i * step` in the underlying list.
+pub struct StepBy<'a, L: 'a + ?Sized + List> {
+    list: &'a L,
+    step: usize,
+}
+
+impl<'a, L: ?Sized + List> StepBy<'a, L> {
+    /// Returns a view of every `step`-th item in the given list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step == 0`.
+    pub fn new(list: &'a L, step: usize) -> Self {
+        assert!(step != 0, "step must be non-zero");
+        StepBy { list: list, step: step }
+    }
+}
+
+impl<'a, L: ?Sized + List> Clone for StepBy<'a, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, L: ?Sized + List> Copy for StepBy<'a, L> {}
+
+impl<'a, L: ?Sized + List> Collection for StepBy<'a, L> {
+    type Item = L::Item;
+
+    fn len(&self) -> usize {
+        step_len(self.list.len(), self.step)
+    }
+
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+
+    fn extend_object(&mut self, _items: &mut Iterator<Item = L::Item>) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn drain<'b>(&'b mut self) -> Box<Iterator<Item = L::Item> + 'b> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn reserve(&mut self, _additional: usize) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn shrink_to_fit(&mut self) where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+impl<'a, L: ?Sized + List> Iter for StepBy<'a, L> {
+    fn iter<'b>(&'b self) -> Box<Iterator<Item = &'b L::Item> + 'b> {
+        Box::new(self.list.iter().step_by(self.step))
+    }
+
+    fn iter_mut<'b>(&'b mut self) -> Box<Iterator<Item = &'b mut L::Item> + 'b>
+        where Self: Mutate
+    {
+        unimplemented!()
+    }
+}
+
+impl<'a, L: ?Sized + List> DrainRange<Range<usize>> for StepBy<'a, L> {
+    fn drain_range<'b>(&'b mut self, _range: Range<usize>)
+        -> Box<Iterator<Item = L::Item> + 'b> where Self: AddRemove
+    {
+        unimplemented!()
+    }
+}
+
+impl<'a, L: ?Sized + List> List for StepBy<'a, L> {
+    fn get(&self, index: usize) -> Option<&L::Item> {
+        if index < self.len() { self.list.get(index * self.step) } else { None }
+    }
+
+    fn get_mut(&mut self, _index: usize) -> Option<&mut L::Item> where Self: Mutate {
+        unimplemented!()
+    }
+
+    fn swap(&mut self, _i: usize, _j: usize) where Self: Mutate {
+        unimplemented!()
+    }
+
+    fn insert(&mut self, _index: usize, _item: L::Item) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn remove(&mut self, _index: usize) -> Option<L::Item> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn swap_remove(&mut self, _index: usize) -> Option<L::Item> where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+/// A mutable view of every `step`-th item in a list, starting with the first.
+///
+/// The item at index `i` in the view is the item at index `i * step` in the underlying list.
+pub struct StepByMut<'a, L: 'a + ?Sized + List + Mutate> {
+    list: &'a mut L,
+    step: usize,
+}
+
+impl<'a, L: ?Sized + List + Mutate> StepByMut<'a, L> {
+    /// Returns a mutable view of every `step`-th item in the given list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step == 0`.
+    pub fn new(list: &'a mut L, step: usize) -> Self {
+        assert!(step != 0, "step must be non-zero");
+        StepByMut { list: list, step: step }
+    }
+}
+
+impl<'a, L: ?Sized + List + Mutate> Mutate for StepByMut<'a, L> {}
+
+impl<'a, L: ?Sized + List + Mutate> Collection for StepByMut<'a, L> {
+    type Item = L::Item;
+
+    fn len(&self) -> usize {
+        step_len(self.list.len(), self.step)
+    }
+
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+
+    fn extend_object(&mut self, _items: &mut Iterator<Item = L::Item>) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn drain<'b>(&'b mut self) -> Box<Iterator<Item = L::Item> + 'b> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn reserve(&mut self, _additional: usize) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn shrink_to_fit(&mut self) where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+impl<'a, L: ?Sized + List + Mutate> Iter for StepByMut<'a, L> {
+    fn iter<'b>(&'b self) -> Box<Iterator<Item = &'b L::Item> + 'b> {
+        Box::new(self.list.iter().step_by(self.step))
+    }
+
+    fn iter_mut<'b>(&'b mut self) -> Box<Iterator<Item = &'b mut L::Item> + 'b> {
+        Box::new(self.list.iter_mut().step_by(self.step))
+    }
+}
+
+impl<'a, L: ?Sized + List + Mutate> DrainRange<Range<usize>> for StepByMut<'a, L> {
+    fn drain_range<'b>(&'b mut self, _range: Range<usize>)
+        -> Box<Iterator<Item = L::Item> + 'b> where Self: AddRemove
+    {
+        unimplemented!()
+    }
+}
+
+impl<'a, L: ?Sized + List + Mutate> List for StepByMut<'a, L> {
+    fn get(&self, index: usize) -> Option<&L::Item> {
+        if index < self.len() { self.list.get(index * self.step) } else { None }
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut L::Item> {
+        if index < self.len() { self.list.get_mut(index * self.step) } else { None }
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        assert!(i < self.len() && j < self.len(), "index out of bounds");
+        self.list.swap(i * self.step, j * self.step);
+    }
+
+    fn insert(&mut self, _index: usize, _item: L::Item) where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn remove(&mut self, _index: usize) -> Option<L::Item> where Self: AddRemove {
+        unimplemented!()
+    }
+
+    fn swap_remove(&mut self, _index: usize) -> Option<L::Item> where Self: AddRemove {
+        unimplemented!()
+    }
+}
+
+/// A cursor over a list.
+///
+/// A cursor points at an item in a list, or at a "ghost" position between the list's last and
+/// first items. Moving past either end of the list moves the cursor to the ghost position, and
+/// moving from the ghost position wraps around to the other end.
+///
+/// Cursors are usually created by [`List::cursor_front`] and [`List::cursor_back`].
+///
+/// [`List::cursor_back`]: ../trait.List.html#method.cursor_back
+/// [`List::cursor_front`]: ../trait.List.html#method.cursor_front
+pub trait Cursor {
+    /// The type of the list's items.
+    type Item;
+
+    /// Returns the index of the item that the cursor points at.
+    ///
+    /// Returns `None` if the cursor is at the ghost position.
+    fn index(&self) -> Option<usize>;
+
+    /// Moves the cursor to the next item in the list.
+    fn move_next(&mut self);
+
+    /// Moves the cursor to the previous item in the list.
+    fn move_prev(&mut self);
+
+    /// Returns a reference to the item that the cursor points at.
+    ///
+    /// Returns `None` if the cursor is at the ghost position.
+    fn current(&self) -> Option<&Self::Item>;
+
+    /// Returns a reference to the item after the one that the cursor points at.
+    ///
+    /// Returns `None` if there is no such item.
+    fn peek_next(&self) -> Option<&Self::Item>;
+
+    /// Returns a reference to the item before the one that the cursor points at.
+    ///
+    /// Returns `None` if there is no such item.
+    fn peek_prev(&self) -> Option<&Self::Item>;
+}
+
+/// A cursor over a list that supports editing the list.
+///
+/// Cursors of this kind are usually created by [`List::cursor_front_mut`] and
+/// [`List::cursor_back_mut`].
+///
+/// [`List::cursor_back_mut`]: ../trait.List.html#method.cursor_back_mut
+/// [`List::cursor_front_mut`]: ../trait.List.html#method.cursor_front_mut
+pub trait CursorMut: Cursor {
+    /// The type of the list.
+    type List;
+
+    /// Returns a mutable reference to the item that the cursor points at.
+    ///
+    /// Returns `None` if the cursor is at the ghost position.
+    fn current_mut(&mut self) -> Option<&mut Self::Item>;
+
+    /// Inserts the given item into the list before the one that the cursor points at.
+    ///
+    /// If the cursor is at the ghost position, the item is inserted at the back of the list.
+    fn insert_before(&mut self, item: Self::Item);
+
+    /// Inserts the given item into the list after the one that the cursor points at.
+    ///
+    /// If the cursor is at the ghost position, the item is inserted at the front of the list.
+    fn insert_after(&mut self, item: Self::Item);
+
+    /// Removes the item that the cursor points at and returns it.
+    ///
+    /// The cursor is moved to the next item. Returns `None` if the cursor was at the ghost
+    /// position.
+    fn remove_current(&mut self) -> Option<Self::Item>;
+
+    /// Splits the list in two before the item that the cursor points at.
+    ///
+    /// Returns a new list that contains the items before the cursor. If the cursor is at the ghost
+    /// position, this contains all of the list's items.
+    fn split_before(&mut self) -> Self::List;
+}
+
+/// A cursor that locates items by their indices.
+///
+/// This cursor supports any list, but each step performs a [`List::get`], so it is only efficient
+/// for lists that support random access.
+///
+/// [`List::get`]: ../trait.List.html#tymethod.get
+pub struct IndexCursor<'a, L: 'a + ?Sized + List> {
+    list: &'a L,
+    index: usize,
+}
+
+impl<'a, L: ?Sized + List> IndexCursor<'a, L> {
+    /// Returns a cursor that points at the item at the given index in the given list.
+    ///
+    /// If `index == list.len()`, the cursor is at the ghost position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > list.len()`.
+    pub fn new(list: &'a L, index: usize) -> Self {
+        assert!(index <= list.len(), "index {} exceeds list length {}", index, list.len());
+        IndexCursor { list: list, index: index }
+    }
+}
+
+impl<'a, L: ?Sized + List> Cursor for IndexCursor<'a, L> {
+    type Item = L::Item;
+
+    fn index(&self) -> Option<usize> {
+        if self.index < self.list.len() { Some(self.index) } else { None }
+    }
+
+    fn move_next(&mut self) {
+        self.index = (self.index + 1) % (self.list.len() + 1);
+    }
+
+    fn move_prev(&mut self) {
+        let len = self.list.len();
+        self.index = (self.index + len) % (len + 1);
+    }
+
+    fn current(&self) -> Option<&L::Item> {
+        self.list.get(self.index)
+    }
+
+    fn peek_next(&self) -> Option<&L::Item> {
+        self.list.get((self.index + 1) % (self.list.len() + 1))
+    }
+
+    fn peek_prev(&self) -> Option<&L::Item> {
+        let len = self.list.len();
+        self.list.get((self.index + len) % (len + 1))
+    }
+}
+
+/// A cursor that locates items by their indices and supports editing the list.
+///
+/// This cursor supports any list, but each step performs a [`List::get`] and each edit performs a
+/// [`List::insert`] or [`List::remove`], so it is only efficient for lists that support random
+/// access.
+///
+/// [`List::get`]: ../trait.List.html#tymethod.get
+/// [`List::insert`]: ../trait.List.html#tymethod.insert
+/// [`List::remove`]: ../trait.List.html#tymethod.remove
+pub struct IndexCursorMut<'a, L: 'a + List + Mutate + AddRemove> {
+    list: &'a mut L,
+    index: usize,
+}
+
+impl<'a, L: List + Mutate + AddRemove> IndexCursorMut<'a, L> {
+    /// Returns a cursor that points at the item at the given index in the given list.
+    ///
+    /// If `index == list.len()`, the cursor is at the ghost position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > list.len()`.
+    pub fn new(list: &'a mut L, index: usize) -> Self {
+        assert!(index <= list.len(), "index {} exceeds list length {}", index, list.len());
+        IndexCursorMut { list: list, index: index }
+    }
+}
+
+impl<'a, L: List + Mutate + AddRemove> Cursor for IndexCursorMut<'a, L> {
+    type Item = L::Item;
+
+    fn index(&self) -> Option<usize> {
+        if self.index < self.list.len() { Some(self.index) } else { None }
+    }
+
+    fn move_next(&mut self) {
+        self.index = (self.index + 1) % (self.list.len() + 1);
+    }
+
+    fn move_prev(&mut self) {
+        let len = self.list.len();
+        self.index = (self.index + len) % (len + 1);
+    }
+
+    fn current(&self) -> Option<&L::Item> {
+        self.list.get(self.index)
+    }
+
+    fn peek_next(&self) -> Option<&L::Item> {
+        self.list.get((self.index + 1) % (self.list.len() + 1))
+    }
+
+    fn peek_prev(&self) -> Option<&L::Item> {
+        let len = self.list.len();
+        self.list.get((self.index + len) % (len + 1))
+    }
+}
+
+impl<'a, L: List + Mutate + AddRemove> CursorMut for IndexCursorMut<'a, L> {
+    type List = L;
+
+    fn current_mut(&mut self) -> Option<&mut L::Item> {
+        self.list.get_mut(self.index)
+    }
+
+    fn insert_before(&mut self, item: L::Item) {
+        self.list.insert(self.index, item);
+        self.index += 1;
+    }
+
+    fn insert_after(&mut self, item: L::Item) {
+        if self.index == self.list.len() {
+            self.list.insert(0, item);
+            self.index += 1;
+        } else {
+            self.list.insert(self.index + 1, item);
+        }
+    }
+
+    fn remove_current(&mut self) -> Option<L::Item> {
+        self.list.remove(self.index)
+    }
+
+    fn split_before(&mut self) -> L {
+        let tail = self.list.split_off(self.index);
+        self.index = 0;
+        mem::replace(self.list, tail)
+    }
+}
+
+#[test]
+fn test_sub_list() {
+    use std::collections::VecDeque;
+
+    let mut list: VecDeque<_> = (0..10).collect();
+
+    {
+        let sub = list.slice(2..8);
+        assert_eq!(sub.len(), 6);
+        assert_eq!(sub.first(), Some(&2));
+        assert_eq!(sub.last(), Some(&7));
+        assert_eq!(sub.get(6), None);
+        assert!(sub.slice(1..3).iter().eq(&[3, 4]));
+    }
+
+    {
+        let mut sub = list.slice_mut(2..8);
+        sub.reverse();
+        *sub.first_mut().unwrap() += 10;
+        assert!(sub.iter().eq(&[17, 6, 5, 4, 3, 2]));
+    }
+
+    assert!(list.iter().eq(&[0, 1, 17, 6, 5, 4, 3, 2, 8, 9]));
+}
+
+#[test]
+fn test_views() {
+    use std::collections::LinkedList;
+
+    let mut a = vec![1, 6, 3];
+    let mut b: LinkedList<_> = vec![4, 5, 2, 0].into_iter().collect();
+
+    {
+        let mut chain = ChainMut::new(&mut a, &mut b);
+        let mut rev = RevMut::new(&mut chain);
+        let mut list = StepByMut::new(&mut rev, 2);
+        assert!(list.iter().eq(&[0, 5, 3, 1]));
+
+        list.swap(0, 3);
+        list.reverse();
+        assert!(list.iter().eq(&[0, 3, 5, 1]));
+        assert_eq!(list.get(4), None);
+        assert!(list.iter_mut().map(|item| &*item).eq(&[0, 3, 5, 1]));
+    }
+
+    assert_eq!(a, [1, 6, 5]);
+    assert!(b.iter().eq(&[4, 3, 2, 0]));
+
+    let rev = Rev::new(&b);
+    assert!(rev.iter().eq(&[0, 2, 3, 4]));
+    assert_eq!((rev.first(), rev.get(3)), (Some(&0), Some(&4)));
+
+    let text = "abcdef";
+    let rev = Rev::new(&text[1..5]);
+    assert!(rev.iter().eq(b"edcb"));
+    assert_eq!(rev.first(), Some(&b'e'));
+
+    let sub = rev.slice(0..3);
+    let chain = Chain::new(&text[0..2], &sub);
+    assert!(chain.iter().eq(b"abedc"));
+    assert!(StepBy::new(&chain, 2).iter().eq(b"aec"));
+}
+
+#[test]
+fn test_cursor() {
+    use std::collections::LinkedList;
+
+    fn check<L: List<Item = i32> + Mutate + AddRemove>(mut list: L) -> (Vec<i32>, Vec<i32>) {
+        {
+            let cursor = list.cursor_back();
+            assert_eq!(cursor.index(), Some(3));
+            assert_eq!((cursor.peek_prev(), cursor.current(), cursor.peek_next()),
+                       (Some(&2), Some(&3), None));
+        }
+
+        let front = {
+            let mut cursor = list.cursor_front_mut();
+            cursor.move_prev();
+            assert_eq!(cursor.index(), None);
+            cursor.insert_after(-1);
+            cursor.insert_before(4);
+            cursor.move_next();
+            cursor.move_next();
+            assert_eq!(cursor.remove_current(), Some(0));
+            *cursor.current_mut().unwrap() *= 10;
+            cursor.insert_after(5);
+            cursor.move_next();
+            assert_eq!((cursor.peek_prev(), cursor.current()), (Some(&10), Some(&5)));
+            let front = cursor.split_before();
+            assert_eq!(cursor.index(), Some(0));
+            front
+        };
+
+        (front.into_vec(), list.into_vec())
+    }
+
+    let expected = (vec![-1, 10], vec![5, 2, 3, 4]);
+    assert_eq!(check(vec![0, 1, 2, 3]), expected);
+    assert_eq!(check((0..4).collect::<LinkedList<_>>()), expected);
+}